@@ -0,0 +1,398 @@
+//! Cumulative sums and products along an axis of a strided N-D array.
+//!
+//! The input is a [`View`] of any strides, so bindings can pass transposed or
+//! sliced arrays without copying; the output is always C-contiguous. Each
+//! lane accumulates sequentially in the accumulator type, as NumPy does, so
+//! `float32` sums round at every step and integer sums wrap.
+
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::error::{NumpyError, Result};
+use crate::interrupt::Interrupt;
+use crate::shape::{checked_size, dim_offsets, normalize_axis, strided_dim_offsets};
+
+/// The cumulative kernels.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CumulativeOp {
+    Sum,
+    Prod,
+    /// `np.nancumsum`: NaN counts as `0`.
+    NanSum,
+    /// `np.nancumprod`: NaN counts as `1`.
+    NanProd,
+}
+
+impl CumulativeOp {
+    /// Parse a NumPy function name such as `cumsum` or `nancumprod`.
+    pub fn parse(name: &str) -> Result<Self> {
+        match name {
+            "cumsum" => Ok(Self::Sum),
+            "cumprod" => Ok(Self::Prod),
+            "nancumsum" => Ok(Self::NanSum),
+            "nancumprod" => Ok(Self::NanProd),
+            other => Err(NumpyError::value(format!(
+                "Unknown cumulative op '{}'",
+                other
+            ))),
+        }
+    }
+}
+
+/// The accumulator types a caller can request with `dtype`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AccumDType {
+    Float64,
+    Float32,
+    Int64,
+    Int32,
+}
+
+impl AccumDType {
+    /// Parse a NumPy dtype spelling such as `int64`, `i8` or `<f4`.
+    pub fn parse(dtype: &str) -> Result<Self> {
+        match dtype {
+            "float64" | "f8" | "<f8" => Ok(Self::Float64),
+            "float32" | "f4" | "<f4" => Ok(Self::Float32),
+            "int64" | "i8" | "<i8" => Ok(Self::Int64),
+            "int32" | "i4" | "<i4" => Ok(Self::Int32),
+            other => Err(NumpyError::dtype(
+                format!("Unsupported accumulator dtype '{}'", other),
+                other,
+            )),
+        }
+    }
+}
+
+/// Element types the cumulative kernels read and accumulate in.
+pub trait Scalar: Copy {
+    const ZERO: Self;
+    const ONE: Self;
+    /// NumPy's default accumulator for this input: integers narrower than
+    /// `int64` widen to it, floats keep their type.
+    const ACCUMULATOR: AccumDType;
+
+    fn is_nan(self) -> bool;
+    fn add(self, other: Self) -> Self;
+    fn mul(self, other: Self) -> Self;
+    fn from_f64(x: f64) -> Self;
+    fn from_i64(x: i64) -> Self;
+
+    /// Convert like NumPy's `astype`: integers wrap and floats truncate.
+    fn cast<U: Scalar>(self) -> U;
+}
+
+macro_rules! float_scalar {
+    ($($t:ty => $accumulator:ident),*) => {$(
+        impl Scalar for $t {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const ACCUMULATOR: AccumDType = AccumDType::$accumulator;
+
+            fn is_nan(self) -> bool {
+                self.is_nan()
+            }
+
+            fn add(self, other: Self) -> Self {
+                self + other
+            }
+
+            fn mul(self, other: Self) -> Self {
+                self * other
+            }
+
+            fn from_f64(x: f64) -> Self {
+                x as $t
+            }
+
+            fn from_i64(x: i64) -> Self {
+                x as $t
+            }
+
+            fn cast<U: Scalar>(self) -> U {
+                U::from_f64(self as f64)
+            }
+        }
+    )*};
+}
+
+macro_rules! int_scalar {
+    ($($t:ty),*) => {$(
+        impl Scalar for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const ACCUMULATOR: AccumDType = AccumDType::Int64;
+
+            fn is_nan(self) -> bool {
+                false
+            }
+
+            fn add(self, other: Self) -> Self {
+                self.wrapping_add(other)
+            }
+
+            fn mul(self, other: Self) -> Self {
+                self.wrapping_mul(other)
+            }
+
+            fn from_f64(x: f64) -> Self {
+                x as $t
+            }
+
+            fn from_i64(x: i64) -> Self {
+                x as $t
+            }
+
+            fn cast<U: Scalar>(self) -> U {
+                U::from_i64(self as i64)
+            }
+        }
+    )*};
+}
+
+float_scalar!(f64 => Float64, f32 => Float32);
+int_scalar!(i64, i32);
+
+/// A strided N-D view of `data`: element `index` lives at
+/// `offset + Σ index[d] * strides[d]`, with strides in elements.
+#[derive(Clone, Copy, Debug)]
+pub struct View<'a, T> {
+    data: &'a [T],
+    shape: &'a [usize],
+    strides: &'a [isize],
+    offset: usize,
+}
+
+impl<'a, T> View<'a, T> {
+    /// Fails unless every element of the view lies inside `data`.
+    pub fn new(
+        data: &'a [T],
+        shape: &'a [usize],
+        strides: &'a [isize],
+        offset: usize,
+    ) -> Result<Self> {
+        if strides.len() != shape.len() {
+            return Err(NumpyError::value(format!(
+                "strides has {} entries for a shape of {} dimensions",
+                strides.len(),
+                shape.len()
+            )));
+        }
+        if checked_size(shape)? > 0 && !within(data.len(), shape, strides, offset) {
+            return Err(NumpyError::value(format!(
+                "strides {:?} and offset {} reach outside a buffer of {} elements",
+                strides,
+                offset,
+                data.len()
+            )));
+        }
+        Ok(Self {
+            data,
+            shape,
+            strides,
+            offset,
+        })
+    }
+}
+
+/// Whether the lowest and highest elements a non-empty view reaches lie in
+/// `0..len`. Strides and offsets come from JS, so the reach is computed with
+/// checked arithmetic and an overflow counts as out of bounds.
+fn within(len: usize, shape: &[usize], strides: &[isize], offset: usize) -> bool {
+    let reach = || {
+        let offset = isize::try_from(offset).ok()?;
+        let (mut low, mut high) = (offset, offset);
+        for (&dim, &stride) in shape.iter().zip(strides) {
+            let reach = isize::try_from(dim - 1).ok()?.checked_mul(stride)?;
+            if reach < 0 {
+                low = low.checked_add(reach)?;
+            } else {
+                high = high.checked_add(reach)?;
+            }
+        }
+        Some((low, high))
+    };
+    matches!(reach(), Some((low, high)) if low >= 0 && (high as usize) < len)
+}
+
+/// `np.cumsum(a, axis, dtype=U)` and friends for `op`, returning the
+/// C-contiguous result and its shape.
+///
+/// With `axis` `None` the view is flattened in C order and the result is 1-D.
+pub fn cumulative<T: Scalar, U: Scalar>(
+    a: View<T>,
+    axis: Option<i64>,
+    op: CumulativeOp,
+    interrupt: &impl Interrupt,
+) -> Result<(Vec<U>, Vec<usize>)> {
+    let ndim = a.shape.len();
+    let size = checked_size(a.shape)?;
+    let axes = match axis {
+        Some(axis) => vec![normalize_axis(axis, ndim)?],
+        None => (0..ndim).collect(),
+    };
+    let kept: Vec<usize> = (0..ndim).filter(|d| !axes.contains(d)).collect();
+    let outer = strided_dim_offsets(a.shape, a.strides, &kept)
+        .into_iter()
+        .zip(dim_offsets(a.shape, &kept));
+    let inner: Vec<(isize, usize)> = strided_dim_offsets(a.shape, a.strides, &axes)
+        .into_iter()
+        .zip(dim_offsets(a.shape, &axes))
+        .collect();
+
+    let (identity, step): (U, fn(U, U) -> U) = match op {
+        CumulativeOp::Sum | CumulativeOp::NanSum => (U::ZERO, U::add),
+        CumulativeOp::Prod | CumulativeOp::NanProd => (U::ONE, U::mul),
+    };
+    let skip_nan = matches!(op, CumulativeOp::NanSum | CumulativeOp::NanProd);
+
+    let mut out = vec![U::ZERO; size];
+    for (base, out_base) in outer {
+        interrupt.check()?;
+        let mut acc = identity;
+        for &(offset, out_offset) in &inner {
+            let x = a.data[(a.offset as isize + base + offset) as usize];
+            if !(skip_nan && x.is_nan()) {
+                acc = step(acc, x.cast());
+            }
+            out[out_base + out_offset] = acc;
+        }
+    }
+    let shape = match axis {
+        Some(_) => a.shape.to_vec(),
+        None => vec![size],
+    };
+    Ok((out, shape))
+}
+
+/// A cumulative result in the accumulator type chosen at runtime.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Accumulated {
+    Float64(Vec<f64>),
+    Float32(Vec<f32>),
+    Int64(Vec<i64>),
+    Int32(Vec<i32>),
+}
+
+/// [`cumulative`] with the accumulator picked by `dtype`, defaulting to
+/// NumPy's choice for `T` (so `int32` input accumulates as `int64`).
+pub fn cumulative_as<T: Scalar>(
+    a: View<T>,
+    axis: Option<i64>,
+    op: CumulativeOp,
+    dtype: Option<AccumDType>,
+    interrupt: &impl Interrupt,
+) -> Result<(Accumulated, Vec<usize>)> {
+    Ok(match dtype.unwrap_or(T::ACCUMULATOR) {
+        AccumDType::Float64 => {
+            let (out, shape) = cumulative(a, axis, op, interrupt)?;
+            (Accumulated::Float64(out), shape)
+        }
+        AccumDType::Float32 => {
+            let (out, shape) = cumulative(a, axis, op, interrupt)?;
+            (Accumulated::Float32(out), shape)
+        }
+        AccumDType::Int64 => {
+            let (out, shape) = cumulative(a, axis, op, interrupt)?;
+            (Accumulated::Int64(out), shape)
+        }
+        AccumDType::Int32 => {
+            let (out, shape) = cumulative(a, axis, op, interrupt)?;
+            (Accumulated::Int32(out), shape)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interrupt::Never;
+    use crate::shape::c_strides;
+
+    fn contiguous<'a, T>(data: &'a [T], shape: &'a [usize], strides: &'a [isize]) -> View<'a, T> {
+        View::new(data, shape, strides, 0).unwrap()
+    }
+
+    #[test]
+    fn test_cumsum_cumprod_along_axes() {
+        let a = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let (shape, strides) = ([2, 3], c_strides(&[2, 3]));
+        let view = contiguous(&a, &shape, &strides);
+
+        let (out, out_shape) =
+            cumulative::<f64, f64>(view, None, CumulativeOp::Sum, &Never).unwrap();
+        assert_eq!(
+            (out, out_shape),
+            (vec![1.0, 3.0, 6.0, 10.0, 15.0, 21.0], vec![6])
+        );
+        let (out, out_shape) =
+            cumulative::<f64, f64>(view, Some(0), CumulativeOp::Sum, &Never).unwrap();
+        assert_eq!(
+            (out, out_shape),
+            (vec![1.0, 2.0, 3.0, 5.0, 7.0, 9.0], vec![2, 3])
+        );
+        let (out, _) = cumulative::<f64, f64>(view, Some(-1), CumulativeOp::Prod, &Never).unwrap();
+        assert_eq!(out, vec![1.0, 2.0, 6.0, 4.0, 20.0, 120.0]);
+        assert!(cumulative::<f64, f64>(view, Some(2), CumulativeOp::Sum, &Never).is_err());
+    }
+
+    #[test]
+    fn test_strided_views() {
+        // The transpose of [[1, 2, 3], [4, 5, 6]], read through its strides.
+        let a = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let view = View::new(&a, &[3, 2], &[1, 3], 0).unwrap();
+        let (out, _) = cumulative::<f64, f64>(view, Some(1), CumulativeOp::Sum, &Never).unwrap();
+        assert_eq!(out, vec![1.0, 5.0, 2.0, 7.0, 3.0, 9.0]);
+
+        // Every other element, backwards: [6, 4, 2].
+        let view = View::new(&a, &[3], &[-2], 5).unwrap();
+        let (out, _) = cumulative::<f64, f64>(view, None, CumulativeOp::Sum, &Never).unwrap();
+        assert_eq!(out, vec![6.0, 10.0, 12.0]);
+
+        assert!(View::new(&a, &[3], &[-2], 3).is_err());
+        assert!(View::new(&a, &[2, 3], &[3], 0).is_err());
+        assert!(View::new(&a, &[0, 9], &[9, 1], 0).is_ok());
+        // Reaches that overflow `isize` are rejected, not wrapped back in range.
+        assert!(View::new(&a, &[3, 3], &[isize::MAX, isize::MAX], 0).is_err());
+        assert!(View::new(&a, &[2], &[isize::MIN], usize::MAX).is_err());
+    }
+
+    #[test]
+    fn test_nan_variants_and_accumulator_dtype() {
+        let a = [1.0, f64::NAN, 3.0];
+        let (shape, strides) = ([3], [1]);
+        let view = contiguous(&a, &shape, &strides);
+        let (out, _) = cumulative::<f64, f64>(view, None, CumulativeOp::NanSum, &Never).unwrap();
+        assert_eq!(out, vec![1.0, 1.0, 4.0]);
+        let (out, _) = cumulative::<f64, f64>(view, None, CumulativeOp::NanProd, &Never).unwrap();
+        assert_eq!(out, vec![1.0, 1.0, 3.0]);
+        let (out, _) = cumulative::<f64, f64>(view, None, CumulativeOp::Sum, &Never).unwrap();
+        assert!(out[1].is_nan() && out[2].is_nan());
+
+        // int32 input widens to int64 unless asked otherwise.
+        let big = [i32::MAX, 1];
+        let view = contiguous(&big, &[2], &[1]);
+        let (out, _) = cumulative_as(view, None, CumulativeOp::Sum, None, &Never).unwrap();
+        assert_eq!(
+            out,
+            Accumulated::Int64(vec![i32::MAX as i64, i32::MAX as i64 + 1])
+        );
+        let int32 = Some(AccumDType::parse("int32").unwrap());
+        let (out, _) = cumulative_as(view, None, CumulativeOp::Sum, int32, &Never).unwrap();
+        assert_eq!(out, Accumulated::Int32(vec![i32::MAX, i32::MIN]));
+        let float32 = Some(AccumDType::parse("<f4").unwrap());
+        let (out, _) = cumulative_as(view, None, CumulativeOp::Prod, float32, &Never).unwrap();
+        assert_eq!(
+            out,
+            Accumulated::Float32(vec![i32::MAX as f32, i32::MAX as f32])
+        );
+
+        assert_eq!(
+            CumulativeOp::parse("nancumprod").unwrap(),
+            CumulativeOp::NanProd
+        );
+        assert!(CumulativeOp::parse("cummax").is_err());
+        assert!(AccumDType::parse("uint8").is_err());
+    }
+}
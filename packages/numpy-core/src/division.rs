@@ -0,0 +1,202 @@
+//! Floored division: `np.floor_divide`, `np.remainder` (`np.mod`) and
+//! `np.divmod` for float and integer dtypes.
+//!
+//! Unlike `np.fmod` and Rust's `%`, the remainder takes the sign of the
+//! divisor, so `np.mod(-7, 3) == 2`.
+
+use core::ops::Neg;
+
+use crate::error::Result;
+use crate::math;
+use crate::validate;
+
+/// Element types with NumPy's floored `divmod`.
+pub trait Divmod: Copy {
+    /// `(a // b, a % b)`.
+    fn divmod(self, other: Self) -> (Self, Self);
+
+    fn floor_divide(self, other: Self) -> Self {
+        self.divmod(other).0
+    }
+
+    fn remainder(self, other: Self) -> Self {
+        self.divmod(other).1
+    }
+}
+
+/// NumPy's `npy_divmod`: the quotient is `(a - fmod(a, b)) / b` rounded to
+/// the nearest integer, so it stays exact where `floor(a / b)` would round up.
+/// Division by zero gives `a / b` and NaN; an infinite dividend gives NaN for
+/// both; a zero result takes the sign NumPy gives it.
+macro_rules! float_divmod {
+    ($($t:ty),*) => {
+        $(
+            impl Divmod for $t {
+                fn divmod(self, other: Self) -> (Self, Self) {
+                    let (a, b) = (self, other);
+                    let fmod = a % b;
+                    if b == 0.0 {
+                        return (a / b, fmod);
+                    }
+
+                    let mut div = (a - fmod) / b;
+                    let rem = if fmod == 0.0 {
+                        zero_like(b)
+                    } else if (b < 0.0) != (fmod < 0.0) {
+                        div -= 1.0;
+                        fmod + b
+                    } else {
+                        fmod
+                    };
+
+                    let quot = if div == 0.0 {
+                        zero_like(a / b)
+                    } else {
+                        // Exact: the floor of an `$t` is representable in `$t`.
+                        let floor = math::floor(div as f64) as $t;
+                        if div - floor > 0.5 {
+                            floor + 1.0
+                        } else {
+                            floor
+                        }
+                    };
+                    (quot, rem)
+                }
+            }
+        )*
+    };
+}
+
+float_divmod!(f64, f32);
+
+/// Zero carrying the sign of `x`.
+fn zero_like<T: Default + Neg<Output = T> + Into<f64>>(x: T) -> T {
+    if x.into().is_sign_negative() {
+        -T::default()
+    } else {
+        T::default()
+    }
+}
+
+/// Division by zero gives `0` for both results, as NumPy does after its
+/// divide-by-zero warning, and `MIN // -1` wraps to `MIN`.
+macro_rules! signed_divmod {
+    ($($t:ty),*) => {
+        $(
+            impl Divmod for $t {
+                fn divmod(self, other: Self) -> (Self, Self) {
+                    if other == 0 {
+                        return (0, 0);
+                    }
+                    let (quot, rem) = (self.wrapping_div(other), self.wrapping_rem(other));
+                    if rem != 0 && (rem < 0) != (other < 0) {
+                        (quot - 1, rem + other)
+                    } else {
+                        (quot, rem)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! unsigned_divmod {
+    ($($t:ty),*) => {
+        $(
+            impl Divmod for $t {
+                fn divmod(self, other: Self) -> (Self, Self) {
+                    if other == 0 {
+                        return (0, 0);
+                    }
+                    (self / other, self % other)
+                }
+            }
+        )*
+    };
+}
+
+signed_divmod!(i8, i16, i32, i64);
+unsigned_divmod!(u8, u16, u32, u64);
+
+/// `out[i] = left[i] // right[i]`.
+pub fn floor_divide_into<T: Divmod>(left: &[T], right: &[T], out: &mut [T]) -> Result<()> {
+    validate::same_len(left.len(), right.len())?;
+    validate::output_len(left.len(), out.len())?;
+    for ((slot, &x), &y) in out.iter_mut().zip(left).zip(right) {
+        *slot = x.floor_divide(y);
+    }
+    Ok(())
+}
+
+/// `out[i] = left[i] % right[i]` with the sign of `right[i]`.
+pub fn remainder_into<T: Divmod>(left: &[T], right: &[T], out: &mut [T]) -> Result<()> {
+    validate::same_len(left.len(), right.len())?;
+    validate::output_len(left.len(), out.len())?;
+    for ((slot, &x), &y) in out.iter_mut().zip(left).zip(right) {
+        *slot = x.remainder(y);
+    }
+    Ok(())
+}
+
+/// Both halves of `np.divmod` in one pass.
+pub fn divmod_into<T: Divmod>(
+    left: &[T],
+    right: &[T],
+    quotient: &mut [T],
+    remainder: &mut [T],
+) -> Result<()> {
+    validate::same_len(left.len(), right.len())?;
+    validate::output_len(left.len(), quotient.len())?;
+    validate::output_len(left.len(), remainder.len())?;
+    for (((q, r), &x), &y) in quotient
+        .iter_mut()
+        .zip(remainder.iter_mut())
+        .zip(left)
+        .zip(right)
+    {
+        (*q, *r) = x.divmod(y);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_float_signs_follow_divisor() {
+        assert_eq!((-7.0f64).divmod(3.0), (-3.0, 2.0));
+        assert_eq!(7.0f64.divmod(-3.0), (-3.0, -2.0));
+        assert_eq!((-7.0f32).remainder(3.0), 2.0);
+        assert!(6.0f64.remainder(-3.0).is_sign_negative());
+        assert!(0.0f64.floor_divide(-3.0).is_sign_negative());
+        // 1 // 0.1 is 9: 0.1 is slightly above one tenth.
+        assert_eq!(1.0f64.divmod(0.1).0, 9.0);
+    }
+
+    #[test]
+    fn test_float_zero_and_non_finite() {
+        let (quot, rem) = 1.0f64.divmod(0.0);
+        assert_eq!(quot, f64::INFINITY);
+        assert!(rem.is_nan());
+        assert!(0.0f64.floor_divide(0.0).is_nan());
+        let (quot, rem) = f64::INFINITY.divmod(3.0);
+        assert!(quot.is_nan() && rem.is_nan());
+        assert_eq!((-5.0f64).divmod(f64::INFINITY), (-1.0, f64::INFINITY));
+        assert_eq!(5.0f64.divmod(f64::INFINITY), (0.0, 5.0));
+    }
+
+    #[test]
+    fn test_integer_rules() {
+        assert_eq!((-7i32).divmod(3), (-3, 2));
+        assert_eq!(7i64.divmod(-3), (-3, -2));
+        assert_eq!(5i32.divmod(0), (0, 0));
+        assert_eq!(i64::MIN.divmod(-1), (i64::MIN, 0));
+        assert_eq!(7u8.divmod(2), (3, 1));
+
+        let (mut quot, mut rem) = ([0i32; 2], [0i32; 2]);
+        divmod_into(&[-7, 7], &[2, -2], &mut quot, &mut rem).unwrap();
+        assert_eq!((quot, rem), ([-4, -4], [1, -1]));
+        assert!(remainder_into(&[1i32], &[1, 2], &mut [0]).is_err());
+    }
+}
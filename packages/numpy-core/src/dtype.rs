@@ -0,0 +1,61 @@
+use alloc::format;
+
+use crate::error::{NumpyError, Result};
+
+/// The element types the kernels are implemented for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DType {
+    Float64,
+    Float32,
+}
+
+impl DType {
+    /// Parse a NumPy dtype spelling such as `float64`, `f8` or `<f8`.
+    pub fn parse(dtype: &str) -> Result<Self> {
+        match dtype {
+            "float64" | "f8" | "<f8" => Ok(Self::Float64),
+            "float32" | "f4" | "<f4" => Ok(Self::Float32),
+            other => Err(NumpyError::dtype(
+                format!("Unsupported dtype '{}'", other),
+                other,
+            )),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Float64 => "float64",
+            Self::Float32 => "float32",
+        }
+    }
+
+    pub fn itemsize(self) -> usize {
+        match self {
+            Self::Float64 => 8,
+            Self::Float32 => 4,
+        }
+    }
+
+    /// NumPy's type promotion for the float kinds supported here.
+    pub fn promote(self, other: Self) -> Self {
+        if self == Self::Float64 || other == Self::Float64 {
+            Self::Float64
+        } else {
+            Self::Float32
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_promote() {
+        assert_eq!(DType::parse("<f4").unwrap(), DType::Float32);
+        assert_eq!(DType::Float32.promote(DType::Float64), DType::Float64);
+        let error = DType::parse("complex128").unwrap_err();
+        assert_eq!(error.kind, crate::ErrorKind::Type);
+        assert_eq!(error.dtype.as_deref(), Some("complex128"));
+    }
+}
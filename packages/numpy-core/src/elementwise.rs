@@ -0,0 +1,596 @@
+//! Elementwise ufunc kernels over contiguous `f64` or `f32` slices.
+//!
+//! Every op is dispatched once per call, outside the loop, so each loop body
+//! is a single tight expression the compiler can vectorize.
+
+use core::f64::consts::PI;
+
+use crate::division::Divmod;
+use crate::error::Result;
+use crate::float::Float;
+use crate::math;
+use crate::validate;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnaryOp {
+    Neg,
+    Abs,
+    Sign,
+    Sqrt,
+    Cbrt,
+    Square,
+    Reciprocal,
+    Exp,
+    Exp2,
+    Expm1,
+    Log,
+    Log2,
+    Log10,
+    Log1p,
+    Sin,
+    Cos,
+    Tan,
+    Arcsin,
+    Arccos,
+    Arctan,
+    Sinh,
+    Cosh,
+    Tanh,
+    Arcsinh,
+    Arccosh,
+    Arctanh,
+    Deg2rad,
+    Rad2deg,
+    /// Half to even, like `np.rint` and `np.round` with `decimals=0`.
+    Round,
+    Floor,
+    Ceil,
+    Trunc,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Pow,
+    /// NaN-propagating, like `np.maximum`.
+    Maximum,
+    /// NaN-propagating, like `np.minimum`.
+    Minimum,
+    /// NaN-ignoring, like `np.fmax`: NaN only when both sides are.
+    Fmax,
+    /// NaN-ignoring, like `np.fmin`.
+    Fmin,
+    /// C-style remainder with the sign of the dividend, like `np.fmod`.
+    Fmod,
+    /// Floored remainder with the sign of the divisor, like `np.remainder`.
+    Remainder,
+    /// Floored quotient, like `np.floor_divide`.
+    FloorDivide,
+    Arctan2,
+    Hypot,
+}
+
+/// Elementwise comparisons; results are `0`/`1` bytes, NumPy's bool layout.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompareOp {
+    Equal,
+    /// True when either side is NaN, like `np.not_equal`.
+    NotEqual,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+}
+
+/// Expand `$apply` with `$f` bound to the scalar function for `$op`.
+macro_rules! with_unary {
+    ($op:expr, $f:ident => $apply:expr) => {
+        match $op {
+            UnaryOp::Neg => {
+                let $f = |x: f64| -x;
+                $apply
+            }
+            UnaryOp::Abs => {
+                let $f = math::abs;
+                $apply
+            }
+            UnaryOp::Sign => {
+                let $f = sign;
+                $apply
+            }
+            UnaryOp::Sqrt => {
+                let $f = math::sqrt;
+                $apply
+            }
+            UnaryOp::Cbrt => {
+                let $f = math::cbrt;
+                $apply
+            }
+            UnaryOp::Square => {
+                let $f = |x: f64| x * x;
+                $apply
+            }
+            UnaryOp::Reciprocal => {
+                let $f = |x: f64| 1.0 / x;
+                $apply
+            }
+            UnaryOp::Exp => {
+                let $f = math::exp;
+                $apply
+            }
+            UnaryOp::Exp2 => {
+                let $f = math::exp2;
+                $apply
+            }
+            UnaryOp::Expm1 => {
+                let $f = math::exp_m1;
+                $apply
+            }
+            UnaryOp::Log => {
+                let $f = math::ln;
+                $apply
+            }
+            UnaryOp::Log2 => {
+                let $f = math::log2;
+                $apply
+            }
+            UnaryOp::Log10 => {
+                let $f = math::log10;
+                $apply
+            }
+            UnaryOp::Log1p => {
+                let $f = math::ln_1p;
+                $apply
+            }
+            UnaryOp::Sin => {
+                let $f = math::sin;
+                $apply
+            }
+            UnaryOp::Cos => {
+                let $f = math::cos;
+                $apply
+            }
+            UnaryOp::Tan => {
+                let $f = math::tan;
+                $apply
+            }
+            UnaryOp::Arcsin => {
+                let $f = math::asin;
+                $apply
+            }
+            UnaryOp::Arccos => {
+                let $f = math::acos;
+                $apply
+            }
+            UnaryOp::Arctan => {
+                let $f = math::atan;
+                $apply
+            }
+            UnaryOp::Sinh => {
+                let $f = math::sinh;
+                $apply
+            }
+            UnaryOp::Cosh => {
+                let $f = math::cosh;
+                $apply
+            }
+            UnaryOp::Tanh => {
+                let $f = math::tanh;
+                $apply
+            }
+            UnaryOp::Arcsinh => {
+                let $f = math::asinh;
+                $apply
+            }
+            UnaryOp::Arccosh => {
+                let $f = math::acosh;
+                $apply
+            }
+            UnaryOp::Arctanh => {
+                let $f = math::atanh;
+                $apply
+            }
+            UnaryOp::Deg2rad => {
+                let $f = |x: f64| x * PI / 180.0;
+                $apply
+            }
+            UnaryOp::Rad2deg => {
+                let $f = |x: f64| x * 180.0 / PI;
+                $apply
+            }
+            UnaryOp::Round => {
+                let $f = math::rint;
+                $apply
+            }
+            UnaryOp::Floor => {
+                let $f = math::floor;
+                $apply
+            }
+            UnaryOp::Ceil => {
+                let $f = math::ceil;
+                $apply
+            }
+            UnaryOp::Trunc => {
+                let $f = math::trunc;
+                $apply
+            }
+        }
+    };
+}
+
+/// Expand `$apply` with `$f` bound to the scalar function for `$op`.
+macro_rules! with_binary {
+    ($op:expr, $f:ident => $apply:expr) => {
+        match $op {
+            BinaryOp::Add => {
+                let $f = |x: f64, y: f64| x + y;
+                $apply
+            }
+            BinaryOp::Sub => {
+                let $f = |x: f64, y: f64| x - y;
+                $apply
+            }
+            BinaryOp::Mul => {
+                let $f = |x: f64, y: f64| x * y;
+                $apply
+            }
+            BinaryOp::Div => {
+                let $f = |x: f64, y: f64| x / y;
+                $apply
+            }
+            BinaryOp::Pow => {
+                let $f = math::powf;
+                $apply
+            }
+            BinaryOp::Maximum => {
+                let $f = maximum;
+                $apply
+            }
+            BinaryOp::Minimum => {
+                let $f = minimum;
+                $apply
+            }
+            BinaryOp::Fmax => {
+                let $f = fmax;
+                $apply
+            }
+            BinaryOp::Fmin => {
+                let $f = fmin;
+                $apply
+            }
+            BinaryOp::Fmod => {
+                let $f = |x: f64, y: f64| x % y;
+                $apply
+            }
+            BinaryOp::Remainder => {
+                let $f = Divmod::remainder;
+                $apply
+            }
+            BinaryOp::FloorDivide => {
+                let $f = Divmod::floor_divide;
+                $apply
+            }
+            BinaryOp::Arctan2 => {
+                let $f = math::atan2;
+                $apply
+            }
+            BinaryOp::Hypot => {
+                let $f = math::hypot;
+                $apply
+            }
+        }
+    };
+}
+
+impl UnaryOp {
+    pub fn apply(self, x: f64) -> f64 {
+        with_unary!(self, f => f(x))
+    }
+}
+
+impl BinaryOp {
+    pub fn apply(self, x: f64, y: f64) -> f64 {
+        with_binary!(self, f => f(x, y))
+    }
+}
+
+impl CompareOp {
+    pub fn apply(self, x: f64, y: f64) -> bool {
+        match self {
+            CompareOp::Equal => x == y,
+            CompareOp::NotEqual => x != y,
+            CompareOp::Less => x < y,
+            CompareOp::LessEqual => x <= y,
+            CompareOp::Greater => x > y,
+            CompareOp::GreaterEqual => x >= y,
+        }
+    }
+}
+
+/// `out[i] = op(input[i])`.
+pub fn unary_into<T: Float>(op: UnaryOp, input: &[T], out: &mut [T]) -> Result<()> {
+    validate::output_len(input.len(), out.len())?;
+    with_unary!(op, f => {
+        for (slot, &x) in out.iter_mut().zip(input) {
+            *slot = T::from_f64(f(x.to_f64()));
+        }
+    });
+    Ok(())
+}
+
+/// `values[i] = op(values[i])`.
+pub fn unary_in_place<T: Float>(op: UnaryOp, values: &mut [T]) {
+    with_unary!(op, f => {
+        for value in values.iter_mut() {
+            *value = T::from_f64(f(value.to_f64()));
+        }
+    });
+}
+
+/// `out[i] = op(left[i], right[i])` for operands of equal length.
+pub fn binary_into<T: Float>(op: BinaryOp, left: &[T], right: &[T], out: &mut [T]) -> Result<()> {
+    validate::same_len(left.len(), right.len())?;
+    validate::output_len(left.len(), out.len())?;
+    with_binary!(op, f => {
+        for ((slot, &x), &y) in out.iter_mut().zip(left).zip(right) {
+            *slot = T::from_f64(f(x.to_f64(), y.to_f64()));
+        }
+    });
+    Ok(())
+}
+
+/// `left[i] = op(left[i], right[i])`.
+pub fn binary_in_place<T: Float>(op: BinaryOp, left: &mut [T], right: &[T]) -> Result<()> {
+    validate::same_len(left.len(), right.len())?;
+    with_binary!(op, f => {
+        for (x, &y) in left.iter_mut().zip(right) {
+            *x = T::from_f64(f(x.to_f64(), y.to_f64()));
+        }
+    });
+    Ok(())
+}
+
+/// `out[i] = op(input[i], scalar)`. The scalar has the element type, as
+/// NumPy casts a Python float to the array's dtype.
+pub fn scalar_into<T: Float>(op: BinaryOp, input: &[T], scalar: T, out: &mut [T]) -> Result<()> {
+    validate::output_len(input.len(), out.len())?;
+
+    // SAFETY: the output length was checked above.
+    #[cfg(target_arch = "aarch64")]
+    if let (Some(input), Some(out)) = (T::as_f64s(input), T::as_f64s_mut(out)) {
+        match op {
+            BinaryOp::Add => {
+                unsafe { neon::add_scalar(input, scalar.to_f64(), out) };
+                return Ok(());
+            }
+            BinaryOp::Mul => {
+                unsafe { neon::mul_scalar(input, scalar.to_f64(), out) };
+                return Ok(());
+            }
+            _ => {}
+        }
+    }
+
+    let scalar = scalar.to_f64();
+    with_binary!(op, f => {
+        for (slot, &x) in out.iter_mut().zip(input) {
+            *slot = T::from_f64(f(x.to_f64(), scalar));
+        }
+    });
+    Ok(())
+}
+
+/// `out[i] = op(left[i], right[i]) as u8` for operands of equal length.
+pub fn compare_into<T: Float>(
+    op: CompareOp,
+    left: &[T],
+    right: &[T],
+    out: &mut [u8],
+) -> Result<()> {
+    validate::same_len(left.len(), right.len())?;
+    validate::output_len(left.len(), out.len())?;
+    for ((slot, &x), &y) in out.iter_mut().zip(left).zip(right) {
+        *slot = u8::from(op.apply(x.to_f64(), y.to_f64()));
+    }
+    Ok(())
+}
+
+/// `out[i] = op(input[i], scalar) as u8`.
+pub fn compare_scalar_into<T: Float>(
+    op: CompareOp,
+    input: &[T],
+    scalar: T,
+    out: &mut [u8],
+) -> Result<()> {
+    validate::output_len(input.len(), out.len())?;
+    let scalar = scalar.to_f64();
+    for (slot, &x) in out.iter_mut().zip(input) {
+        *slot = u8::from(op.apply(x.to_f64(), scalar));
+    }
+    Ok(())
+}
+
+/// `np.clip`: `minimum(maximum(x, min), max)`, so NaN bounds or elements
+/// propagate instead of panicking like `f64::clamp`.
+pub fn clip_into<T: Float>(input: &[T], min: T, max: T, out: &mut [T]) -> Result<()> {
+    validate::output_len(input.len(), out.len())?;
+    let (min, max) = (min.to_f64(), max.to_f64());
+    for (slot, &x) in out.iter_mut().zip(input) {
+        *slot = T::from_f64(minimum(maximum(x.to_f64(), min), max));
+    }
+    Ok(())
+}
+
+fn maximum(x: f64, y: f64) -> f64 {
+    if x.is_nan() || y.is_nan() {
+        f64::NAN
+    } else if y > x {
+        y
+    } else {
+        x
+    }
+}
+
+fn minimum(x: f64, y: f64) -> f64 {
+    if x.is_nan() || y.is_nan() {
+        f64::NAN
+    } else if y < x {
+        y
+    } else {
+        x
+    }
+}
+
+fn fmax(x: f64, y: f64) -> f64 {
+    if x.is_nan() || y > x {
+        y
+    } else {
+        x
+    }
+}
+
+fn fmin(x: f64, y: f64) -> f64 {
+    if x.is_nan() || y < x {
+        y
+    } else {
+        x
+    }
+}
+
+/// `np.sign`: `-1`, `0` or `1`, and NaN for NaN.
+fn sign(x: f64) -> f64 {
+    if x > 0.0 {
+        1.0
+    } else if x < 0.0 {
+        -1.0
+    } else {
+        x
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod neon {
+    use core::arch::aarch64::{vaddq_f64, vdupq_n_f64, vld1q_f64, vmulq_f64, vst1q_f64};
+
+    macro_rules! scalar_kernel {
+        ($name:ident, $vector_op:ident, $op:tt) => {
+            /// # Safety
+            /// `output` must be at least as long as `input`.
+            pub unsafe fn $name(input: &[f64], scalar: f64, output: &mut [f64]) {
+                let len = input.len();
+                let vector_len = len - (len % 8);
+                let scalar_vector = vdupq_n_f64(scalar);
+                let input_ptr = input.as_ptr();
+                let output_ptr = output.as_mut_ptr();
+                let mut i = 0;
+
+                while i < vector_len {
+                    let left0 = vld1q_f64(input_ptr.add(i));
+                    let left1 = vld1q_f64(input_ptr.add(i + 2));
+                    let left2 = vld1q_f64(input_ptr.add(i + 4));
+                    let left3 = vld1q_f64(input_ptr.add(i + 6));
+
+                    vst1q_f64(output_ptr.add(i), $vector_op(left0, scalar_vector));
+                    vst1q_f64(output_ptr.add(i + 2), $vector_op(left1, scalar_vector));
+                    vst1q_f64(output_ptr.add(i + 4), $vector_op(left2, scalar_vector));
+                    vst1q_f64(output_ptr.add(i + 6), $vector_op(left3, scalar_vector));
+
+                    i += 8;
+                }
+
+                while i < len {
+                    *output_ptr.add(i) = *input_ptr.add(i) $op scalar;
+                    i += 1;
+                }
+            }
+        };
+    }
+
+    scalar_kernel!(add_scalar, vaddq_f64, +);
+    scalar_kernel!(mul_scalar, vmulq_f64, *);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_maximum_propagates_nan() {
+        let mut out = [0.0; 3];
+        binary_into(
+            BinaryOp::Maximum,
+            &[1.0, f64::NAN, 3.0],
+            &[2.0, 1.0, f64::NAN],
+            &mut out,
+        )
+        .unwrap();
+        assert_eq!(out[0], 2.0);
+        assert!(out[1].is_nan() && out[2].is_nan());
+
+        let (x, y) = (
+            [1.0, f64::NAN, 3.0, f64::NAN],
+            [2.0, 1.0, f64::NAN, f64::NAN],
+        );
+        let mut out = [0.0; 4];
+        binary_into(BinaryOp::Fmax, &x, &y, &mut out).unwrap();
+        assert_eq!(out[..3], [2.0, 1.0, 3.0]);
+        assert!(out[3].is_nan());
+        binary_into(BinaryOp::Fmin, &x, &y, &mut out).unwrap();
+        assert_eq!(out[..3], [1.0, 1.0, 3.0]);
+        assert!(out[3].is_nan());
+    }
+
+    #[test]
+    fn test_binary_rejects_unequal_lengths() {
+        let mut out = [0.0; 3];
+        let error =
+            binary_into(BinaryOp::Add, &[1.0, 2.0, 3.0], &[1.0, 2.0], &mut out).unwrap_err();
+        assert_eq!(
+            error.message,
+            "operands could not be broadcast together with shapes (3,) (2,)"
+        );
+    }
+
+    #[test]
+    fn test_scalar_and_unary_kernels() {
+        let input: [f64; 10] = core::array::from_fn(|i| i as f64);
+        let mut out = [0.0; 10];
+        scalar_into(BinaryOp::Add, &input, 0.5, &mut out).unwrap();
+        assert_eq!(out[9], 9.5);
+        scalar_into(BinaryOp::Mul, &input, 2.0, &mut out).unwrap();
+        assert_eq!(out[9], 18.0);
+        unary_into(UnaryOp::Sign, &[-2.0, 0.0, f64::NAN], &mut out[..3]).unwrap();
+        assert_eq!(&out[..2], &[-1.0, 0.0]);
+        assert!(out[2].is_nan());
+        clip_into(&[-1.0, 5.0, f64::NAN], 0.0, 2.0, &mut out[..3]).unwrap();
+        assert_eq!(&out[..2], &[0.0, 2.0]);
+        assert!(out[2].is_nan());
+    }
+
+    #[test]
+    fn test_compare_kernels_treat_nan_as_unordered() {
+        let left = [1.0, 2.0, f64::NAN];
+        let mut out = [9; 3];
+        compare_into(CompareOp::Less, &left, &[2.0, 2.0, 0.0], &mut out).unwrap();
+        assert_eq!(out, [1, 0, 0]);
+        compare_into(CompareOp::NotEqual, &left, &[1.0, 3.0, f64::NAN], &mut out).unwrap();
+        assert_eq!(out, [0, 1, 1]);
+        compare_scalar_into(CompareOp::GreaterEqual, &left, 2.0, &mut out).unwrap();
+        assert_eq!(out, [0, 1, 0]);
+        assert!(compare_into(CompareOp::Equal, &left, &[1.0], &mut out).is_err());
+    }
+
+    #[test]
+    fn test_float32_rounds_each_result_once() {
+        let input = [2.0f32, 0.1, f32::NAN];
+        let mut out = [0.0f32; 3];
+        unary_into(UnaryOp::Sqrt, &input, &mut out).unwrap();
+        assert_eq!(out[..2], [2.0f32.sqrt(), 0.1f32.sqrt()]);
+        scalar_into(BinaryOp::Add, &input, 0.2, &mut out).unwrap();
+        assert_eq!(out[1], 0.1f32 + 0.2f32);
+        assert!(out[2].is_nan());
+    }
+}
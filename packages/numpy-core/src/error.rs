@@ -0,0 +1,289 @@
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{self, Write as _};
+use core::ops::Deref;
+
+pub type Result<T> = core::result::Result<T, NumpyError>;
+
+/// The NumPy exception class a failure corresponds to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// `ValueError`: bad values or incompatible shapes.
+    Value,
+    /// `numpy.exceptions.AxisError`.
+    Axis,
+    /// `numpy.linalg.LinAlgError`.
+    LinAlg,
+    /// `IndexError`: an index outside the axis it selects from.
+    Index,
+    /// `TypeError`: unsupported or mismatched dtype.
+    Type,
+    /// `MemoryError`: an output could not be allocated.
+    Memory,
+    /// A kernel stopped early because its caller cancelled it.
+    Abort,
+}
+
+impl ErrorKind {
+    /// The Python class name, used as the `name` of the JS error.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Value => "ValueError",
+            Self::Axis => "AxisError",
+            Self::LinAlg => "LinAlgError",
+            Self::Index => "IndexError",
+            Self::Type => "TypeError",
+            Self::Memory => "MemoryError",
+            Self::Abort => "AbortError",
+        }
+    }
+}
+
+/// A kernel failure with the structured context the TS layer needs to
+/// rethrow it as the matching NumPy error class.
+///
+/// Boxed so `Result<T, NumpyError>` stays pointer-sized on the happy path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NumpyError(Box<ErrorInfo>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ErrorInfo {
+    pub kind: ErrorKind,
+    pub message: String,
+    pub expected_shape: Option<Vec<usize>>,
+    pub actual_shape: Option<Vec<usize>>,
+    pub axis: Option<i64>,
+    pub ndim: Option<usize>,
+    pub dtype: Option<String>,
+    pub bytes: Option<usize>,
+}
+
+impl NumpyError {
+    pub fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self(Box::new(ErrorInfo {
+            kind,
+            message: message.into(),
+            expected_shape: None,
+            actual_shape: None,
+            axis: None,
+            ndim: None,
+            dtype: None,
+            bytes: None,
+        }))
+    }
+
+    pub fn value(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Value, message)
+    }
+
+    pub fn linalg(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::LinAlg, message)
+    }
+
+    pub fn index(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Index, message)
+    }
+
+    /// A `ValueError` for operands whose shapes do not fit together.
+    pub fn shape_mismatch(
+        message: impl Into<String>,
+        expected: &[usize],
+        actual: &[usize],
+    ) -> Self {
+        let mut error = Self::value(message);
+        error.0.expected_shape = Some(expected.to_vec());
+        error.0.actual_shape = Some(actual.to_vec());
+        error
+    }
+
+    /// A flat buffer whose length does not match what the call describes.
+    pub fn length_mismatch(expected: usize, actual: usize) -> Self {
+        Self::shape_mismatch(
+            alloc::format!("Expected input length {}, got {}", expected, actual),
+            &[expected],
+            &[actual],
+        )
+    }
+
+    pub fn broadcast(left: &[usize], right: &[usize]) -> Self {
+        Self::shape_mismatch(
+            alloc::format!(
+                "operands could not be broadcast together with shapes {} {}",
+                shape_repr(left),
+                shape_repr(right)
+            ),
+            left,
+            right,
+        )
+    }
+
+    /// NumPy's error for reducing an empty array with an op like `maximum`.
+    pub fn no_identity(reduction: &str) -> Self {
+        Self::value(alloc::format!(
+            "zero-size array to reduction operation {} which has no identity",
+            reduction
+        ))
+    }
+
+    pub fn axis(axis: i64, ndim: usize) -> Self {
+        let mut error = Self::new(
+            ErrorKind::Axis,
+            alloc::format!(
+                "axis {} is out of bounds for array of dimension {}",
+                axis,
+                ndim
+            ),
+        );
+        error.0.axis = Some(axis);
+        error.0.ndim = Some(ndim);
+        error
+    }
+
+    pub fn dtype(message: impl Into<String>, dtype: &str) -> Self {
+        let mut error = Self::new(ErrorKind::Type, message);
+        error.0.dtype = Some(dtype.to_string());
+        error
+    }
+
+    pub fn memory(bytes: usize) -> Self {
+        let mut error = Self::new(
+            ErrorKind::Memory,
+            alloc::format!("Unable to allocate {} bytes for an array", bytes),
+        );
+        error.0.bytes = Some(bytes);
+        error
+    }
+
+    pub fn aborted() -> Self {
+        Self::new(ErrorKind::Abort, "The operation was aborted")
+    }
+
+    /// The wire format both bindings throw: a single-line JSON object with
+    /// `name`, `message` and whichever structured fields are set.
+    pub fn to_json(&self) -> String {
+        let mut json = String::with_capacity(self.message.len() + 48);
+        json.push_str("{\"name\":");
+        write_json_string(&mut json, self.kind.name());
+        json.push_str(",\"message\":");
+        write_json_string(&mut json, &self.message);
+
+        for (key, shape) in [
+            ("expectedShape", &self.expected_shape),
+            ("actualShape", &self.actual_shape),
+        ] {
+            if let Some(shape) = shape {
+                let _ = write!(json, ",\"{}\":[", key);
+                for (index, dim) in shape.iter().enumerate() {
+                    if index > 0 {
+                        json.push(',');
+                    }
+                    let _ = write!(json, "{}", dim);
+                }
+                json.push(']');
+            }
+        }
+        if let Some(axis) = self.axis {
+            let _ = write!(json, ",\"axis\":{}", axis);
+        }
+        if let Some(ndim) = self.ndim {
+            let _ = write!(json, ",\"ndim\":{}", ndim);
+        }
+        if let Some(dtype) = &self.dtype {
+            json.push_str(",\"dtype\":");
+            write_json_string(&mut json, dtype);
+        }
+        if let Some(bytes) = self.bytes {
+            let _ = write!(json, ",\"bytes\":{}", bytes);
+        }
+        json.push('}');
+        json
+    }
+}
+
+impl Deref for NumpyError {
+    type Target = ErrorInfo;
+
+    fn deref(&self) -> &ErrorInfo {
+        &self.0
+    }
+}
+
+impl fmt::Display for NumpyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.kind.name(), self.message)
+    }
+}
+
+impl core::error::Error for NumpyError {}
+
+/// NumPy's shape spelling: `(2,3)`, with a trailing comma for one dimension.
+pub fn shape_repr(shape: &[usize]) -> String {
+    let mut repr = String::from("(");
+    for (index, dim) in shape.iter().enumerate() {
+        if index > 0 {
+            repr.push(',');
+        }
+        let _ = write!(repr, "{}", dim);
+    }
+    if shape.len() == 1 {
+        repr.push(',');
+    }
+    repr.push(')');
+    repr
+}
+
+fn write_json_string(json: &mut String, value: &str) {
+    json.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => {
+                let _ = write!(json, "\\u{:04x}", ch as u32);
+            }
+            ch => json.push(ch),
+        }
+    }
+    json.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_broadcast_error_json() {
+        let error = NumpyError::broadcast(&[2, 3], &[2]);
+        assert_eq!(
+            error.message,
+            "operands could not be broadcast together with shapes (2,3) (2,)"
+        );
+        assert_eq!(
+            error.to_json(),
+            "{\"name\":\"ValueError\",\"message\":\"operands could not be broadcast together with shapes (2,3) (2,)\",\"expectedShape\":[2,3],\"actualShape\":[2]}"
+        );
+    }
+
+    #[test]
+    fn test_axis_error_fields() {
+        let error = NumpyError::axis(-3, 2);
+        assert_eq!(error.kind, ErrorKind::Axis);
+        assert_eq!(
+            error.to_json(),
+            "{\"name\":\"AxisError\",\"message\":\"axis -3 is out of bounds for array of dimension 2\",\"axis\":-3,\"ndim\":2}"
+        );
+    }
+
+    #[test]
+    fn test_json_escapes_message() {
+        let error = NumpyError::dtype("Unsupported dtype '\"x\"\n'", "\\x");
+        assert_eq!(
+            error.to_json(),
+            "{\"name\":\"TypeError\",\"message\":\"Unsupported dtype '\\\"x\\\"\\n'\",\"dtype\":\"\\\\x\"}"
+        );
+    }
+}
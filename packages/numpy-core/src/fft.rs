@@ -0,0 +1,200 @@
+//! Discrete Fourier transforms of any length.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+
+use crate::error::{NumpyError, Result};
+use crate::interrupt::Interrupt;
+use crate::math;
+use crate::validate;
+
+/// `np.fft.fft` / `np.fft.ifft` of `real + i * imag`, written to `out` as
+/// interleaved `[re0, im0, re1, im1, ...]`. The inverse is scaled by `1/n`.
+///
+/// Power-of-two lengths use radix-2; others use Bluestein's algorithm.
+pub fn fft_into(
+    real: &[f64],
+    imag: Option<&[f64]>,
+    inverse: bool,
+    out: &mut [f64],
+    interrupt: &impl Interrupt,
+) -> Result<()> {
+    let n = real.len();
+    if let Some(imag) = imag {
+        validate::same_len(n, imag.len())?;
+    }
+    if n == 0 {
+        return Err(NumpyError::value(
+            "Invalid number of FFT data points (0) specified.",
+        ));
+    }
+    validate::output_len(validate::checked_len(n, 2)?, out.len())?;
+
+    let mut re = real.to_vec();
+    let mut im = imag.map_or_else(|| vec![0.0; n], <[f64]>::to_vec);
+    transform(&mut re, &mut im, inverse, interrupt)?;
+
+    let scale = if inverse { 1.0 / n as f64 } else { 1.0 };
+    for (pair, (r, i)) in out.chunks_exact_mut(2).zip(re.iter().zip(&im)) {
+        pair[0] = r * scale;
+        pair[1] = i * scale;
+    }
+    Ok(())
+}
+
+/// Unscaled in-place DFT of `re + i * im`.
+pub fn transform(
+    re: &mut [f64],
+    im: &mut [f64],
+    inverse: bool,
+    interrupt: &impl Interrupt,
+) -> Result<()> {
+    validate::same_len(re.len(), im.len())?;
+    if re.len().is_power_of_two() {
+        radix2(re, im, inverse, interrupt)
+    } else {
+        bluestein(re, im, inverse, interrupt)
+    }
+}
+
+/// In-place iterative Cooley-Tukey; checks the interrupt once per stage.
+fn radix2(re: &mut [f64], im: &mut [f64], inverse: bool, interrupt: &impl Interrupt) -> Result<()> {
+    let n = re.len();
+    let bits = n.trailing_zeros();
+
+    if n > 1 {
+        for i in 0..n {
+            let j = i.reverse_bits() >> (usize::BITS - bits);
+            if j > i {
+                re.swap(i, j);
+                im.swap(i, j);
+            }
+        }
+    }
+
+    let sign = if inverse { 1.0 } else { -1.0 };
+    let mut len = 2;
+    while len <= n {
+        interrupt.check()?;
+        let half = len / 2;
+        let angle = sign * 2.0 * PI / len as f64;
+
+        for k in 0..half {
+            let (w_im, w_re) = math::sin_cos(angle * k as f64);
+            let mut start = 0;
+            while start < n {
+                let (a, b) = (start + k, start + k + half);
+                let t_re = w_re * re[b] - w_im * im[b];
+                let t_im = w_re * im[b] + w_im * re[b];
+                re[b] = re[a] - t_re;
+                im[b] = im[a] - t_im;
+                re[a] += t_re;
+                im[a] += t_im;
+                start += len;
+            }
+        }
+        len <<= 1;
+    }
+    Ok(())
+}
+
+/// Chirp-z transform: an arbitrary-length DFT as a power-of-two convolution.
+fn bluestein(
+    re: &mut [f64],
+    im: &mut [f64],
+    inverse: bool,
+    interrupt: &impl Interrupt,
+) -> Result<()> {
+    let n = re.len();
+    let m = (2 * n - 1).next_power_of_two();
+    let sign = if inverse { 1.0 } else { -1.0 };
+
+    // w[k] = exp(sign * i * pi * k^2 / n); k^2 is reduced mod 2n to keep the angle exact.
+    let chirp: Vec<(f64, f64)> = (0..n)
+        .map(|k| {
+            let k2 = (k as u128 * k as u128 % (2 * n as u128)) as f64;
+            let (s, c) = math::sin_cos(sign * PI * k2 / n as f64);
+            (c, s)
+        })
+        .collect();
+
+    let mut a_re = vec![0.0; m];
+    let mut a_im = vec![0.0; m];
+    for k in 0..n {
+        let (c, s) = chirp[k];
+        a_re[k] = re[k] * c - im[k] * s;
+        a_im[k] = re[k] * s + im[k] * c;
+    }
+
+    let mut b_re = vec![0.0; m];
+    let mut b_im = vec![0.0; m];
+    b_re[0] = chirp[0].0;
+    b_im[0] = -chirp[0].1;
+    for k in 1..n {
+        let (c, s) = chirp[k];
+        b_re[k] = c;
+        b_im[k] = -s;
+        b_re[m - k] = c;
+        b_im[m - k] = -s;
+    }
+
+    radix2(&mut a_re, &mut a_im, false, interrupt)?;
+    radix2(&mut b_re, &mut b_im, false, interrupt)?;
+    for i in 0..m {
+        let r = a_re[i] * b_re[i] - a_im[i] * b_im[i];
+        let j = a_re[i] * b_im[i] + a_im[i] * b_re[i];
+        a_re[i] = r;
+        a_im[i] = j;
+    }
+    radix2(&mut a_re, &mut a_im, true, interrupt)?;
+
+    let scale = 1.0 / m as f64;
+    for k in 0..n {
+        let (c, s) = chirp[k];
+        let (r, i) = (a_re[k] * scale, a_im[k] * scale);
+        re[k] = r * c - i * s;
+        im[k] = r * s + i * c;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interrupt::Never;
+
+    #[test]
+    fn test_fft_of_impulse_is_flat() {
+        let mut out = [0.0; 8];
+        fft_into(&[1.0, 0.0, 0.0, 0.0], None, false, &mut out, &Never).unwrap();
+        for pair in out.chunks_exact(2) {
+            assert!((pair[0] - 1.0).abs() < 1e-12 && pair[1].abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_bluestein_roundtrip() {
+        let input = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let mut spectrum = [0.0; 12];
+        fft_into(&input, None, false, &mut spectrum, &Never).unwrap();
+        assert!((spectrum[0] - 21.0).abs() < 1e-12);
+
+        let re: Vec<f64> = spectrum.iter().step_by(2).copied().collect();
+        let im: Vec<f64> = spectrum.iter().skip(1).step_by(2).copied().collect();
+        let mut back = [0.0; 12];
+        fft_into(&re, Some(&im), true, &mut back, &Never).unwrap();
+        for (pair, expected) in back.chunks_exact(2).zip(input) {
+            assert!((pair[0] - expected).abs() < 1e-12 && pair[1].abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_empty_fft_is_value_error() {
+        let error = fft_into(&[], None, false, &mut [], &Never).unwrap_err();
+        assert_eq!(
+            error.message,
+            "Invalid number of FFT data points (0) specified."
+        );
+    }
+}
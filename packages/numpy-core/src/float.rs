@@ -0,0 +1,51 @@
+//! The float element types kernels are generic over.
+
+/// `f64` and `f32` elements.
+///
+/// Kernels evaluate each element in `f64` and round the result once. That is
+/// exact for `+ - * /`, `sqrt` and the rounding ops, and within an ulp for
+/// the transcendentals, so `float32` data stays `float32` end to end without
+/// a second copy of every math function. Reductions accumulate in `f64`.
+pub trait Float: Copy + PartialOrd + Default {
+    fn to_f64(self) -> f64;
+
+    /// Round to `Self`.
+    fn from_f64(x: f64) -> Self;
+
+    /// The slice itself when `Self` is `f64`, for `f64`-only fast paths.
+    fn as_f64s(_values: &[Self]) -> Option<&[f64]> {
+        None
+    }
+
+    fn as_f64s_mut(_values: &mut [Self]) -> Option<&mut [f64]> {
+        None
+    }
+}
+
+impl Float for f64 {
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn from_f64(x: f64) -> Self {
+        x
+    }
+
+    fn as_f64s(values: &[Self]) -> Option<&[f64]> {
+        Some(values)
+    }
+
+    fn as_f64s_mut(values: &mut [Self]) -> Option<&mut [f64]> {
+        Some(values)
+    }
+}
+
+impl Float for f32 {
+    fn to_f64(self) -> f64 {
+        f64::from(self)
+    }
+
+    fn from_f64(x: f64) -> Self {
+        x as f32
+    }
+}
@@ -0,0 +1,29 @@
+use crate::error::{NumpyError, Result};
+
+/// Cooperative cancellation for long-running kernels.
+///
+/// Kernels call [`Interrupt::check`] between blocks of work, so a binding can
+/// stop a job from another thread without the core knowing how the flag is
+/// stored.
+pub trait Interrupt {
+    fn is_interrupted(&self) -> bool;
+
+    /// Fail with [`ErrorKind::Abort`](crate::ErrorKind::Abort) once interrupted.
+    fn check(&self) -> Result<()> {
+        if self.is_interrupted() {
+            Err(NumpyError::aborted())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// For synchronous callers that can never be cancelled.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Never;
+
+impl Interrupt for Never {
+    fn is_interrupted(&self) -> bool {
+        false
+    }
+}
@@ -0,0 +1,36 @@
+//! Backend-independent pieces shared by `numpy-native` and `numpy-wasm`.
+//!
+//! Kernels, dtype handling and argument validation live here so both bindings
+//! are thin wrappers with identical semantics. The crate is `no_std` with
+//! `alloc`; the default `std` feature only switches float math from `libm` to
+//! the platform intrinsics.
+
+#![no_std]
+#![deny(clippy::all)]
+
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod cumulative;
+pub mod division;
+pub mod dtype;
+pub mod elementwise;
+pub mod error;
+pub mod fft;
+pub mod float;
+pub mod interrupt;
+pub mod linalg;
+pub mod math;
+pub mod norm;
+pub mod reduce;
+pub mod rounding;
+pub mod shape;
+pub mod sort;
+pub mod ufunc;
+pub mod validate;
+
+pub use dtype::DType;
+pub use error::{ErrorInfo, ErrorKind, NumpyError, Result};
+pub use float::Float;
+pub use interrupt::{Interrupt, Never};
@@ -0,0 +1,344 @@
+//! Dense linear algebra on row-major `f64` matrices.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::error::{NumpyError, Result};
+use crate::interrupt::Interrupt;
+use crate::math;
+use crate::validate;
+
+/// Rows of the output computed between interrupt checks.
+const MATMUL_ROW_BLOCK: usize = 64;
+
+/// `out = A @ B` for `m x k` and `k x n` matrices.
+pub fn matmul_into(
+    a: &[f64],
+    b: &[f64],
+    m: usize,
+    k: usize,
+    n: usize,
+    out: &mut [f64],
+    interrupt: &impl Interrupt,
+) -> Result<()> {
+    validate::matrix_len("A", a.len(), m, k)?;
+    validate::matrix_len("B", b.len(), k, n)?;
+    validate::output_len(validate::checked_len(m, n)?, out.len())?;
+
+    for (block, rows) in out.chunks_mut(MATMUL_ROW_BLOCK * n.max(1)).enumerate() {
+        interrupt.check()?;
+        rows.fill(0.0);
+        let first_row = block * MATMUL_ROW_BLOCK;
+
+        for (offset, row) in rows.chunks_mut(n.max(1)).enumerate() {
+            let a_row = &a[(first_row + offset) * k..(first_row + offset + 1) * k];
+            for (kk, &a_val) in a_row.iter().enumerate() {
+                let b_row = &b[kk * n..(kk + 1) * n];
+                for (value, &b_val) in row.iter_mut().zip(b_row) {
+                    *value += a_val * b_val;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Write the `cols x rows` transpose of a `rows x cols` matrix, in cache tiles.
+pub fn transpose_into(input: &[f64], rows: usize, cols: usize, out: &mut [f64]) -> Result<()> {
+    validate::matrix_len("Input", input.len(), rows, cols)?;
+    validate::output_len(input.len(), out.len())?;
+
+    const TILE: usize = 32;
+
+    let mut row_block = 0;
+    while row_block < rows {
+        let row_max = (row_block + TILE).min(rows);
+        let mut col_block = 0;
+
+        while col_block < cols {
+            let col_max = (col_block + TILE).min(cols);
+            let mut row = row_block;
+
+            while row < row_max {
+                let input_offset = row * cols;
+                let mut col = col_block;
+
+                while col < col_max {
+                    out[col * rows + row] = input[input_offset + col];
+                    col += 1;
+                }
+
+                row += 1;
+            }
+
+            col_block += TILE;
+        }
+
+        row_block += TILE;
+    }
+    Ok(())
+}
+
+/// Lower-triangular `L` with `A = L @ L.T`, like `np.linalg.cholesky`.
+pub fn cholesky_into(
+    a: &[f64],
+    n: usize,
+    out: &mut [f64],
+    interrupt: &impl Interrupt,
+) -> Result<()> {
+    validate::matrix_len("A", a.len(), n, n)?;
+    validate::output_len(a.len(), out.len())?;
+    let l = out;
+    l.fill(0.0);
+
+    for j in 0..n {
+        interrupt.check()?;
+        let diagonal = a[j * n + j] - l[j * n..j * n + j].iter().map(|x| x * x).sum::<f64>();
+        if diagonal <= 0.0 || diagonal.is_nan() {
+            return Err(NumpyError::linalg("Matrix is not positive definite"));
+        }
+        let diagonal = math::sqrt(diagonal);
+        l[j * n + j] = diagonal;
+
+        for i in j + 1..n {
+            let dot: f64 = l[i * n..i * n + j]
+                .iter()
+                .zip(&l[j * n..j * n + j])
+                .map(|(x, y)| x * y)
+                .sum();
+            l[i * n + j] = (a[i * n + j] - dot) / diagonal;
+        }
+    }
+    Ok(())
+}
+
+/// Reduced SVD `A = U @ diag(S) @ Vt` of an `m x n` matrix, written into
+/// `u` (`m x k`), `s` (`k`) and `vt` (`k x n`) with `k = min(m, n)`.
+///
+/// One-sided Jacobi: accurate to full precision on small singular values,
+/// and each sweep is a natural point to poll the interrupt.
+pub fn svd_into(
+    a: &[f64],
+    m: usize,
+    n: usize,
+    u: &mut [f64],
+    s: &mut [f64],
+    vt: &mut [f64],
+    interrupt: &impl Interrupt,
+) -> Result<()> {
+    validate::matrix_len("A", a.len(), m, n)?;
+    let k = m.min(n);
+    validate::output_len(validate::checked_len(m, k)?, u.len())?;
+    validate::output_len(k, s.len())?;
+    validate::output_len(validate::checked_len(k, n)?, vt.len())?;
+
+    // Work on the tall orientation; for wide inputs decompose A.T and swap U and V.
+    let transposed = m < n;
+    let (rows, cols) = if transposed { (n, m) } else { (m, n) };
+    let at = |i: usize, j: usize| {
+        if transposed {
+            a[j * n + i]
+        } else {
+            a[i * n + j]
+        }
+    };
+
+    // Column-major so rotations touch contiguous memory.
+    let mut left: Vec<Vec<f64>> = (0..cols)
+        .map(|j| (0..rows).map(|i| at(i, j)).collect())
+        .collect();
+    let mut right: Vec<Vec<f64>> = (0..cols)
+        .map(|j| (0..cols).map(|i| if i == j { 1.0 } else { 0.0 }).collect())
+        .collect();
+
+    const MAX_SWEEPS: usize = 60;
+    let tolerance = f64::EPSILON * rows as f64;
+
+    for _ in 0..MAX_SWEEPS {
+        interrupt.check()?;
+        let mut rotated = false;
+
+        for p in 0..cols {
+            for q in p + 1..cols {
+                let (alpha, beta, gamma) = left[p]
+                    .iter()
+                    .zip(&left[q])
+                    .fold((0.0, 0.0, 0.0), |(alpha, beta, gamma), (&x, &y)| {
+                        (alpha + x * x, beta + y * y, gamma + x * y)
+                    });
+                if gamma == 0.0 || math::abs(gamma) <= tolerance * math::sqrt(alpha * beta) {
+                    continue;
+                }
+                rotated = true;
+
+                let zeta = (beta - alpha) / (2.0 * gamma);
+                let t = math::signum(zeta) / (math::abs(zeta) + math::sqrt(1.0 + zeta * zeta));
+                let c = 1.0 / math::sqrt(1.0 + t * t);
+                let s = c * t;
+
+                rotate_columns(&mut left, p, q, c, s);
+                rotate_columns(&mut right, p, q, c, s);
+            }
+        }
+
+        if !rotated {
+            break;
+        }
+    }
+
+    let mut singular: Vec<(f64, usize)> = left
+        .iter()
+        .enumerate()
+        .map(|(j, column)| (math::sqrt(column.iter().map(|x| x * x).sum::<f64>()), j))
+        .collect();
+    singular.sort_by(|x, y| y.0.total_cmp(&x.0));
+
+    // `k == cols`: the tall factor is `rows x k`, the square one `k x cols`.
+    let mut tall = vec![0.0; rows * k];
+    let mut square = vec![0.0; k * cols];
+    for (rank, &(sigma, j)) in singular.iter().enumerate() {
+        s[rank] = sigma;
+        let scale = if sigma > 0.0 { 1.0 / sigma } else { 0.0 };
+        for i in 0..rows {
+            tall[i * k + rank] = left[j][i] * scale;
+        }
+        for i in 0..cols {
+            square[rank * cols + i] = right[j][i];
+        }
+    }
+
+    // For wide inputs A = (A.T).T = V S U.T, so the roles swap.
+    if transposed {
+        transpose_into(&square, k, cols, u)?;
+        transpose_into(&tall, rows, k, vt)?;
+    } else {
+        u.copy_from_slice(&tall);
+        vt.copy_from_slice(&square);
+    }
+    Ok(())
+}
+
+fn rotate_columns(columns: &mut [Vec<f64>], p: usize, q: usize, c: f64, s: f64) {
+    let (head, tail) = columns.split_at_mut(q);
+    for (x, y) in head[p].iter_mut().zip(tail[0].iter_mut()) {
+        let (xp, yq) = (*x, *y);
+        *x = c * xp - s * yq;
+        *y = s * xp + c * yq;
+    }
+}
+
+/// Inner product of two equal-length vectors.
+pub fn dot(a: &[f64], b: &[f64]) -> Result<f64> {
+    validate::same_len(a.len(), b.len())?;
+    Ok(a.iter().zip(b).map(|(x, y)| x * y).sum())
+}
+
+/// Sum of the main diagonal of a `rows x cols` matrix.
+pub fn trace(a: &[f64], rows: usize, cols: usize) -> Result<f64> {
+    validate::matrix_len("Matrix", a.len(), rows, cols)?;
+    Ok(a.iter().step_by(cols + 1).take(rows.min(cols)).sum())
+}
+
+/// Determinant by cofactor expansion; only 2x2 and 3x3 are supported.
+pub fn det(a: &[f64], n: usize) -> Result<f64> {
+    validate::matrix_len("Matrix", a.len(), n, n)?;
+    match n {
+        2 => Ok(a[0] * a[3] - a[1] * a[2]),
+        3 => Ok(a[0] * a[4] * a[8] + a[1] * a[5] * a[6] + a[2] * a[3] * a[7]
+            - a[2] * a[4] * a[6]
+            - a[1] * a[3] * a[8]
+            - a[0] * a[5] * a[7]),
+        _ => Err(NumpyError::shape_mismatch(
+            "det only supports 2x2 and 3x3 matrices",
+            &[3, 3],
+            &[n, n],
+        )),
+    }
+}
+
+/// Inverse by the adjugate; only 2x2 and 3x3 are supported.
+pub fn inv_into(a: &[f64], n: usize, out: &mut [f64]) -> Result<()> {
+    if n != 2 && n != 3 {
+        return Err(NumpyError::shape_mismatch(
+            "inv only supports 2x2 and 3x3 matrices",
+            &[3, 3],
+            &[n, n],
+        ));
+    }
+    let det = det(a, n)?;
+    validate::output_len(a.len(), out.len())?;
+    if math::abs(det) < 1e-10 {
+        return Err(NumpyError::linalg("Singular matrix"));
+    }
+
+    if n == 2 {
+        out.copy_from_slice(&[a[3], -a[1], -a[2], a[0]]);
+    } else {
+        out.copy_from_slice(&[
+            a[4] * a[8] - a[5] * a[7],
+            a[2] * a[7] - a[1] * a[8],
+            a[1] * a[5] - a[2] * a[4],
+            a[5] * a[6] - a[3] * a[8],
+            a[0] * a[8] - a[2] * a[6],
+            a[2] * a[3] - a[0] * a[5],
+            a[3] * a[7] - a[4] * a[6],
+            a[1] * a[6] - a[0] * a[7],
+            a[0] * a[4] - a[1] * a[3],
+        ]);
+    }
+    for value in out.iter_mut() {
+        *value /= det;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interrupt::Never;
+
+    #[test]
+    fn test_matmul_2x2() {
+        let mut out = [0.0; 4];
+        matmul_into(
+            &[1.0, 2.0, 3.0, 4.0],
+            &[5.0, 6.0, 7.0, 8.0],
+            2,
+            2,
+            2,
+            &mut out,
+            &Never,
+        )
+        .unwrap();
+        assert_eq!(out, [19.0, 22.0, 43.0, 50.0]);
+    }
+
+    #[test]
+    fn test_svd_reconstructs_wide_input() {
+        let a = [3.0, 2.0, 2.0, 2.0, 3.0, -2.0];
+        let (mut u, mut s, mut vt) = ([0.0; 4], [0.0; 2], [0.0; 6]);
+        svd_into(&a, 2, 3, &mut u, &mut s, &mut vt, &Never).unwrap();
+        assert!((s[0] - 5.0).abs() < 1e-12 && (s[1] - 3.0).abs() < 1e-12);
+        for i in 0..2 {
+            for j in 0..3 {
+                let value: f64 = (0..2).map(|r| u[i * 2 + r] * s[r] * vt[r * 3 + j]).sum();
+                assert!((value - a[i * 3 + j]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cholesky_rejects_indefinite() {
+        let mut out = [0.0; 4];
+        let error = cholesky_into(&[1.0, 2.0, 2.0, 1.0], 2, &mut out, &Never).unwrap_err();
+        assert_eq!(error.kind, crate::ErrorKind::LinAlg);
+    }
+
+    #[test]
+    fn test_small_inverse() {
+        let mut out = [0.0; 4];
+        inv_into(&[4.0, 7.0, 2.0, 6.0], 2, &mut out).unwrap();
+        assert!((out[0] - 0.6).abs() < 1e-12 && (out[1] + 0.7).abs() < 1e-12);
+        assert!(inv_into(&[1.0, 2.0, 2.0, 4.0], 2, &mut out).is_err());
+    }
+}
@@ -0,0 +1,94 @@
+//! Scalar float functions. With the `std` feature they are the platform
+//! intrinsics; without it they come from `libm`, so kernels build on any
+//! `no_std` target.
+
+macro_rules! unary_math {
+    ($($name:ident => $method:ident, $libm:ident;)*) => {
+        $(
+            #[inline]
+            pub fn $name(x: f64) -> f64 {
+                #[cfg(feature = "std")]
+                {
+                    x.$method()
+                }
+                #[cfg(not(feature = "std"))]
+                {
+                    libm::$libm(x)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! binary_math {
+    ($($name:ident => $method:ident, $libm:ident;)*) => {
+        $(
+            #[inline]
+            pub fn $name(x: f64, y: f64) -> f64 {
+                #[cfg(feature = "std")]
+                {
+                    x.$method(y)
+                }
+                #[cfg(not(feature = "std"))]
+                {
+                    libm::$libm(x, y)
+                }
+            }
+        )*
+    };
+}
+
+unary_math! {
+    abs => abs, fabs;
+    sqrt => sqrt, sqrt;
+    cbrt => cbrt, cbrt;
+    exp => exp, exp;
+    exp2 => exp2, exp2;
+    exp_m1 => exp_m1, expm1;
+    ln => ln, log;
+    log2 => log2, log2;
+    log10 => log10, log10;
+    ln_1p => ln_1p, log1p;
+    sin => sin, sin;
+    cos => cos, cos;
+    tan => tan, tan;
+    asin => asin, asin;
+    acos => acos, acos;
+    atan => atan, atan;
+    sinh => sinh, sinh;
+    cosh => cosh, cosh;
+    tanh => tanh, tanh;
+    round => round, round;
+    rint => round_ties_even, rint;
+    floor => floor, floor;
+    ceil => ceil, ceil;
+    trunc => trunc, trunc;
+}
+
+// `std` open-codes the inverse hyperbolics from `ln`/`ln_1p`, which drifts
+// several ULP from NumPy near the edges of the domain; libm's are fdlibm ports.
+pub use libm::{acosh, asinh, atanh};
+
+binary_math! {
+    powf => powf, pow;
+    atan2 => atan2, atan2;
+    hypot => hypot, hypot;
+}
+
+/// `(sin x, cos x)`.
+#[inline]
+pub fn sin_cos(x: f64) -> (f64, f64) {
+    (sin(x), cos(x))
+}
+
+/// `1.0` or `-1.0` following the sign bit, like `f64::signum` for non-NaN input.
+#[inline]
+pub fn signum(x: f64) -> f64 {
+    if x.is_nan() {
+        f64::NAN
+    } else if x.is_sign_negative() {
+        -1.0
+    } else {
+        1.0
+    }
+}
@@ -0,0 +1,394 @@
+//! `np.linalg.norm`: vector p-norms and matrix norms over any axis.
+
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::error::{NumpyError, Result};
+use crate::interrupt::Interrupt;
+use crate::linalg;
+use crate::math;
+use crate::shape::{c_strides, check_size, checked_size, normalize_axis};
+
+/// The `ord` argument. Numbers cover every vector order and the numeric
+/// matrix orders; `fro` and `nuc` are matrix-only.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NormOrd {
+    P(f64),
+    Fro,
+    Nuc,
+}
+
+impl NormOrd {
+    /// Parse `fro`, `nuc` or a number, including `inf`, `-inf` and JS's
+    /// `Infinity`.
+    pub fn parse(ord: &str) -> Result<Self> {
+        match ord.trim() {
+            "fro" => Ok(Self::Fro),
+            "nuc" => Ok(Self::Nuc),
+            number => number
+                .parse()
+                .map(Self::P)
+                .map_err(|_| NumpyError::value(format!("Invalid norm order '{}'", ord))),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Fro => "fro",
+            Self::Nuc => "nuc",
+            Self::P(_) => "",
+        }
+    }
+}
+
+/// Euclidean norm with LAPACK `dnrm2`'s running scale, so squaring neither
+/// overflows for huge elements nor underflows for tiny ones.
+pub fn nrm2(values: impl IntoIterator<Item = f64>) -> f64 {
+    let (mut scale, mut ssq) = (0.0, 1.0);
+    let mut infinite = false;
+    for x in values {
+        let a = math::abs(x);
+        if a.is_nan() {
+            return f64::NAN;
+        } else if a.is_infinite() {
+            infinite = true;
+        } else if a != 0.0 {
+            if scale < a {
+                let ratio = scale / a;
+                ssq = 1.0 + ssq * ratio * ratio;
+                scale = a;
+            } else {
+                let ratio = a / scale;
+                ssq += ratio * ratio;
+            }
+        }
+    }
+    if infinite {
+        f64::INFINITY
+    } else {
+        scale * math::sqrt(ssq)
+    }
+}
+
+fn fold_abs(x: &[f64], reduction: &str, pick: fn(f64, f64) -> f64) -> Result<f64> {
+    x.iter()
+        .map(|&v| math::abs(v))
+        .reduce(|acc, v| {
+            if acc.is_nan() || v.is_nan() {
+                f64::NAN
+            } else {
+                pick(acc, v)
+            }
+        })
+        .ok_or_else(|| NumpyError::no_identity(reduction))
+}
+
+/// Vector norm of order `p`: `inf`/`-inf` are the largest/smallest
+/// magnitude, `0` counts non-zeros, and any other `p` is
+/// `sum(|x|^p)^(1/p)`.
+pub fn vector_norm(x: &[f64], p: f64) -> Result<f64> {
+    Ok(if p == f64::INFINITY {
+        fold_abs(x, "maximum", f64::max)?
+    } else if p == f64::NEG_INFINITY {
+        fold_abs(x, "minimum", f64::min)?
+    } else if p == 0.0 {
+        x.iter().filter(|&&v| v != 0.0).count() as f64
+    } else if p == 1.0 {
+        x.iter().map(|&v| math::abs(v)).sum()
+    } else if p == 2.0 {
+        nrm2(x.iter().copied())
+    } else if p.is_nan() {
+        return Err(NumpyError::value("Invalid norm order 'nan' for vectors"));
+    } else {
+        p_norm(x, p)
+    })
+}
+
+/// `sum(|x|^p)^(1/p)`; positive `p` is scaled by the largest magnitude so
+/// the powers stay in range.
+fn p_norm(x: &[f64], p: f64) -> f64 {
+    let largest = x.iter().fold(0.0, |m: f64, &v| m.max(math::abs(v)));
+    let scale = if p > 0.0 && largest.is_finite() && largest > 0.0 {
+        largest
+    } else {
+        1.0
+    };
+    let sum: f64 = x.iter().map(|&v| math::powf(math::abs(v) / scale, p)).sum();
+    math::powf(sum, 1.0 / p) * scale
+}
+
+/// Norm of a row-major `rows x cols` matrix. `1`/`-1` take the largest or
+/// smallest absolute column sum, `inf`/`-inf` the row sums, `2`/`-2` the
+/// largest or smallest singular value, and `nuc` their sum.
+pub fn matrix_norm(
+    a: &[f64],
+    rows: usize,
+    cols: usize,
+    ord: NormOrd,
+    interrupt: &impl Interrupt,
+) -> Result<f64> {
+    crate::validate::matrix_len("Matrix", a.len(), rows, cols)?;
+    let column_sums = || -> Vec<f64> {
+        (0..cols)
+            .map(|c| (0..rows).map(|r| math::abs(a[r * cols + c])).sum())
+            .collect()
+    };
+    let row_sums = || -> Vec<f64> {
+        a.chunks_exact(cols.max(1))
+            .take(rows)
+            .map(|row| row.iter().map(|&v| math::abs(v)).sum())
+            .collect()
+    };
+
+    let singular = || singular_values(a, rows, cols, interrupt);
+
+    match ord {
+        NormOrd::Fro => Ok(nrm2(a.iter().copied())),
+        NormOrd::Nuc => Ok(singular()?.iter().sum()),
+        NormOrd::P(1.0) => fold_abs(&column_sums(), "maximum", f64::max),
+        NormOrd::P(-1.0) => fold_abs(&column_sums(), "minimum", f64::min),
+        NormOrd::P(2.0) => fold_abs(&singular()?, "maximum", f64::max),
+        NormOrd::P(-2.0) => fold_abs(&singular()?, "minimum", f64::min),
+        NormOrd::P(p) if p == f64::INFINITY => fold_abs(&row_sums(), "maximum", f64::max),
+        NormOrd::P(p) if p == f64::NEG_INFINITY => fold_abs(&row_sums(), "minimum", f64::min),
+        NormOrd::P(_) => Err(NumpyError::value("Invalid norm order for matrices.")),
+    }
+}
+
+fn singular_values(
+    a: &[f64],
+    rows: usize,
+    cols: usize,
+    interrupt: &impl Interrupt,
+) -> Result<Vec<f64>> {
+    let k = rows.min(cols);
+    let mut u = vec![0.0; rows * k];
+    let mut s = vec![0.0; k];
+    let mut vt = vec![0.0; k * cols];
+    linalg::svd_into(a, rows, cols, &mut u, &mut s, &mut vt, interrupt)?;
+    Ok(s)
+}
+
+/// `np.linalg.norm(a, ord, axis, keepdims)` for a C-contiguous array of
+/// `shape`, returning the result and its shape.
+///
+/// With no `axis`, `ord=None` is the 2-norm of the flattened array and any
+/// other `ord` needs a 1-D or 2-D input. One axis takes vector norms along
+/// it; two axes take matrix norms over those `(row, column)` axes.
+pub fn norm(
+    a: &[f64],
+    shape: &[usize],
+    ord: Option<NormOrd>,
+    axis: Option<&[i64]>,
+    keepdims: bool,
+    interrupt: &impl Interrupt,
+) -> Result<(Vec<f64>, Vec<usize>)> {
+    check_size(a.len(), shape)?;
+    let ndim = shape.len();
+    let improper = || NumpyError::value("Improper number of dimensions to norm.");
+
+    let axes: Vec<usize> = match axis {
+        None if ord.is_none() => {
+            let shape = if keepdims { vec![1; ndim] } else { Vec::new() };
+            return Ok((vec![nrm2(a.iter().copied())], shape));
+        }
+        None if ndim == 1 || ndim == 2 => (0..ndim).collect(),
+        None => return Err(improper()),
+        Some(axis) => axis
+            .iter()
+            .map(|&ax| normalize_axis(ax, ndim))
+            .collect::<Result<_>>()?,
+    };
+    match axes.as_slice() {
+        [_] => {
+            if let Some(ord @ (NormOrd::Fro | NormOrd::Nuc)) = ord {
+                return Err(NumpyError::value(format!(
+                    "Invalid norm order '{}' for vectors",
+                    ord.name()
+                )));
+            }
+        }
+        [row, col] if row == col => return Err(NumpyError::value("Duplicate axes given.")),
+        [_, _] => {}
+        _ => return Err(improper()),
+    }
+
+    let strides = c_strides(shape);
+    let outer: Vec<usize> = (0..ndim).filter(|d| !axes.contains(d)).collect();
+    let out_len = checked_size(&outer.iter().map(|&d| shape[d]).collect::<Vec<_>>())?;
+    let mut lane = vec![0.0; axes.iter().map(|&d| shape[d]).product()];
+    let mut out = Vec::with_capacity(out_len);
+
+    for index in 0..out_len {
+        interrupt.check()?;
+        // Decompose the flat output index over the kept axes, last fastest.
+        let mut rest = index;
+        let mut base = 0isize;
+        for &d in outer.iter().rev() {
+            base += (rest % shape[d]) as isize * strides[d];
+            rest /= shape[d];
+        }
+        let at = |offset: isize| a[(base + offset) as usize];
+
+        out.push(match axes.as_slice() {
+            [d] => {
+                for (i, slot) in lane.iter_mut().enumerate() {
+                    *slot = at(i as isize * strides[*d]);
+                }
+                let p = match ord {
+                    Some(NormOrd::P(p)) => p,
+                    _ => 2.0,
+                };
+                vector_norm(&lane, p)?
+            }
+            [row, col] => {
+                let cols = shape[*col];
+                for (i, slot) in lane.iter_mut().enumerate() {
+                    let (r, c) = (i / cols, i % cols);
+                    *slot = at(r as isize * strides[*row] + c as isize * strides[*col]);
+                }
+                matrix_norm(
+                    &lane,
+                    shape[*row],
+                    cols,
+                    ord.unwrap_or(NormOrd::Fro),
+                    interrupt,
+                )?
+            }
+            _ => unreachable!("axes were checked above"),
+        });
+    }
+
+    let out_shape = (0..ndim)
+        .filter_map(|d| match (axes.contains(&d), keepdims) {
+            (false, _) => Some(shape[d]),
+            (true, true) => Some(1),
+            (true, false) => None,
+        })
+        .collect();
+    Ok((out, out_shape))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interrupt::Never;
+
+    #[test]
+    fn test_vector_orders() {
+        let x = [3.0, -4.0, 0.0];
+        assert_eq!(vector_norm(&x, 2.0).unwrap(), 5.0);
+        assert_eq!(vector_norm(&x, 1.0).unwrap(), 7.0);
+        assert_eq!(vector_norm(&x, f64::INFINITY).unwrap(), 4.0);
+        assert_eq!(vector_norm(&x, f64::NEG_INFINITY).unwrap(), 0.0);
+        assert_eq!(vector_norm(&x, 0.0).unwrap(), 2.0);
+        assert!((vector_norm(&x, 3.0).unwrap() - 91f64.cbrt()).abs() < 1e-14);
+        assert_eq!(vector_norm(&[1.0, 0.0], -1.0).unwrap(), 0.0);
+        assert_eq!(
+            vector_norm(&[], f64::INFINITY).unwrap_err().message,
+            "zero-size array to reduction operation maximum which has no identity"
+        );
+    }
+
+    #[test]
+    fn test_nrm2_is_overflow_safe() {
+        assert_eq!(nrm2([3e300, 4e300]), 5e300);
+        assert_eq!(nrm2([3e-300, 4e-300]), 5e-300);
+        assert_eq!(nrm2([f64::INFINITY, f64::INFINITY, 1.0]), f64::INFINITY);
+        assert!(nrm2([f64::INFINITY, f64::NAN]).is_nan());
+        assert_eq!(
+            vector_norm(&[3e200, 4e200], 3.0).unwrap(),
+            91f64.cbrt() * 1e200
+        );
+    }
+
+    #[test]
+    fn test_matrix_orders() {
+        // [[1, -2], [3, 4]]
+        let a = [1.0, -2.0, 3.0, 4.0];
+        let norm = |ord| matrix_norm(&a, 2, 2, ord, &Never).unwrap();
+        assert_eq!(norm(NormOrd::P(1.0)), 6.0);
+        assert_eq!(norm(NormOrd::P(-1.0)), 4.0);
+        assert_eq!(norm(NormOrd::P(f64::INFINITY)), 7.0);
+        assert_eq!(norm(NormOrd::P(f64::NEG_INFINITY)), 3.0);
+        assert!((norm(NormOrd::Fro) - 30f64.sqrt()).abs() < 1e-14);
+        // Singular values of [[1, -2], [3, 4]] are sqrt(15 +- 5 sqrt 5).
+        let (big, small) = (
+            (15.0 + 5.0 * 5f64.sqrt()).sqrt(),
+            (15.0 - 5.0 * 5f64.sqrt()).sqrt(),
+        );
+        assert!((norm(NormOrd::P(2.0)) - big).abs() < 1e-13);
+        assert!((norm(NormOrd::P(-2.0)) - small).abs() < 1e-13);
+        assert!((norm(NormOrd::Nuc) - (big + small)).abs() < 1e-13);
+        assert_eq!(
+            matrix_norm(&a, 2, 2, NormOrd::P(3.0), &Never)
+                .unwrap_err()
+                .message,
+            "Invalid norm order for matrices."
+        );
+    }
+
+    #[test]
+    fn test_axis_and_keepdims() {
+        let a = [1.0, -2.0, 3.0, 4.0, 0.0, -3.0];
+        let (out, shape) = norm(
+            &a,
+            &[2, 3],
+            Some(NormOrd::P(1.0)),
+            Some(&[0]),
+            false,
+            &Never,
+        )
+        .unwrap();
+        assert_eq!((out, shape), (vec![5.0, 2.0, 6.0], vec![3]));
+        let (out, shape) = norm(&a, &[2, 3], None, Some(&[-1]), true, &Never).unwrap();
+        assert_eq!(out[1], 5.0);
+        assert_eq!(shape, vec![2, 1]);
+        let (out, shape) = norm(
+            &a,
+            &[1, 2, 3],
+            Some(NormOrd::P(f64::INFINITY)),
+            Some(&[1, 2]),
+            false,
+            &Never,
+        )
+        .unwrap();
+        assert_eq!((out, shape), (vec![7.0], vec![1]));
+        let (out, shape) = norm(&a, &[2, 3], None, None, true, &Never).unwrap();
+        assert_eq!((out, shape), (vec![39f64.sqrt()], vec![1, 1]));
+    }
+
+    #[test]
+    fn test_argument_errors() {
+        let a = [0.0; 8];
+        let err = |ord, axis: Option<&[i64]>| {
+            norm(&a, &[2, 2, 2], ord, axis, false, &Never)
+                .unwrap_err()
+                .message
+                .clone()
+        };
+        assert_eq!(
+            err(Some(NormOrd::P(2.0)), None),
+            "Improper number of dimensions to norm."
+        );
+        assert_eq!(
+            err(Some(NormOrd::Fro), Some(&[0])),
+            "Invalid norm order 'fro' for vectors"
+        );
+        assert_eq!(err(None, Some(&[1, -2])), "Duplicate axes given.");
+        assert_eq!(
+            err(None, Some(&[0, 1, 2])),
+            "Improper number of dimensions to norm."
+        );
+        assert_eq!(
+            NormOrd::parse("-inf").unwrap(),
+            NormOrd::P(f64::NEG_INFINITY)
+        );
+        assert_eq!(
+            NormOrd::parse("Infinity").unwrap(),
+            NormOrd::P(f64::INFINITY)
+        );
+        assert!(NormOrd::parse("max").is_err());
+    }
+}
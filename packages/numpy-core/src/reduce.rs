@@ -0,0 +1,602 @@
+//! Reductions over contiguous `f64` or `f32` slices: whole-array, plus
+//! `var`/`std` and the arg-reductions along axes.
+//!
+//! `f32` inputs accumulate in `f64` and round the result once.
+
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::error::{NumpyError, Result};
+use crate::float::Float;
+use crate::interrupt::Interrupt;
+use crate::math;
+use crate::shape::{
+    check_size, dim_offsets, normalize_axis, reduced_shape, reduction_axes, DimOffsets,
+};
+use crate::validate;
+
+fn widened<T: Float>(a: &[T]) -> impl Iterator<Item = f64> + Clone + '_ {
+    a.iter().map(|x| x.to_f64())
+}
+
+pub fn sum<T: Float>(a: &[T]) -> T {
+    T::from_f64(widened(a).sum())
+}
+
+pub fn prod<T: Float>(a: &[T]) -> T {
+    T::from_f64(widened(a).product())
+}
+
+pub fn mean<T: Float>(a: &[T]) -> T {
+    T::from_f64(mean_f64(widened(a)))
+}
+
+/// Population variance (`ddof=0`).
+pub fn var<T: Float>(a: &[T]) -> T {
+    T::from_f64(var_f64(widened(a)))
+}
+
+pub fn std<T: Float>(a: &[T]) -> T {
+    T::from_f64(math::sqrt(var_f64(widened(a))))
+}
+
+/// NaN when any element is NaN, like `np.max`; an error when empty.
+pub fn max<T: Float>(a: &[T]) -> Result<T> {
+    extreme(a, "maximum", |x, best| x > best)
+}
+
+/// NaN when any element is NaN, like `np.min`; an error when empty.
+pub fn min<T: Float>(a: &[T]) -> Result<T> {
+    extreme(a, "minimum", |x, best| x < best)
+}
+
+/// The element `better` prefers over every other, or the first NaN.
+fn extreme<T: Float>(a: &[T], reduction: &str, better: fn(f64, f64) -> bool) -> Result<T> {
+    let mut values = widened(a);
+    let mut best = values
+        .next()
+        .ok_or_else(|| NumpyError::no_identity(reduction))?;
+    for x in values {
+        if best.is_nan() {
+            break;
+        }
+        if x.is_nan() || better(x, best) {
+            best = x;
+        }
+    }
+    Ok(T::from_f64(best))
+}
+
+/// Mean of `values`; `0` when there are none.
+fn mean_f64(values: impl Iterator<Item = f64> + Clone) -> f64 {
+    let count = values.clone().count();
+    if count == 0 {
+        return 0.0;
+    }
+    values.sum::<f64>() / count as f64
+}
+
+fn var_f64(values: impl Iterator<Item = f64> + Clone) -> f64 {
+    Moments::of(values).var(0.0)
+}
+
+/// Count, mean and sum of squared deviations (`m2`) of a run of values.
+///
+/// Values are taken relative to the first one, so data far from zero relative
+/// to its spread subtracts exactly, and runs are summed in short blocks merged
+/// pairwise with Chan et al.'s update, so rounding error grows with `log n`.
+#[derive(Clone, Copy, Debug, Default)]
+struct Moments {
+    count: f64,
+    mean: f64,
+    m2: f64,
+}
+
+impl Moments {
+    /// Length of the blocks summed with Welford's update directly.
+    const BLOCK: f64 = 8.0;
+
+    const EMPTY: Self = Self {
+        count: 0.0,
+        mean: 0.0,
+        m2: 0.0,
+    };
+
+    fn of(values: impl IntoIterator<Item = f64>) -> Self {
+        let mut moments = MomentsAccumulator::new();
+        values.into_iter().for_each(|x| moments.push(x));
+        moments.finish()
+    }
+
+    /// Welford's update with one more value.
+    fn push(&mut self, x: f64) {
+        self.count += 1.0;
+        let delta = x - self.mean;
+        self.mean += delta / self.count;
+        self.m2 += delta * (x - self.mean);
+    }
+
+    fn merge(self, other: Self) -> Self {
+        if other.count == 0.0 {
+            return self;
+        }
+        if self.count == 0.0 {
+            return other;
+        }
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        Self {
+            count,
+            mean: self.mean + delta * (other.count / count),
+            m2: self.m2 + other.m2 + delta * delta * (self.count * other.count / count),
+        }
+    }
+
+    /// `m2 / max(count - ddof, 0)`: NaN with no values, and NaN or inf when
+    /// `ddof` leaves no degrees of freedom, as NumPy gives.
+    fn var(self, ddof: f64) -> f64 {
+        self.m2 / (self.count - ddof).max(0.0)
+    }
+}
+
+/// Streams values into [`Moments`] without buffering them.
+///
+/// Full blocks carry into `levels` like a binary counter, where slot `k`
+/// holds the merge of `2^k` blocks, so equal-sized runs are merged as a
+/// split-in-half recursion would.
+struct MomentsAccumulator {
+    shift: Option<f64>,
+    block: Moments,
+    levels: [Moments; usize::BITS as usize],
+}
+
+impl MomentsAccumulator {
+    fn new() -> Self {
+        Self {
+            shift: None,
+            block: Moments::EMPTY,
+            levels: [Moments::EMPTY; usize::BITS as usize],
+        }
+    }
+
+    fn push(&mut self, x: f64) {
+        let shift = *self.shift.get_or_insert(x);
+        self.block.push(x - shift);
+        if self.block.count < Moments::BLOCK {
+            return;
+        }
+        let mut carry = core::mem::take(&mut self.block);
+        for level in &mut self.levels {
+            if level.count == 0.0 {
+                *level = carry;
+                return;
+            }
+            carry = core::mem::take(level).merge(carry);
+        }
+    }
+
+    fn finish(self) -> Moments {
+        let runs = self.levels.into_iter().rev().chain([self.block]);
+        runs.fold(Moments::EMPTY, Moments::merge)
+    }
+}
+
+/// `np.var(a, axis, ddof=ddof, keepdims=keepdims, where=mask)` for a
+/// C-contiguous array of `shape`, returning the result and its shape.
+///
+/// `axis` is `None` for every axis or a set of distinct axes. `mask` has one
+/// entry per element (broadcast by the caller); only `true` elements count.
+pub fn var_axis<T: Float>(
+    a: &[T],
+    shape: &[usize],
+    axis: Option<&[i64]>,
+    ddof: f64,
+    keepdims: bool,
+    mask: Option<&[bool]>,
+    interrupt: &impl Interrupt,
+) -> Result<(Vec<T>, Vec<usize>)> {
+    let (var, shape) = var_lanes(a, shape, axis, ddof, keepdims, mask, interrupt)?;
+    Ok((var.into_iter().map(T::from_f64).collect(), shape))
+}
+
+/// `np.std` with the arguments of [`var_axis`].
+pub fn std_axis<T: Float>(
+    a: &[T],
+    shape: &[usize],
+    axis: Option<&[i64]>,
+    ddof: f64,
+    keepdims: bool,
+    mask: Option<&[bool]>,
+    interrupt: &impl Interrupt,
+) -> Result<(Vec<T>, Vec<usize>)> {
+    let (var, shape) = var_lanes(a, shape, axis, ddof, keepdims, mask, interrupt)?;
+    let std = var.into_iter().map(|v| T::from_f64(math::sqrt(v)));
+    Ok((std.collect(), shape))
+}
+
+fn var_lanes<T: Float>(
+    a: &[T],
+    shape: &[usize],
+    axis: Option<&[i64]>,
+    ddof: f64,
+    keepdims: bool,
+    mask: Option<&[bool]>,
+    interrupt: &impl Interrupt,
+) -> Result<(Vec<f64>, Vec<usize>)> {
+    check_size(a.len(), shape)?;
+    if let Some(mask) = mask {
+        validate::same_len(a.len(), mask.len())?;
+    }
+    let axes = reduction_axes(axis, shape.len())?;
+    let kept: Vec<usize> = (0..shape.len()).filter(|d| !axes.contains(d)).collect();
+    let mut inner = DimOffsets::new(shape, &axes);
+
+    let mut out = Vec::with_capacity(kept.iter().map(|&d| shape[d]).product());
+    for base in DimOffsets::new(shape, &kept) {
+        interrupt.check()?;
+        inner.restart();
+        let lane = inner
+            .by_ref()
+            .map(|offset| base + offset)
+            .filter(|&i| mask.map_or(true, |mask| mask[i]))
+            .map(|i| a[i].to_f64());
+        out.push(Moments::of(lane).var(ddof));
+    }
+    Ok((out, reduced_shape(shape, &axes, keepdims)))
+}
+
+/// Index of the first maximum, or of the first NaN, like `np.argmax`.
+pub fn argmax<T: Float>(a: &[T]) -> Result<usize> {
+    arg_extreme(widened(a), ARGMAX)
+}
+
+/// Index of the first minimum, or of the first NaN, like `np.argmin`.
+pub fn argmin<T: Float>(a: &[T]) -> Result<usize> {
+    arg_extreme(widened(a), ARGMIN)
+}
+
+/// Index of the first maximum skipping NaN, like `np.nanargmax`.
+pub fn nanargmax<T: Float>(a: &[T]) -> Result<usize> {
+    arg_extreme(widened(a), NANARGMAX)
+}
+
+/// Index of the first minimum skipping NaN, like `np.nanargmin`.
+pub fn nanargmin<T: Float>(a: &[T]) -> Result<usize> {
+    arg_extreme(widened(a), NANARGMIN)
+}
+
+/// `np.argmax(a, axis, keepdims=keepdims)` for a C-contiguous array of
+/// `shape`, returning the indices and their shape.
+///
+/// With `axis` `None` the single index is into the flattened array.
+pub fn argmax_axis<T: Float>(
+    a: &[T],
+    shape: &[usize],
+    axis: Option<i64>,
+    keepdims: bool,
+    interrupt: &impl Interrupt,
+) -> Result<(Vec<i64>, Vec<usize>)> {
+    arg_lanes(a, shape, axis, keepdims, ARGMAX, interrupt)
+}
+
+/// `np.argmin` with the arguments of [`argmax_axis`].
+pub fn argmin_axis<T: Float>(
+    a: &[T],
+    shape: &[usize],
+    axis: Option<i64>,
+    keepdims: bool,
+    interrupt: &impl Interrupt,
+) -> Result<(Vec<i64>, Vec<usize>)> {
+    arg_lanes(a, shape, axis, keepdims, ARGMIN, interrupt)
+}
+
+/// `np.nanargmax` with the arguments of [`argmax_axis`]; an all-NaN lane is
+/// an error.
+pub fn nanargmax_axis<T: Float>(
+    a: &[T],
+    shape: &[usize],
+    axis: Option<i64>,
+    keepdims: bool,
+    interrupt: &impl Interrupt,
+) -> Result<(Vec<i64>, Vec<usize>)> {
+    arg_lanes(a, shape, axis, keepdims, NANARGMAX, interrupt)
+}
+
+/// `np.nanargmin` with the arguments of [`argmax_axis`].
+pub fn nanargmin_axis<T: Float>(
+    a: &[T],
+    shape: &[usize],
+    axis: Option<i64>,
+    keepdims: bool,
+    interrupt: &impl Interrupt,
+) -> Result<(Vec<i64>, Vec<usize>)> {
+    arg_lanes(a, shape, axis, keepdims, NANARGMIN, interrupt)
+}
+
+/// How an arg-reduction picks its element.
+#[derive(Clone, Copy)]
+struct ArgPick {
+    /// The name in NumPy's empty-input error, which `nanargmax` shares.
+    name: &'static str,
+    better: fn(f64, f64) -> bool,
+    skip_nan: bool,
+}
+
+const ARGMAX: ArgPick = ArgPick {
+    name: "argmax",
+    better: |x, best| x > best,
+    skip_nan: false,
+};
+const ARGMIN: ArgPick = ArgPick {
+    name: "argmin",
+    better: |x, best| x < best,
+    skip_nan: false,
+};
+const NANARGMAX: ArgPick = ArgPick {
+    skip_nan: true,
+    ..ARGMAX
+};
+const NANARGMIN: ArgPick = ArgPick {
+    skip_nan: true,
+    ..ARGMIN
+};
+
+/// Index of the first value `pick.better` prefers over every earlier one.
+/// Without `skip_nan` the first NaN wins outright.
+fn arg_extreme(values: impl Iterator<Item = f64>, pick: ArgPick) -> Result<usize> {
+    let mut best: Option<(usize, f64)> = None;
+    let mut empty = true;
+    for (i, x) in values.enumerate() {
+        empty = false;
+        if x.is_nan() {
+            if pick.skip_nan {
+                continue;
+            }
+            return Ok(i);
+        }
+        if best.map_or(true, |(_, value)| (pick.better)(x, value)) {
+            best = Some((i, x));
+        }
+    }
+    match best {
+        Some((i, _)) => Ok(i),
+        None if empty => Err(NumpyError::value(format!(
+            "attempt to get {} of an empty sequence",
+            pick.name
+        ))),
+        None => Err(NumpyError::value("All-NaN slice encountered")),
+    }
+}
+
+fn arg_lanes<T: Float>(
+    a: &[T],
+    shape: &[usize],
+    axis: Option<i64>,
+    keepdims: bool,
+    pick: ArgPick,
+    interrupt: &impl Interrupt,
+) -> Result<(Vec<i64>, Vec<usize>)> {
+    check_size(a.len(), shape)?;
+    let axes = match axis {
+        Some(axis) => vec![normalize_axis(axis, shape.len())?],
+        None => (0..shape.len()).collect(),
+    };
+    let kept: Vec<usize> = (0..shape.len()).filter(|d| !axes.contains(d)).collect();
+    let (outer, inner) = (dim_offsets(shape, &kept), dim_offsets(shape, &axes));
+
+    let mut out = Vec::with_capacity(outer.len());
+    for base in outer {
+        interrupt.check()?;
+        let lane = inner.iter().map(|&offset| a[base + offset].to_f64());
+        out.push(arg_extreme(lane, pick)? as i64);
+    }
+    Ok((out, reduced_shape(shape, &axes, keepdims)))
+}
+
+fn non_nan<T: Float>(a: &[T]) -> impl Iterator<Item = f64> + Clone + '_ {
+    widened(a).filter(|x| !x.is_nan())
+}
+
+pub fn nansum<T: Float>(a: &[T]) -> T {
+    T::from_f64(non_nan(a).sum())
+}
+
+/// Mean of the non-NaN elements; NaN when there are none.
+pub fn nanmean<T: Float>(a: &[T]) -> T {
+    if non_nan(a).next().is_none() {
+        return T::from_f64(f64::NAN);
+    }
+    T::from_f64(mean_f64(non_nan(a)))
+}
+
+pub fn nanvar<T: Float>(a: &[T]) -> T {
+    if non_nan(a).next().is_none() {
+        return T::from_f64(f64::NAN);
+    }
+    T::from_f64(var_f64(non_nan(a)))
+}
+
+pub fn nanstd<T: Float>(a: &[T]) -> T {
+    T::from_f64(math::sqrt(nanvar(a).to_f64()))
+}
+
+/// Maximum of the non-NaN elements, like `np.nanmax`: NaN when every
+/// element is NaN, an error when empty.
+pub fn nanmax<T: Float>(a: &[T]) -> Result<T> {
+    nan_extreme(a, "fmax", |x, best| x > best)
+}
+
+/// Minimum of the non-NaN elements, like `np.nanmin`.
+pub fn nanmin<T: Float>(a: &[T]) -> Result<T> {
+    nan_extreme(a, "fmin", |x, best| x < best)
+}
+
+fn nan_extreme<T: Float>(a: &[T], reduction: &str, better: fn(f64, f64) -> bool) -> Result<T> {
+    if a.is_empty() {
+        return Err(NumpyError::no_identity(reduction));
+    }
+    let best = non_nan(a).reduce(|best, x| if better(x, best) { x } else { best });
+    Ok(T::from_f64(best.unwrap_or(f64::NAN)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interrupt::Never;
+    use alloc::vec;
+
+    #[test]
+    fn test_mean_and_variance() {
+        assert_eq!(mean(&[2.0, 4.0, 6.0, 8.0]), 5.0);
+        assert_eq!(var(&[2.0, 4.0, 6.0, 8.0]), 5.0);
+        assert_eq!(nanmean(&[1.0, f64::NAN, 3.0]), 2.0);
+        assert!(nanmean(&[f64::NAN]).is_nan());
+    }
+
+    #[test]
+    fn test_var_is_stable_far_from_zero() {
+        // Squaring the mean would lose everything below 1e18 * eps = 222.
+        let a: Vec<f64> = [4.0, 7.0, 13.0, 16.0].iter().map(|x| 1e9 + x).collect();
+        assert_eq!(var(&a), 22.5);
+        let many: Vec<f64> = (0..10_000).map(|i| 1e12 + (i % 2) as f64).collect();
+        assert!((var(&many) - 0.25).abs() < 1e-15);
+        // A partial last block and uneven carries: var(0..n) = (n^2 - 1) / 12.
+        let ramp: Vec<f64> = (0..1001).map(f64::from).collect();
+        assert!((var(&ramp) - 83_500.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_var_axis_ddof_keepdims_where() {
+        // [[1, 2, 3], [4, 6, 11]]
+        let a = [1.0f64, 2.0, 3.0, 4.0, 6.0, 11.0];
+        let var = |axis: Option<&[i64]>, ddof, keepdims, mask| {
+            var_axis(&a, &[2, 3], axis, ddof, keepdims, mask, &Never).unwrap()
+        };
+        assert_eq!(
+            var(Some(&[1]), 1.0, false, None),
+            (vec![1.0, 13.0], vec![2])
+        );
+        assert_eq!(var(Some(&[0]), 0.0, true, None).0, vec![2.25, 4.0, 16.0]);
+        assert_eq!(var(Some(&[0]), 0.0, true, None).1, vec![1, 3]);
+        assert_eq!(var(None, 0.0, false, None).1, Vec::<usize>::new());
+        assert_eq!(
+            var(Some(&[1, 0]), 0.0, false, None).0,
+            var(None, 0.0, false, None).0
+        );
+        let mask = [true, true, false, false, true, true];
+        assert_eq!(
+            var(Some(&[-1]), 0.0, false, Some(&mask)).0,
+            vec![0.25, 6.25]
+        );
+
+        // No degrees of freedom: NaN for a constant lane, inf otherwise.
+        let (edge, _) = var(
+            Some(&[1]),
+            3.0,
+            false,
+            Some(&[true, true, true, true, false, false]),
+        );
+        assert!(edge[0].is_infinite() && edge[1].is_nan());
+        let (std, _) = std_axis(
+            &[2.0f32, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0],
+            &[8],
+            None,
+            0.0,
+            false,
+            None,
+            &Never,
+        )
+        .unwrap();
+        assert_eq!(std, vec![2.0f32]);
+        assert_eq!(
+            var_axis(&a, &[2, 3], Some(&[2]), 0.0, false, None, &Never)
+                .unwrap_err()
+                .axis,
+            Some(2)
+        );
+        assert!(var_axis(&a, &[2, 3], None, 0.0, false, Some(&[true]), &Never).is_err());
+    }
+
+    #[test]
+    fn test_max_min_propagate_nan() {
+        assert_eq!(max(&[1.0, 3.0, -2.0]).unwrap(), 3.0);
+        assert_eq!(min(&[1.0, 3.0, -2.0]).unwrap(), -2.0);
+        assert!(max(&[1.0, f64::NAN, 3.0]).unwrap().is_nan());
+        assert!(min(&[f64::NAN, 1.0]).unwrap().is_nan());
+        assert_eq!(nanmax(&[1.0, f64::NAN, 3.0]).unwrap(), 3.0);
+        assert_eq!(nanmin(&[f64::NAN, 2.0, -1.0]).unwrap(), -1.0);
+        assert!(nanmax(&[f64::NAN, f64::NAN]).unwrap().is_nan());
+        assert!(nanmin(&[f32::NAN]).unwrap().is_nan());
+        assert_eq!(
+            nanmax::<f64>(&[]).unwrap_err().message,
+            "zero-size array to reduction operation fmax which has no identity"
+        );
+        assert_eq!(
+            max::<f64>(&[]).unwrap_err().message,
+            "zero-size array to reduction operation maximum which has no identity"
+        );
+        assert_eq!(
+            min::<f32>(&[]).unwrap_err().message,
+            "zero-size array to reduction operation minimum which has no identity"
+        );
+    }
+
+    #[test]
+    fn test_arg_extremes() {
+        assert_eq!(argmax(&[1.0, 3.0, 3.0, 2.0]).unwrap(), 1);
+        assert_eq!(argmin(&[2.0, 1.0, 1.0]).unwrap(), 1);
+        assert_eq!(
+            argmax::<f64>(&[]).unwrap_err().message,
+            "attempt to get argmax of an empty sequence"
+        );
+
+        let a = [2.0, f64::NAN, 5.0, f64::NAN, 5.0];
+        assert_eq!(argmax(&a).unwrap(), 1);
+        assert_eq!(argmin(&a).unwrap(), 1);
+        assert_eq!(nanargmax(&a).unwrap(), 2);
+        assert_eq!(nanargmin(&a).unwrap(), 0);
+        assert_eq!(
+            nanargmin(&[f64::NAN, f64::NAN]).unwrap_err().message,
+            "All-NaN slice encountered"
+        );
+        assert_eq!(
+            nanargmin::<f32>(&[]).unwrap_err().message,
+            "attempt to get argmin of an empty sequence"
+        );
+    }
+
+    #[test]
+    fn test_arg_extremes_along_axis() {
+        // [[3, 1, 3],
+        //  [0, NaN, 4]]
+        let a = [3.0, 1.0, 3.0, 0.0, f64::NAN, 4.0];
+        let (idx, shape) = argmax_axis(&a, &[2, 3], Some(1), false, &Never).unwrap();
+        assert_eq!((idx, shape), (vec![0, 1], vec![2]));
+        let (idx, shape) = nanargmax_axis(&a, &[2, 3], Some(-1), true, &Never).unwrap();
+        assert_eq!((idx, shape), (vec![0, 2], vec![2, 1]));
+        let (idx, _) = argmin_axis(&a, &[2, 3], Some(0), false, &Never).unwrap();
+        assert_eq!(idx, vec![1, 1, 0]);
+        let (idx, _) = nanargmin_axis(&a, &[2, 3], Some(0), false, &Never).unwrap();
+        assert_eq!(idx, vec![1, 0, 0]);
+        let (idx, shape) = argmin_axis(&a, &[2, 3], None, true, &Never).unwrap();
+        assert_eq!((idx, shape), (vec![4], vec![1, 1]));
+        let (idx, shape) = nanargmin_axis(&a, &[2, 3], None, false, &Never).unwrap();
+        assert_eq!((idx, shape), (vec![3], vec![]));
+
+        // No lanes to reduce is fine; an empty lane is not.
+        let (idx, shape) = argmax_axis::<f64>(&[], &[0, 3], Some(1), false, &Never).unwrap();
+        assert_eq!((idx, shape), (vec![], vec![0]));
+        assert!(argmax_axis::<f64>(&[], &[0, 3], Some(0), false, &Never).is_err());
+        assert!(argmax_axis(&a, &[2, 3], Some(2), false, &Never).is_err());
+    }
+
+    #[test]
+    fn test_float32_accumulates_in_f64() {
+        let a = [1.0e8f32, 1.0, -1.0e8];
+        assert_eq!(sum(&a), 1.0f32);
+        assert_eq!(max(&a).unwrap(), 1.0e8f32);
+        assert_eq!(argmin(&a).unwrap(), 2);
+        assert!(nanmean(&[f32::NAN]).is_nan());
+    }
+}
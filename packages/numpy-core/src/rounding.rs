@@ -0,0 +1,106 @@
+//! `np.round` / `np.around` with `decimals`, for float and integer dtypes.
+//!
+//! NumPy rounds half to even, so `np.round(2.5) == 2` where `f64::round`
+//! gives 3. `np.rint` is `decimals=0` and `np.fix` is truncation; both are
+//! plain [`UnaryOp`](crate::elementwise::UnaryOp)s.
+
+use crate::error::Result;
+use crate::math;
+use crate::validate;
+
+/// Element types `np.round` accepts.
+pub trait Round: Copy {
+    /// `np.round(self, decimals)`.
+    fn round_to(self, decimals: i32) -> Self;
+}
+
+/// `10^|decimals|`, infinite once it leaves the `f64` range.
+fn scale(decimals: i32) -> f64 {
+    math::powf(10.0, f64::from(decimals.unsigned_abs()))
+}
+
+/// NumPy's algorithm: multiply by `10^decimals` (or divide for negative
+/// `decimals`), round half to even, then undo the scaling in the element
+/// type. A scale beyond the float range leaves the value as is, or rounds
+/// it to zero for negative `decimals`.
+macro_rules! float_round {
+    ($($t:ty),*) => {
+        $(
+            impl Round for $t {
+                fn round_to(self, decimals: i32) -> Self {
+                    let factor = scale(decimals) as $t;
+                    if !factor.is_finite() {
+                        return if decimals >= 0 || !self.is_finite() { self } else { self * 0.0 };
+                    }
+                    if decimals >= 0 {
+                        math::rint((self * factor) as f64) as $t / factor
+                    } else {
+                        math::rint((self / factor) as f64) as $t * factor
+                    }
+                }
+            }
+        )*
+    };
+}
+
+float_round!(f64, f32);
+
+/// Integers are already whole, so only negative `decimals` change them;
+/// NumPy rounds those through `float64`.
+macro_rules! int_round {
+    ($($t:ty),*) => {
+        $(
+            impl Round for $t {
+                fn round_to(self, decimals: i32) -> Self {
+                    if decimals >= 0 {
+                        return self;
+                    }
+                    (self as f64).round_to(decimals) as $t
+                }
+            }
+        )*
+    };
+}
+
+int_round!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+/// `out[i] = np.round(input[i], decimals)`.
+pub fn round_into<T: Round>(input: &[T], decimals: i32, out: &mut [T]) -> Result<()> {
+    validate::output_len(input.len(), out.len())?;
+    for (slot, &x) in out.iter_mut().zip(input) {
+        *slot = x.round_to(decimals);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_half_to_even() {
+        let mut out = [0.0; 5];
+        round_into(&[0.5, 1.5, 2.5, -2.5, 3.7], 0, &mut out).unwrap();
+        assert_eq!(out, [0.0, 2.0, 2.0, -2.0, 4.0]);
+        assert_eq!(0.125f64.round_to(2), 0.12);
+        assert_eq!(2.5f32.round_to(0), 2.0);
+    }
+
+    #[test]
+    fn test_negative_decimals() {
+        assert_eq!(1250.0f64.round_to(-2), 1200.0);
+        assert_eq!(1350.0f64.round_to(-2), 1400.0);
+        assert_eq!(25i32.round_to(-1), 20);
+        assert_eq!((-35i64).round_to(-1), -40);
+        assert_eq!(7u8.round_to(3), 7);
+    }
+
+    #[test]
+    fn test_extreme_decimals() {
+        assert_eq!(1.5f64.round_to(400), 1.5);
+        assert_eq!(123.0f64.round_to(-400), 0.0);
+        assert!((-123.0f64).round_to(-400).is_sign_negative());
+        assert!(f64::NAN.round_to(2).is_nan());
+        assert_eq!(f64::INFINITY.round_to(-2), f64::INFINITY);
+    }
+}
@@ -0,0 +1,300 @@
+//! Shape arithmetic: broadcasting, reshape inference and axis normalization.
+
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::error::{NumpyError, Result};
+use crate::validate::too_big;
+
+/// Row-major strides in elements.
+pub fn c_strides(shape: &[usize]) -> Vec<isize> {
+    let mut strides = vec![0isize; shape.len()];
+    let mut stride = 1isize;
+    for (slot, &dim) in strides.iter_mut().zip(shape).rev() {
+        *slot = stride;
+        stride *= dim.max(1) as isize;
+    }
+    strides
+}
+
+/// The shape two operands broadcast to, following NumPy's right-aligned rules.
+pub fn broadcast_shapes(left: &[usize], right: &[usize]) -> Result<Vec<usize>> {
+    let ndim = left.len().max(right.len());
+    let mut shape = vec![0; ndim];
+    for (axis, slot) in shape.iter_mut().enumerate() {
+        let l = left.len().checked_sub(ndim - axis).map_or(1, |i| left[i]);
+        let r = right.len().checked_sub(ndim - axis).map_or(1, |i| right[i]);
+        *slot = match (l, r) {
+            (l, r) if l == r => l,
+            (1, r) => r,
+            (l, 1) => l,
+            _ => return Err(NumpyError::broadcast(left, right)),
+        };
+    }
+    Ok(shape)
+}
+
+/// Strides that read an array of `shape` as if it had the broadcast `target`
+/// shape: stretched and prepended axes get stride 0.
+pub fn broadcast_strides(shape: &[usize], strides: &[isize], target: &[usize]) -> Vec<isize> {
+    let pad = target.len() - shape.len();
+    (0..target.len())
+        .map(|axis| {
+            if axis < pad || shape[axis - pad] == 1 {
+                0
+            } else {
+                strides[axis - pad]
+            }
+        })
+        .collect()
+}
+
+pub fn to_usize_shape(shape: &[i64]) -> Result<Vec<usize>> {
+    shape
+        .iter()
+        .map(|&dim| {
+            usize::try_from(dim)
+                .map_err(|_| NumpyError::value("negative dimensions are not allowed"))
+        })
+        .collect()
+}
+
+/// Number of elements in `shape`, failing instead of overflowing.
+pub fn checked_size(shape: &[usize]) -> Result<usize> {
+    shape
+        .iter()
+        .try_fold(1usize, |acc, &dim| acc.checked_mul(dim))
+        .ok_or_else(too_big)
+}
+
+/// Resolve a reshape target with at most one `-1` against `size` elements.
+pub fn infer_shape(shape: &[i64], size: usize) -> Result<Vec<usize>> {
+    let unknown = shape.iter().filter(|&&dim| dim == -1).count();
+    if unknown > 1 {
+        return Err(NumpyError::value("can only specify one unknown dimension"));
+    }
+
+    let known: Vec<i64> = shape.iter().copied().filter(|&dim| dim != -1).collect();
+    let known_size = checked_size(&to_usize_shape(&known)?)?;
+    let mismatch = || {
+        NumpyError::value(format!(
+            "cannot reshape array of size {} into shape {:?}",
+            size, shape
+        ))
+    };
+
+    let resolved = shape
+        .iter()
+        .map(|&dim| {
+            if dim == -1 {
+                if known_size == 0 || size % known_size != 0 {
+                    Err(mismatch())
+                } else {
+                    Ok(size / known_size)
+                }
+            } else {
+                Ok(dim as usize)
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    if checked_size(&resolved)? != size {
+        return Err(mismatch());
+    }
+    Ok(resolved)
+}
+
+/// Map a possibly negative axis into `0..ndim`.
+pub fn normalize_axis(axis: i64, ndim: usize) -> Result<usize> {
+    let resolved = if axis < 0 { axis + ndim as i64 } else { axis };
+    if resolved < 0 || resolved >= ndim as i64 {
+        return Err(NumpyError::axis(axis, ndim));
+    }
+    Ok(resolved as usize)
+}
+
+/// Map a possibly negative index into `0..dim`, the length of `axis`.
+pub fn normalize_index(index: i64, dim: usize, axis: usize) -> Result<usize> {
+    let resolved = if index < 0 { index + dim as i64 } else { index };
+    if resolved < 0 || resolved >= dim as i64 {
+        return Err(NumpyError::index(format!(
+            "index {} is out of bounds for axis {} with size {}",
+            index, axis, dim
+        )));
+    }
+    Ok(resolved as usize)
+}
+
+/// Fail like NumPy's reshape unless `shape` holds exactly `len` elements.
+pub fn check_size(len: usize, shape: &[usize]) -> Result<()> {
+    if checked_size(shape)? != len {
+        return Err(NumpyError::value(format!(
+            "cannot reshape array of size {} into shape {:?}",
+            len, shape
+        )));
+    }
+    Ok(())
+}
+
+/// A reduction's `axis` argument over `ndim` dimensions: every axis when
+/// `None`, otherwise each one normalized, with repeats rejected.
+pub fn reduction_axes(axis: Option<&[i64]>, ndim: usize) -> Result<Vec<usize>> {
+    let axis = match axis {
+        Some(axis) => axis,
+        None => return Ok((0..ndim).collect()),
+    };
+    let mut axes = Vec::with_capacity(axis.len());
+    for &ax in axis {
+        let ax = normalize_axis(ax, ndim)?;
+        if axes.contains(&ax) {
+            return Err(NumpyError::value("duplicate value in 'axis'"));
+        }
+        axes.push(ax);
+    }
+    Ok(axes)
+}
+
+/// The shape a reduction over `axes` leaves: those axes dropped, or kept
+/// with length 1 under `keepdims`.
+pub fn reduced_shape(shape: &[usize], axes: &[usize], keepdims: bool) -> Vec<usize> {
+    (0..shape.len())
+        .filter_map(|d| match (axes.contains(&d), keepdims) {
+            (false, _) => Some(shape[d]),
+            (true, true) => Some(1),
+            (true, false) => None,
+        })
+        .collect()
+}
+
+/// Flat offsets into a C-contiguous array of `shape` of every index over
+/// `dims`, the other axes held at 0, in C order.
+///
+/// Offsets over the kept axes plus offsets over the reduced axes address
+/// each lane of a reduction.
+pub fn dim_offsets(shape: &[usize], dims: &[usize]) -> Vec<usize> {
+    DimOffsets::new(shape, dims).collect()
+}
+
+/// [`dim_offsets`] yielded one at a time, for reductions whose lanes are too
+/// many or too long to collect.
+#[derive(Clone, Debug)]
+pub struct DimOffsets {
+    /// Length and stride of each axis in `dims`, outermost first.
+    extents: Vec<(usize, usize)>,
+    index: Vec<usize>,
+    next: Option<usize>,
+}
+
+impl DimOffsets {
+    pub fn new(shape: &[usize], dims: &[usize]) -> Self {
+        let strides = c_strides(shape);
+        let extents: Vec<(usize, usize)> = (0..shape.len())
+            .filter(|d| dims.contains(d))
+            .map(|d| (shape[d], strides[d] as usize))
+            .collect();
+        let mut offsets = Self {
+            index: vec![0; extents.len()],
+            extents,
+            next: None,
+        };
+        offsets.restart();
+        offsets
+    }
+
+    /// Starts again from the first offset.
+    pub fn restart(&mut self) {
+        self.index.fill(0);
+        let empty = self.extents.iter().any(|&(len, _)| len == 0);
+        self.next = (!empty).then_some(0);
+    }
+}
+
+impl Iterator for DimOffsets {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let offset = self.next?;
+        let mut next = offset;
+        for (i, &(len, stride)) in self.index.iter_mut().zip(&self.extents).rev() {
+            *i += 1;
+            if *i < len {
+                self.next = Some(next + stride);
+                return Some(offset);
+            }
+            *i = 0;
+            next -= (len - 1) * stride;
+        }
+        self.next = None;
+        Some(offset)
+    }
+}
+
+/// [`dim_offsets`] for a view with element `strides`, relative to the
+/// view's first element.
+pub fn strided_dim_offsets(shape: &[usize], strides: &[isize], dims: &[usize]) -> Vec<isize> {
+    let mut offsets = vec![0isize];
+    for d in 0..shape.len() {
+        if !dims.contains(&d) {
+            continue;
+        }
+        let stride = strides[d];
+        offsets = offsets
+            .iter()
+            .flat_map(|&base| (0..shape[d]).map(move |i| base + i as isize * stride))
+            .collect();
+    }
+    offsets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_broadcast_shapes() {
+        assert_eq!(
+            broadcast_shapes(&[2, 1, 3], &[4, 1]).unwrap(),
+            vec![2, 4, 3]
+        );
+        assert_eq!(
+            broadcast_shapes(&[2, 3], &[2]).unwrap_err().message,
+            "operands could not be broadcast together with shapes (2,3) (2,)"
+        );
+    }
+
+    #[test]
+    fn test_infer_shape_and_axis() {
+        assert_eq!(infer_shape(&[2, -1], 6).unwrap(), vec![2, 3]);
+        assert!(infer_shape(&[-1, -1], 6).is_err());
+        assert!(infer_shape(&[4, -1], 6).is_err());
+        assert_eq!(normalize_axis(-1, 3).unwrap(), 2);
+        assert_eq!(normalize_axis(3, 3).unwrap_err().axis, Some(3));
+    }
+
+    #[test]
+    fn test_reduction_axes_and_offsets() {
+        assert_eq!(reduction_axes(None, 3).unwrap(), vec![0, 1, 2]);
+        assert_eq!(reduction_axes(Some(&[-1, 0]), 3).unwrap(), vec![2, 0]);
+        assert_eq!(
+            reduction_axes(Some(&[1, -2]), 3).unwrap_err().message,
+            "duplicate value in 'axis'"
+        );
+        assert_eq!(reduced_shape(&[2, 3, 4], &[2, 0], false), vec![3]);
+        assert_eq!(reduced_shape(&[2, 3, 4], &[2, 0], true), vec![1, 3, 1]);
+        // Shape (2, 3, 4): the kept axis 1 has stride 4, the others 12 and 1.
+        assert_eq!(dim_offsets(&[2, 3, 4], &[1]), vec![0, 4, 8]);
+        assert_eq!(dim_offsets(&[2, 3, 4], &[2, 0])[..5], [0, 1, 2, 3, 12]);
+        assert_eq!(dim_offsets(&[2, 3], &[]), vec![0]);
+        assert_eq!(dim_offsets(&[2, 0, 3], &[0, 1]), Vec::<usize>::new());
+
+        let mut offsets = DimOffsets::new(&[2, 3, 4], &[0, 2]);
+        assert_eq!(
+            offsets.by_ref().take(6).collect::<Vec<_>>(),
+            [0, 1, 2, 3, 12, 13]
+        );
+        offsets.restart();
+        assert_eq!(offsets.count(), 8);
+        assert!(check_size(5, &[2, 3]).is_err());
+    }
+}
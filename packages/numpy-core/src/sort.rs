@@ -0,0 +1,90 @@
+//! Sorting in NumPy order: ascending with NaN last.
+
+use alloc::vec;
+use core::cmp::Ordering;
+
+use crate::error::Result;
+use crate::interrupt::Interrupt;
+
+/// Elements sorted per run before merging; the interrupt is checked per run
+/// and per merge pass.
+const RUN: usize = 1 << 16;
+
+/// The total order `np.sort` uses for floats: NaN compares greater than
+/// everything, including infinity.
+pub fn numpy_order(a: &f64, b: &f64) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (false, false) => a.partial_cmp(b).unwrap_or(Ordering::Equal),
+        (a_nan, b_nan) => a_nan.cmp(&b_nan),
+    }
+}
+
+/// Sort `values` in place.
+pub fn sort_in_place(values: &mut [f64], interrupt: &impl Interrupt) -> Result<()> {
+    for run in values.chunks_mut(RUN) {
+        interrupt.check()?;
+        run.sort_unstable_by(numpy_order);
+    }
+
+    if values.len() > RUN {
+        // Bottom-up merge of sorted runs, ping-ponging between two buffers.
+        let mut scratch = vec![0.0; values.len()];
+        let mut width = RUN;
+        let mut in_scratch = false;
+
+        while width < values.len() {
+            interrupt.check()?;
+            let (src, dst) = if in_scratch {
+                (&scratch[..], &mut values[..])
+            } else {
+                (&values[..], &mut scratch[..])
+            };
+            for (start, target) in (0..src.len())
+                .step_by(2 * width)
+                .zip(dst.chunks_mut(2 * width))
+            {
+                let mid = (start + width).min(src.len());
+                let end = (start + 2 * width).min(src.len());
+                merge(&src[start..mid], &src[mid..end], target);
+            }
+            in_scratch = !in_scratch;
+            width *= 2;
+        }
+
+        if in_scratch {
+            values.copy_from_slice(&scratch);
+        }
+    }
+    Ok(())
+}
+
+fn merge(left: &[f64], right: &[f64], out: &mut [f64]) {
+    let (mut i, mut j) = (0, 0);
+    for slot in out.iter_mut() {
+        let take_left =
+            j == right.len() || (i < left.len() && numpy_order(&left[i], &right[j]).is_le());
+        if take_left {
+            *slot = left[i];
+            i += 1;
+        } else {
+            *slot = right[j];
+            j += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interrupt::Never;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_nan_sorts_last_across_runs() {
+        let mut values: Vec<f64> = (0..RUN * 2 + 5).rev().map(|i| i as f64).collect();
+        values[7] = f64::NAN;
+        sort_in_place(&mut values, &Never).unwrap();
+        assert!(values.last().unwrap().is_nan());
+        assert!(values[..values.len() - 1].windows(2).all(|w| w[0] <= w[1]));
+    }
+}
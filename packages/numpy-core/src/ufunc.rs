@@ -0,0 +1,575 @@
+//! The methods NumPy gives every binary ufunc: `reduce`, `accumulate`,
+//! `outer`, `reduceat` and `at`.
+//!
+//! A [`Ufunc`] pairs a [`BinaryOp`] with its NumPy name and identity, so each
+//! elementwise kernel gets the methods without code of its own. Inputs
+//! are C-contiguous; each step is evaluated in `f64` and rounded to the
+//! element type, like the elementwise kernels. Lanes fold left to right,
+//! except that `reduce` folds reorderable ops pairwise like NumPy's `add`.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::elementwise::BinaryOp;
+use crate::error::{NumpyError, Result};
+use crate::float::Float;
+use crate::interrupt::Interrupt;
+use crate::shape::{
+    c_strides, check_size, dim_offsets, normalize_axis, normalize_index, reduced_shape,
+    reduction_axes,
+};
+use crate::validate;
+
+/// Every binary ufunc under its NumPy name.
+const REGISTRY: &[Ufunc] = &[
+    Ufunc::entry("add", BinaryOp::Add),
+    Ufunc::entry("subtract", BinaryOp::Sub),
+    Ufunc::entry("multiply", BinaryOp::Mul),
+    Ufunc::entry("divide", BinaryOp::Div),
+    Ufunc::entry("power", BinaryOp::Pow),
+    Ufunc::entry("maximum", BinaryOp::Maximum),
+    Ufunc::entry("minimum", BinaryOp::Minimum),
+    Ufunc::entry("fmax", BinaryOp::Fmax),
+    Ufunc::entry("fmin", BinaryOp::Fmin),
+    Ufunc::entry("fmod", BinaryOp::Fmod),
+    Ufunc::entry("remainder", BinaryOp::Remainder),
+    Ufunc::entry("floor_divide", BinaryOp::FloorDivide),
+    Ufunc::entry("arctan2", BinaryOp::Arctan2),
+    Ufunc::entry("hypot", BinaryOp::Hypot),
+];
+
+/// Other names NumPy binds to the same ufunc objects.
+const ALIASES: &[(&str, &str)] = &[("true_divide", "divide"), ("mod", "remainder")];
+
+/// A binary ufunc and its methods.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Ufunc {
+    name: &'static str,
+    op: BinaryOp,
+}
+
+impl Ufunc {
+    const fn entry(name: &'static str, op: BinaryOp) -> Self {
+        Self { name, op }
+    }
+
+    /// Look a ufunc up by NumPy name, such as `add`, `floor_divide` or the
+    /// alias `mod`.
+    pub fn parse(name: &str) -> Result<Self> {
+        let canonical = ALIASES
+            .iter()
+            .find(|&&(alias, _)| alias == name)
+            .map_or(name, |&(_, canonical)| canonical);
+        REGISTRY
+            .iter()
+            .find(|ufunc| ufunc.name == canonical)
+            .copied()
+            .ok_or_else(|| NumpyError::value(format!("Unknown ufunc '{}'", name)))
+    }
+
+    pub fn op(self) -> BinaryOp {
+        self.op
+    }
+
+    /// The canonical NumPy name, as used in error messages.
+    pub fn name(self) -> &'static str {
+        self.name
+    }
+
+    /// `ufunc.identity`: what `reduce` returns for an empty lane.
+    pub fn identity(self) -> Option<f64> {
+        match self.op {
+            BinaryOp::Add | BinaryOp::Hypot => Some(0.0),
+            BinaryOp::Mul => Some(1.0),
+            _ => None,
+        }
+    }
+
+    /// Whether the op is associative and commutative, which NumPy requires
+    /// to reduce over more than one axis at once.
+    fn reorderable(self) -> bool {
+        matches!(
+            self.op,
+            BinaryOp::Add
+                | BinaryOp::Mul
+                | BinaryOp::Hypot
+                | BinaryOp::Maximum
+                | BinaryOp::Minimum
+                | BinaryOp::Fmax
+                | BinaryOp::Fmin
+        )
+    }
+
+    /// `ufunc.reduce(a, axis, keepdims=keepdims, initial=initial)` for a
+    /// C-contiguous array of `shape`, returning the result and its shape.
+    ///
+    /// `axis` is `None` for every axis or a set of distinct axes; NumPy's
+    /// default of axis 0 is the caller's to pass. Each lane folds from
+    /// `initial` when given, else from its first element; an empty lane is
+    /// the identity, or an error for ufuncs without one.
+    pub fn reduce<T: Float>(
+        self,
+        a: &[T],
+        shape: &[usize],
+        axis: Option<&[i64]>,
+        keepdims: bool,
+        initial: Option<f64>,
+        interrupt: &impl Interrupt,
+    ) -> Result<(Vec<T>, Vec<usize>)> {
+        check_size(a.len(), shape)?;
+        let axes = reduction_axes(axis, shape.len())?;
+        if axes.len() > 1 && !self.reorderable() {
+            return Err(NumpyError::value(format!(
+                "reduction operation '{}' is not reorderable, so at most one axis may be specified",
+                self.name()
+            )));
+        }
+        let kept: Vec<usize> = (0..shape.len()).filter(|d| !axes.contains(d)).collect();
+        let (outer, inner) = (dim_offsets(shape, &kept), dim_offsets(shape, &axes));
+
+        let mut out = Vec::with_capacity(outer.len());
+        let mut lane = Vec::with_capacity(inner.len());
+        for base in outer {
+            interrupt.check()?;
+            lane.clear();
+            lane.extend(inner.iter().map(|&offset| a[base + offset].to_f64()));
+            let (first, rest) = match (initial, lane.split_first()) {
+                (Some(initial), _) => (Some(initial), &lane[..]),
+                (None, Some((&first, rest))) => (Some(first), rest),
+                (None, None) => (None, &lane[..]),
+            };
+            // Only an empty lane falls back to the identity, so a lone `-0.0`
+            // survives `add.reduce`.
+            let first = match first {
+                Some(first) => first,
+                None => self
+                    .identity()
+                    .ok_or_else(|| NumpyError::no_identity(self.name()))?,
+            };
+            let value = match rest {
+                [] => first,
+                rest if self.reorderable() => self.op.apply(first, self.pairwise(rest)),
+                rest => self.fold(first, rest.iter().copied()),
+            };
+            out.push(T::from_f64(value));
+        }
+        Ok((out, reduced_shape(shape, &axes, keepdims)))
+    }
+
+    /// `ufunc.accumulate(a, axis)`: the running `reduce` along `axis`, in
+    /// the shape of the input.
+    pub fn accumulate<T: Float>(
+        self,
+        a: &[T],
+        shape: &[usize],
+        axis: i64,
+        interrupt: &impl Interrupt,
+    ) -> Result<Vec<T>> {
+        check_size(a.len(), shape)?;
+        let axis = normalize_axis(axis, shape.len())?;
+        let kept: Vec<usize> = (0..shape.len()).filter(|&d| d != axis).collect();
+        let (outer, inner) = (dim_offsets(shape, &kept), dim_offsets(shape, &[axis]));
+
+        let mut out = vec![T::default(); a.len()];
+        for base in outer {
+            interrupt.check()?;
+            let mut acc = None;
+            for &offset in &inner {
+                let x = a[base + offset].to_f64();
+                let next = acc.map_or(x, |acc| self.op.apply(acc, x));
+                // Round every step, as NumPy stores each partial result.
+                out[base + offset] = T::from_f64(next);
+                acc = Some(out[base + offset].to_f64());
+            }
+        }
+        Ok(out)
+    }
+
+    /// `ufunc.outer(a, b)` over the flattened inputs:
+    /// `out[i * b.len() + j] = op(a[i], b[j])`. The result has shape
+    /// `a.shape + b.shape`.
+    pub fn outer_into<T: Float>(self, a: &[T], b: &[T], out: &mut [T]) -> Result<()> {
+        validate::output_len(validate::checked_len(a.len(), b.len())?, out.len())?;
+        for (row, &x) in out.chunks_exact_mut(b.len().max(1)).zip(a) {
+            for (slot, &y) in row.iter_mut().zip(b) {
+                *slot = T::from_f64(self.op.apply(x.to_f64(), y.to_f64()));
+            }
+        }
+        Ok(())
+    }
+
+    /// `ufunc.reduceat(a, indices, axis)`: reduce `a[indices[i]..indices[i + 1]]`
+    /// along `axis`, or take `a[indices[i]]` alone where the next index is not
+    /// larger; the last slice runs to the end. Returns the result and its
+    /// shape, which has `indices.len()` along `axis`.
+    pub fn reduceat<T: Float>(
+        self,
+        a: &[T],
+        shape: &[usize],
+        indices: &[i64],
+        axis: i64,
+        interrupt: &impl Interrupt,
+    ) -> Result<(Vec<T>, Vec<usize>)> {
+        check_size(a.len(), shape)?;
+        let axis = normalize_axis(axis, shape.len())?;
+        let len = shape[axis];
+        let starts = indices
+            .iter()
+            .map(|&index| {
+                usize::try_from(index)
+                    .ok()
+                    .filter(|&start| start < len)
+                    .ok_or_else(|| {
+                        NumpyError::index(format!(
+                            "index {} out-of-bounds in {}.reduceat [0, {})",
+                            index,
+                            self.name(),
+                            len
+                        ))
+                    })
+            })
+            .collect::<Result<Vec<usize>>>()?;
+
+        let mut out_shape = shape.to_vec();
+        out_shape[axis] = starts.len();
+        let kept: Vec<usize> = (0..shape.len()).filter(|&d| d != axis).collect();
+        let (stride, out_stride) = (
+            c_strides(shape)[axis] as usize,
+            c_strides(&out_shape)[axis] as usize,
+        );
+        let bases = dim_offsets(shape, &kept)
+            .into_iter()
+            .zip(dim_offsets(&out_shape, &kept));
+
+        let mut out = vec![T::default(); out_shape.iter().product()];
+        for (base, out_base) in bases {
+            interrupt.check()?;
+            for (i, &start) in starts.iter().enumerate() {
+                let end = match starts.get(i + 1) {
+                    Some(&next) if next > start => next,
+                    Some(_) => start + 1,
+                    None => len,
+                };
+                let mut lane = (start..end).map(|k| a[base + k * stride].to_f64());
+                let first = lane.next().expect("start is in bounds");
+                out[out_base + i * out_stride] = T::from_f64(self.fold(first, lane));
+            }
+        }
+        Ok((out, out_shape))
+    }
+
+    /// `ufunc.at(target, indices, values)`: apply the op in place and
+    /// unbuffered, so an index that repeats is applied every time it appears.
+    ///
+    /// `indices` is a tuple of index arrays for the leading axes of the
+    /// C-contiguous `target`; indices may be negative and arrays of length 1
+    /// broadcast. Each index selects the subarray over the remaining axes, and
+    /// `values` holds one value, one subarray, or one subarray per index.
+    /// Every index is checked before `target` changes.
+    pub fn at<T: Float>(
+        self,
+        target: &mut [T],
+        shape: &[usize],
+        indices: &[&[i64]],
+        values: &[T],
+        interrupt: &impl Interrupt,
+    ) -> Result<()> {
+        check_size(target.len(), shape)?;
+        if indices.len() > shape.len() {
+            return Err(NumpyError::index(format!(
+                "too many indices for array: array is {}-dimensional, but {} were indexed",
+                shape.len(),
+                indices.len()
+            )));
+        }
+        let count = index_count(indices)?;
+        let strides = c_strides(shape);
+        let mut bases = vec![0; count];
+        for (axis, index) in indices.iter().enumerate() {
+            for (i, base) in bases.iter_mut().enumerate() {
+                let index = index[if index.len() == 1 { 0 } else { i }];
+                *base += normalize_index(index, shape[axis], axis)? * strides[axis] as usize;
+            }
+        }
+
+        let inner: usize = shape[indices.len()..].iter().product();
+        let (row_step, col_step) = match values.len() {
+            1 => (0, 0),
+            len if len == inner => (0, 1),
+            len if Some(len) == count.checked_mul(inner) => (inner, 1),
+            len => {
+                let mut expected = vec![count];
+                expected.extend_from_slice(&shape[indices.len()..]);
+                return Err(NumpyError::shape_mismatch(
+                    "array is not broadcastable to correct shape",
+                    &expected,
+                    &[len],
+                ));
+            }
+        };
+        for (i, base) in bases.into_iter().enumerate() {
+            interrupt.check()?;
+            let row = &mut target[base..base + inner];
+            for (j, slot) in row.iter_mut().enumerate() {
+                let value = values[i * row_step + j * col_step].to_f64();
+                *slot = T::from_f64(self.op.apply(slot.to_f64(), value));
+            }
+        }
+        Ok(())
+    }
+
+    fn fold(self, first: f64, rest: impl Iterator<Item = f64>) -> f64 {
+        rest.fold(first, |acc, x| self.op.apply(acc, x))
+    }
+
+    /// Reduce a non-empty lane like NumPy's `pairwise_sum`: eight running
+    /// partials per block of up to 128 elements, and longer lanes split in
+    /// halves, so rounding error grows with the log of the length.
+    fn pairwise(self, x: &[f64]) -> f64 {
+        const BLOCK: usize = 128;
+        let op = |a, b| self.op.apply(a, b);
+        if x.len() < 8 {
+            return self.fold(x[0], x[1..].iter().copied());
+        }
+        if x.len() <= BLOCK {
+            let mut r = [0.0; 8];
+            r.copy_from_slice(&x[..8]);
+            let blocks = x.len() - x.len() % 8;
+            for chunk in x[8..blocks].chunks_exact(8) {
+                for (r, &v) in r.iter_mut().zip(chunk) {
+                    *r = op(*r, v);
+                }
+            }
+            let partial = op(
+                op(op(r[0], r[1]), op(r[2], r[3])),
+                op(op(r[4], r[5]), op(r[6], r[7])),
+            );
+            return self.fold(partial, x[blocks..].iter().copied());
+        }
+        let half = x.len() / 2 - x.len() / 2 % 8;
+        op(self.pairwise(&x[..half]), self.pairwise(&x[half..]))
+    }
+}
+
+/// The broadcast length of a tuple of index arrays: every array has that
+/// length or length 1. No arrays select the whole array once.
+fn index_count(indices: &[&[i64]]) -> Result<usize> {
+    let count = indices
+        .iter()
+        .map(|index| index.len())
+        .find(|&len| len != 1)
+        .unwrap_or(1);
+    if indices
+        .iter()
+        .any(|index| index.len() != 1 && index.len() != count)
+    {
+        let shapes: String = indices
+            .iter()
+            .map(|index| format!("({},) ", index.len()))
+            .collect();
+        return Err(NumpyError::index(format!(
+            "shape mismatch: indexing arrays could not be broadcast together with shapes {}",
+            shapes
+        )));
+    }
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interrupt::Never;
+
+    fn ufunc(name: &str) -> Ufunc {
+        Ufunc::parse(name).unwrap()
+    }
+
+    #[test]
+    fn test_registry_names_and_identities() {
+        assert_eq!(ufunc("true_divide").name(), "divide");
+        assert_eq!(ufunc("mod").op(), BinaryOp::Remainder);
+        assert_eq!(ufunc("multiply").identity(), Some(1.0));
+        assert_eq!(ufunc("maximum").identity(), None);
+        assert!(Ufunc::parse("logaddexp").is_err());
+    }
+
+    #[test]
+    fn test_reduce_axes_identity_and_initial() {
+        // [[1, 2, 3],
+        //  [4, 5, 6]]
+        let a = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let add = ufunc("add");
+        let (out, shape) = add
+            .reduce(&a, &[2, 3], Some(&[0]), false, None, &Never)
+            .unwrap();
+        assert_eq!((out, shape), (vec![5.0, 7.0, 9.0], vec![3]));
+        let (out, shape) = add.reduce(&a, &[2, 3], None, true, None, &Never).unwrap();
+        assert_eq!((out, shape), (vec![21.0], vec![1, 1]));
+        let (out, _) = ufunc("subtract")
+            .reduce(&a, &[2, 3], Some(&[1]), false, None, &Never)
+            .unwrap();
+        assert_eq!(out, vec![-4.0, -7.0]);
+        let (out, _) = ufunc("maximum")
+            .reduce(&a, &[2, 3], Some(&[1]), false, Some(5.5), &Never)
+            .unwrap();
+        assert_eq!(out, vec![5.5, 6.0]);
+
+        let (out, _) = add
+            .reduce(&[-0.0f64], &[1], None, false, None, &Never)
+            .unwrap();
+        assert!(out[0].is_sign_negative());
+        // 200 tiny terms vanish one by one against 1.0 but not when summed
+        // pairwise first.
+        let mut tiny = vec![1e-16; 201];
+        tiny[0] = 1.0;
+        let (out, _) = add
+            .reduce(&tiny, &[201], None, false, None, &Never)
+            .unwrap();
+        assert!(out[0] > 1.0);
+        let (out, _) = ufunc("hypot")
+            .reduce::<f64>(&[3.0, 4.0, 12.0], &[1, 3], None, false, None, &Never)
+            .unwrap();
+        // libm's hypot, used without the `std` feature, may be 1 ulp off.
+        assert!(
+            out[0].to_bits().abs_diff(13.0f64.to_bits()) <= 1,
+            "{}",
+            out[0]
+        );
+        let (out, _) = ufunc("multiply")
+            .reduce::<f64>(&[], &[0], None, false, None, &Never)
+            .unwrap();
+        assert_eq!(out, vec![1.0]);
+        assert_eq!(
+            ufunc("minimum")
+                .reduce::<f64>(&[], &[0], None, false, None, &Never)
+                .unwrap_err()
+                .message,
+            "zero-size array to reduction operation minimum which has no identity"
+        );
+        let error = ufunc("subtract")
+            .reduce(&a, &[2, 3], None, false, None, &Never)
+            .unwrap_err();
+        assert!(error
+            .message
+            .starts_with("reduction operation 'subtract' is not reorderable"));
+    }
+
+    #[test]
+    fn test_accumulate_and_outer() {
+        let a = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let out = ufunc("add").accumulate(&a, &[2, 3], 1, &Never).unwrap();
+        assert_eq!(out, vec![1.0, 3.0, 6.0, 4.0, 9.0, 15.0]);
+        let out = ufunc("maximum")
+            .accumulate(&[2.0, 1.0, f64::NAN, 3.0], &[4], 0, &Never)
+            .unwrap();
+        assert_eq!(out[..2], [2.0, 2.0]);
+        assert!(out[2].is_nan() && out[3].is_nan());
+        let out = ufunc("multiply")
+            .accumulate(&a, &[2, 3], -2, &Never)
+            .unwrap();
+        assert_eq!(out, vec![1.0, 2.0, 3.0, 4.0, 10.0, 18.0]);
+
+        let mut out = [0.0f32; 6];
+        ufunc("subtract")
+            .outer_into(&[10.0, 20.0], &[1.0, 2.0, 3.0], &mut out)
+            .unwrap();
+        assert_eq!(out, [9.0, 8.0, 7.0, 19.0, 18.0, 17.0]);
+        assert!(ufunc("add")
+            .outer_into(&[1.0], &[1.0, 2.0], &mut [0.0])
+            .is_err());
+    }
+
+    #[test]
+    fn test_reduceat() {
+        let a: Vec<f64> = (0..8).map(f64::from).collect();
+        let add = ufunc("add");
+        // NumPy's docs example: np.add.reduceat(np.arange(8), [0,4, 1,5, 2,6, 3,7])[::2].
+        let (out, shape) = add
+            .reduceat(&a, &[8], &[0, 4, 1, 5, 2, 6, 3, 7], 0, &Never)
+            .unwrap();
+        assert_eq!(
+            out.iter().step_by(2).copied().collect::<Vec<_>>(),
+            vec![6.0, 10.0, 14.0, 18.0]
+        );
+        assert_eq!(shape, vec![8]);
+        let (out, _) = add.reduceat(&a, &[8], &[5, 2, 6], 0, &Never).unwrap();
+        assert_eq!(out, vec![5.0, 14.0, 13.0]);
+
+        let (out, shape) = ufunc("multiply")
+            .reduceat(&a, &[2, 4], &[0, 2], 1, &Never)
+            .unwrap();
+        assert_eq!((out, shape), (vec![0.0, 6.0, 20.0, 42.0], vec![2, 2]));
+        assert_eq!(
+            add.reduceat(&a, &[8], &[0, 8], 0, &Never)
+                .unwrap_err()
+                .message,
+            "index 8 out-of-bounds in add.reduceat [0, 8)"
+        );
+        assert!(add.reduceat(&a, &[8], &[-1], 0, &Never).is_err());
+    }
+
+    #[test]
+    fn test_at_is_unbuffered_and_bounds_checked() {
+        // np.add.at(counts, [0, 2, 2, -1], 1): the repeated 2 counts twice.
+        let mut counts = [0.0; 4];
+        let add = ufunc("add");
+        add.at(&mut counts, &[4], &[&[0, 2, 2, -1]], &[1.0], &Never)
+            .unwrap();
+        assert_eq!(counts, [1.0, 0.0, 2.0, 1.0]);
+
+        // A (2, 3) target: an index tuple picks elements, a single index
+        // array picks rows.
+        let mut a = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        ufunc("maximum")
+            .at(
+                &mut a,
+                &[2, 3],
+                &[&[0, 1, 0], &[2]],
+                &[9.0, 0.0, 7.0],
+                &Never,
+            )
+            .unwrap();
+        assert_eq!(a, [1.0, 2.0, 9.0, 4.0, 5.0, 6.0]);
+        ufunc("multiply")
+            .at(&mut a, &[2, 3], &[&[1, 1]], &[2.0, 3.0, 4.0], &Never)
+            .unwrap();
+        assert_eq!(a, [1.0, 2.0, 9.0, 16.0, 45.0, 96.0]);
+        ufunc("subtract")
+            .at(
+                &mut a,
+                &[2, 3],
+                &[&[0, 1]],
+                &[1.0, 1.0, 1.0, 2.0, 2.0, 2.0],
+                &Never,
+            )
+            .unwrap();
+        assert_eq!(a, [0.0, 1.0, 8.0, 14.0, 43.0, 94.0]);
+
+        let error = add
+            .at(&mut counts, &[4], &[&[1, 4]], &[1.0], &Never)
+            .unwrap_err();
+        assert_eq!(error.kind, crate::ErrorKind::Index);
+        assert_eq!(
+            error.message,
+            "index 4 is out of bounds for axis 0 with size 4"
+        );
+        // Nothing was applied before the bad index was found.
+        assert_eq!(counts, [1.0, 0.0, 2.0, 1.0]);
+        assert_eq!(
+            add.at(&mut counts, &[4], &[&[0], &[0]], &[1.0], &Never)
+                .unwrap_err()
+                .message,
+            "too many indices for array: array is 1-dimensional, but 2 were indexed"
+        );
+        assert!(add
+            .at(&mut a, &[2, 3], &[&[0, 1], &[0, 1, 2]], &[1.0], &Never)
+            .unwrap_err()
+            .message
+            .starts_with("shape mismatch: indexing arrays could not be broadcast"));
+        assert!(add
+            .at(&mut a, &[2, 3], &[&[0, 1]], &[1.0, 2.0], &Never)
+            .is_err());
+    }
+}
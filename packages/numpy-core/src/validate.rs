@@ -0,0 +1,73 @@
+//! Argument checks shared by every binding, so a bad call fails with the same
+//! NumPy error on each backend.
+
+use alloc::format;
+
+use crate::error::{NumpyError, Result};
+
+/// Elementwise binary kernels take operands already broadcast by the caller,
+/// so unequal flat lengths are a broadcast failure.
+pub fn same_len(left: usize, right: usize) -> Result<()> {
+    if left != right {
+        return Err(NumpyError::broadcast(&[left], &[right]));
+    }
+    Ok(())
+}
+
+/// A caller-provided output buffer must hold exactly `expected` elements.
+pub fn output_len(expected: usize, actual: usize) -> Result<()> {
+    if expected != actual {
+        return Err(NumpyError::shape_mismatch(
+            format!("Expected output length {}, got {}", expected, actual),
+            &[expected],
+            &[actual],
+        ));
+    }
+    Ok(())
+}
+
+/// `rows * cols`, or the `ValueError` NumPy raises for an impossible size.
+pub fn checked_len(rows: usize, cols: usize) -> Result<usize> {
+    rows.checked_mul(cols).ok_or_else(too_big)
+}
+
+/// A flat buffer described as a `rows x cols` row-major matrix.
+pub fn matrix_len(name: &str, len: usize, rows: usize, cols: usize) -> Result<()> {
+    if checked_len(rows, cols)? != len {
+        return Err(NumpyError::shape_mismatch(
+            format!(
+                "{} has length {}, which does not match a {}x{} matrix",
+                name, len, rows, cols
+            ),
+            &[rows, cols],
+            &[len],
+        ));
+    }
+    Ok(())
+}
+
+pub(crate) fn too_big() -> NumpyError {
+    NumpyError::value(
+        "array is too big; `arr.size * arr.dtype.itemsize` is larger than the maximum possible size",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_length_validation() {
+        let error = same_len(3, 2).unwrap_err();
+        assert_eq!(error.expected_shape, Some(vec![3]));
+        assert_eq!(error.actual_shape, Some(vec![2]));
+        assert!(matrix_len("A", 6, 2, 3).is_ok());
+        assert_eq!(
+            matrix_len("A", 5, 2, 3).unwrap_err().message,
+            "A has length 5, which does not match a 2x3 matrix"
+        );
+        assert!(matrix_len("A", 0, usize::MAX, 2).is_err());
+        assert!(output_len(4, 3).is_err());
+    }
+}
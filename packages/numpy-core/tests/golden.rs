@@ -0,0 +1,517 @@
+//! NumPy parity against the golden fixtures in `tests/golden`.
+//!
+//! `tests/golden/generate.py` wrote each case's inputs and expected outputs
+//! once; this harness only reads the `.npy` files, so it runs offline with no
+//! Python. Tolerances are per op, in units in the last place.
+
+use std::fs;
+use std::path::PathBuf;
+
+use sylphx_numpy_core::cumulative::{self, CumulativeOp, View};
+use sylphx_numpy_core::elementwise::{self, BinaryOp, UnaryOp};
+use sylphx_numpy_core::norm::{self, NormOrd};
+use sylphx_numpy_core::ufunc::Ufunc;
+use sylphx_numpy_core::{division, fft, linalg, reduce, rounding, sort, Never};
+
+/// A `.npy` v1/v2 array of `<f8` or `<i8`, widened to `f64`.
+struct Npy {
+    shape: Vec<usize>,
+    data: Vec<f64>,
+}
+
+fn load(case: &str, part: &str) -> Npy {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/golden")
+        .join(format!("{}.{}.npy", case, part));
+    let bytes = fs::read(&path).unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+    parse(&bytes).unwrap_or_else(|e| panic!("{}: {}", path.display(), e))
+}
+
+fn parse(bytes: &[u8]) -> Result<Npy, String> {
+    if bytes.get(..6) != Some(b"\x93NUMPY") {
+        return Err("missing .npy magic".into());
+    }
+    let (header_len, start) = match bytes[6] {
+        1 => (u16::from_le_bytes([bytes[8], bytes[9]]) as usize, 10),
+        2 | 3 => (
+            u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]) as usize,
+            12,
+        ),
+        v => return Err(format!("unsupported .npy version {}", v)),
+    };
+    let header =
+        std::str::from_utf8(&bytes[start..start + header_len]).map_err(|e| e.to_string())?;
+    if header.contains("'fortran_order': True") {
+        return Err("Fortran-ordered arrays are not supported".into());
+    }
+
+    let field = |key: &str| {
+        let rest = &header[header.find(key).ok_or(format!("no {} in header", key))? + key.len()..];
+        Ok::<_, String>(rest.trim_start_matches([':', ' ', '\'']))
+    };
+    let descr = field("'descr'")?;
+    let shape = field("'shape'")?;
+    let shape = &shape[1..shape.find(')').ok_or("unterminated shape")?];
+    let shape = shape
+        .split(',')
+        .map(str::trim)
+        .filter(|dim| !dim.is_empty())
+        .map(|dim| dim.parse::<usize>().map_err(|e| e.to_string()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let words = bytes[start + header_len..]
+        .chunks_exact(8)
+        .map(|w| <[u8; 8]>::try_from(w).unwrap());
+    let data: Vec<f64> = if descr.starts_with("<f8") {
+        words.map(f64::from_le_bytes).collect()
+    } else if descr.starts_with("<i8") {
+        words.map(|w| i64::from_le_bytes(w) as f64).collect()
+    } else {
+        return Err(format!("unsupported dtype {}", descr));
+    };
+    if data.len() != shape.iter().product::<usize>() {
+        return Err(format!("{} values for shape {:?}", data.len(), shape));
+    }
+    Ok(Npy { shape, data })
+}
+
+#[derive(Clone, Copy)]
+enum Tol {
+    /// Each element within this many ULPs of the expected value.
+    Ulp(u64),
+    /// Each element within this many ULPs of the largest expected magnitude,
+    /// for results built from sums whose terms may cancel.
+    Scaled(u64),
+}
+
+/// Distance in representable doubles; `+0` and `-0` are the same point.
+fn ulps(a: f64, b: f64) -> u64 {
+    let ordered = |x: f64| {
+        let bits = x.to_bits() as i64;
+        if bits < 0 {
+            i64::MIN - bits
+        } else {
+            bits
+        }
+    };
+    ordered(a).abs_diff(ordered(b))
+}
+
+/// Compare against `<case>.expected.npy`, returning a line per mismatch.
+fn compare(case: &str, actual: &[f64], tol: Tol) -> Vec<String> {
+    let expected = load(case, "expected");
+    if actual.len() != expected.data.len() {
+        return vec![format!(
+            "{}: {} values, expected shape {:?}",
+            case,
+            actual.len(),
+            expected.shape
+        )];
+    }
+    let scale = expected
+        .data
+        .iter()
+        .filter(|x| x.is_finite())
+        .fold(0.0_f64, |max, x| max.max(x.abs()));
+
+    let mut failures = Vec::new();
+    for (i, (&a, &e)) in actual.iter().zip(&expected.data).enumerate() {
+        let ok = if e.is_nan() || a.is_nan() || e.is_infinite() {
+            e.is_nan() && a.is_nan() || a == e
+        } else {
+            match tol {
+                Tol::Ulp(max) => ulps(a, e) <= max,
+                Tol::Scaled(max) => (a - e).abs() <= max as f64 * f64::EPSILON * scale,
+            }
+        };
+        if !ok {
+            failures.push(format!(
+                "{}[{}]: got {:e}, expected {:e} ({} ulp)",
+                case,
+                i,
+                a,
+                e,
+                ulps(a, e)
+            ));
+        }
+    }
+    failures
+}
+
+fn assert_all(failures: Vec<String>) {
+    assert!(
+        failures.is_empty(),
+        "{} mismatches:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+const UNARY: &[(&str, UnaryOp, u64)] = &[
+    ("negative", UnaryOp::Neg, 0),
+    ("absolute", UnaryOp::Abs, 0),
+    ("sign", UnaryOp::Sign, 0),
+    ("sqrt", UnaryOp::Sqrt, 0),
+    ("cbrt", UnaryOp::Cbrt, 2),
+    ("square", UnaryOp::Square, 0),
+    ("reciprocal", UnaryOp::Reciprocal, 0),
+    ("exp", UnaryOp::Exp, 2),
+    ("exp2", UnaryOp::Exp2, 2),
+    ("expm1", UnaryOp::Expm1, 2),
+    ("log", UnaryOp::Log, 2),
+    ("log2", UnaryOp::Log2, 2),
+    ("log10", UnaryOp::Log10, 2),
+    ("log1p", UnaryOp::Log1p, 2),
+    ("sin", UnaryOp::Sin, 2),
+    ("cos", UnaryOp::Cos, 2),
+    ("tan", UnaryOp::Tan, 2),
+    ("arcsin", UnaryOp::Arcsin, 2),
+    ("arccos", UnaryOp::Arccos, 2),
+    ("arctan", UnaryOp::Arctan, 2),
+    ("sinh", UnaryOp::Sinh, 2),
+    ("cosh", UnaryOp::Cosh, 2),
+    ("tanh", UnaryOp::Tanh, 2),
+    ("arcsinh", UnaryOp::Arcsinh, 2),
+    ("arccosh", UnaryOp::Arccosh, 2),
+    ("arctanh", UnaryOp::Arctanh, 2),
+    ("deg2rad", UnaryOp::Deg2rad, 1),
+    ("rad2deg", UnaryOp::Rad2deg, 1),
+    ("round", UnaryOp::Round, 0),
+    ("floor", UnaryOp::Floor, 0),
+    ("ceil", UnaryOp::Ceil, 0),
+    ("trunc", UnaryOp::Trunc, 0),
+];
+
+const BINARY: &[(&str, BinaryOp, u64)] = &[
+    ("add", BinaryOp::Add, 0),
+    ("subtract", BinaryOp::Sub, 0),
+    ("multiply", BinaryOp::Mul, 0),
+    ("divide", BinaryOp::Div, 0),
+    ("power", BinaryOp::Pow, 2),
+    ("maximum", BinaryOp::Maximum, 0),
+    ("minimum", BinaryOp::Minimum, 0),
+    ("fmod", BinaryOp::Fmod, 0),
+    ("remainder", BinaryOp::Remainder, 0),
+    ("floor_divide", BinaryOp::FloorDivide, 0),
+    ("arctan2", BinaryOp::Arctan2, 2),
+    ("hypot", BinaryOp::Hypot, 1),
+    ("fmax", BinaryOp::Fmax, 0),
+    ("fmin", BinaryOp::Fmin, 0),
+];
+
+#[test]
+fn unary_ufuncs_match_numpy() {
+    let mut failures = Vec::new();
+    for &(case, op, tol) in UNARY {
+        let x = load(case, "x").data;
+        let mut out = vec![0.0; x.len()];
+        elementwise::unary_into(op, &x, &mut out).unwrap();
+        failures.extend(compare(case, &out, Tol::Ulp(tol)));
+    }
+    assert_all(failures);
+}
+
+#[test]
+fn binary_ufuncs_match_numpy() {
+    let mut failures = Vec::new();
+    for &(case, op, tol) in BINARY {
+        let (x, y) = (load(case, "x").data, load(case, "y").data);
+        let mut out = vec![0.0; x.len()];
+        elementwise::binary_into(op, &x, &y, &mut out).unwrap();
+        failures.extend(compare(case, &out, Tol::Ulp(tol)));
+    }
+    assert_all(failures);
+}
+
+type Reduction<'a> = &'a dyn Fn(&[f64]) -> f64;
+
+#[test]
+fn integer_division_matches_numpy() {
+    let mut failures = Vec::new();
+    for case in ["floor_divide_int", "remainder_int"] {
+        let int = |part| {
+            load(case, part)
+                .data
+                .iter()
+                .map(|&v| v as i64)
+                .collect::<Vec<_>>()
+        };
+        let (x, y) = (int("x"), int("y"));
+        let mut out = vec![0; x.len()];
+        if case == "floor_divide_int" {
+            division::floor_divide_into(&x, &y, &mut out).unwrap();
+        } else {
+            division::remainder_into(&x, &y, &mut out).unwrap();
+        }
+        let out: Vec<f64> = out.iter().map(|&v| v as f64).collect();
+        failures.extend(compare(case, &out, Tol::Ulp(0)));
+    }
+    assert_all(failures);
+}
+
+#[test]
+fn round_with_decimals_matches_numpy() {
+    let mut failures = Vec::new();
+    for (case, decimals) in [("round_2", 2), ("round_m2", -2)] {
+        let x = load(case, "x").data;
+        let mut out = vec![0.0; x.len()];
+        rounding::round_into(&x, decimals, &mut out).unwrap();
+        failures.extend(compare(case, &out, Tol::Ulp(0)));
+    }
+
+    let x: Vec<i64> = load("round_m1_int", "x")
+        .data
+        .iter()
+        .map(|&v| v as i64)
+        .collect();
+    let mut out = vec![0; x.len()];
+    rounding::round_into(&x, -1, &mut out).unwrap();
+    let out: Vec<f64> = out.iter().map(|&v| v as f64).collect();
+    failures.extend(compare("round_m1_int", &out, Tol::Ulp(0)));
+    assert_all(failures);
+}
+
+#[test]
+fn reductions_match_numpy() {
+    let argmax = |x: &[f64]| reduce::argmax(x).unwrap() as f64;
+    let argmin = |x: &[f64]| reduce::argmin(x).unwrap() as f64;
+    let norm_1 = |x: &[f64]| norm::vector_norm(x, 1.0).unwrap();
+    let norm_2 = |x: &[f64]| norm::vector_norm(x, 2.0).unwrap();
+    let norm_inf = |x: &[f64]| norm::vector_norm(x, f64::INFINITY).unwrap();
+    let max = |x: &[f64]| reduce::max(x).unwrap();
+    let min = |x: &[f64]| reduce::min(x).unwrap();
+    let nanmax = |x: &[f64]| reduce::nanmax(x).unwrap();
+    let nanmin = |x: &[f64]| reduce::nanmin(x).unwrap();
+    let cases: &[(&str, Reduction, Tol)] = &[
+        ("sum", &reduce::sum, Tol::Ulp(16)),
+        ("prod", &reduce::prod, Tol::Ulp(64)),
+        ("mean", &reduce::mean, Tol::Ulp(16)),
+        ("var", &reduce::var, Tol::Ulp(16)),
+        ("std", &reduce::std, Tol::Ulp(8)),
+        ("max", &max, Tol::Ulp(0)),
+        ("min", &min, Tol::Ulp(0)),
+        ("max_nan", &max, Tol::Ulp(0)),
+        ("min_nan", &min, Tol::Ulp(0)),
+        ("argmax", &argmax, Tol::Ulp(0)),
+        ("argmin", &argmin, Tol::Ulp(0)),
+        ("nansum", &reduce::nansum, Tol::Ulp(16)),
+        ("nanmean", &reduce::nanmean, Tol::Ulp(16)),
+        ("nanvar", &reduce::nanvar, Tol::Ulp(16)),
+        ("nanstd", &reduce::nanstd, Tol::Ulp(8)),
+        ("nanmax", &nanmax, Tol::Ulp(0)),
+        ("nanmin", &nanmin, Tol::Ulp(0)),
+        ("norm_1", &norm_1, Tol::Ulp(16)),
+        ("norm_2", &norm_2, Tol::Ulp(8)),
+        ("norm_inf", &norm_inf, Tol::Ulp(0)),
+    ];
+
+    let mut failures = Vec::new();
+    for &(case, kernel, tol) in cases {
+        let x = load(case, "x").data;
+        failures.extend(compare(case, &[kernel(&x)], tol));
+    }
+    assert_all(failures);
+}
+
+#[test]
+fn linalg_matches_numpy() {
+    let mut failures = Vec::new();
+    let (a, b) = (load("matmul", "a").data, load("matmul", "b").data);
+    let mut out = vec![0.0; 7 * 6];
+    linalg::matmul_into(&a, &b, 7, 5, 6, &mut out, &Never).unwrap();
+    failures.extend(compare("matmul", &out, Tol::Scaled(8)));
+
+    let x = load("transpose", "x").data;
+    let mut out = vec![0.0; x.len()];
+    linalg::transpose_into(&x, 5, 7, &mut out).unwrap();
+    failures.extend(compare("transpose", &out, Tol::Ulp(0)));
+
+    let (a, b) = (load("dot", "a").data, load("dot", "b").data);
+    failures.extend(compare(
+        "dot",
+        &[linalg::dot(&a, &b).unwrap()],
+        Tol::Ulp(16),
+    ));
+
+    let (a, b) = (load("outer", "a").data, load("outer", "b").data);
+    let mut out = vec![0.0; a.len() * b.len()];
+    Ufunc::parse("multiply")
+        .unwrap()
+        .outer_into(&a, &b, &mut out)
+        .unwrap();
+    failures.extend(compare("outer", &out, Tol::Ulp(0)));
+
+    let x = load("trace", "x").data;
+    failures.extend(compare(
+        "trace",
+        &[linalg::trace(&x, 6, 6).unwrap()],
+        Tol::Ulp(4),
+    ));
+
+    for (n, det_case, inv_case) in [(2, "det_2", "inv_2"), (3, "det_3", "inv_3")] {
+        let x = load(det_case, "x").data;
+        failures.extend(compare(
+            det_case,
+            &[linalg::det(&x, n).unwrap()],
+            Tol::Ulp(16),
+        ));
+        let mut out = vec![0.0; n * n];
+        linalg::inv_into(&x, n, &mut out).unwrap();
+        failures.extend(compare(inv_case, &out, Tol::Scaled(16)));
+    }
+
+    let x = load("cholesky", "x").data;
+    let mut out = vec![0.0; x.len()];
+    linalg::cholesky_into(&x, 6, &mut out, &Never).unwrap();
+    failures.extend(compare("cholesky", &out, Tol::Scaled(16)));
+
+    // Only the singular values are unique; U and Vt are free up to sign.
+    let x = load("svd", "x").data;
+    let (mut u, mut s, mut vt) = (vec![0.0; 6 * 4], vec![0.0; 4], vec![0.0; 4 * 4]);
+    linalg::svd_into(&x, 6, 4, &mut u, &mut s, &mut vt, &Never).unwrap();
+    failures.extend(compare("svd", &s, Tol::Scaled(16)));
+    assert_all(failures);
+}
+
+#[test]
+fn norms_match_numpy() {
+    let mut failures = Vec::new();
+    for (case, p, tol) in [
+        ("norm_3", 3.0, Tol::Ulp(16)),
+        ("norm_half", 0.5, Tol::Ulp(16)),
+        ("norm_minf", f64::NEG_INFINITY, Tol::Ulp(0)),
+        ("norm_0", 0.0, Tol::Ulp(0)),
+        ("norm_2_huge", 2.0, Tol::Ulp(8)),
+        ("norm_2_tiny", 2.0, Tol::Ulp(8)),
+    ] {
+        let x = load(case, "x").data;
+        failures.extend(compare(case, &[norm::vector_norm(&x, p).unwrap()], tol));
+    }
+
+    let x = load("norm_3_axis1", "x").data;
+    let (out, _) = norm::norm(
+        &x,
+        &[5, 8],
+        Some(NormOrd::P(3.0)),
+        Some(&[1]),
+        false,
+        &Never,
+    )
+    .unwrap();
+    failures.extend(compare("norm_3_axis1", &out, Tol::Ulp(16)));
+
+    let inf = f64::INFINITY;
+    for (case, ord) in [
+        ("matrix_norm_fro", NormOrd::Fro),
+        ("matrix_norm_nuc", NormOrd::Nuc),
+        ("matrix_norm_1", NormOrd::P(1.0)),
+        ("matrix_norm_m1", NormOrd::P(-1.0)),
+        ("matrix_norm_2", NormOrd::P(2.0)),
+        ("matrix_norm_m2", NormOrd::P(-2.0)),
+        ("matrix_norm_inf", NormOrd::P(inf)),
+        ("matrix_norm_minf", NormOrd::P(-inf)),
+    ] {
+        let x = load(case, "x").data;
+        let actual = norm::matrix_norm(&x, 6, 4, ord, &Never).unwrap();
+        failures.extend(compare(case, &[actual], Tol::Scaled(16)));
+    }
+    assert_all(failures);
+}
+
+#[test]
+fn fft_matches_numpy() {
+    let mut failures = Vec::new();
+    // (case, complex input, inverse)
+    for (case, complex, inverse) in [
+        ("fft_16", false, false),
+        ("fft_12", false, false),
+        ("fft_17", true, false),
+        ("ifft_16", true, true),
+        ("ifft_12", true, true),
+    ] {
+        let re = load(case, "re").data;
+        let im = complex.then(|| load(case, "im").data);
+        let mut out = vec![0.0; re.len() * 2];
+        fft::fft_into(&re, im.as_deref(), inverse, &mut out, &Never).unwrap();
+        failures.extend(compare(case, &out, Tol::Scaled(32)));
+    }
+    assert_all(failures);
+}
+
+#[test]
+fn sort_matches_numpy() {
+    let mut x = load("sort", "x").data;
+    sort::sort_in_place(&mut x, &Never).unwrap();
+    assert_all(compare("sort", &x, Tol::Ulp(0)));
+}
+
+#[test]
+fn moments_match_numpy() {
+    let mut failures = Vec::new();
+    let x = load("var_ill_conditioned", "x").data;
+    let (var, _) = reduce::var_axis(&x, &[x.len()], None, 0.0, false, None, &Never).unwrap();
+    failures.extend(compare("var_ill_conditioned", &var, Tol::Ulp(4)));
+
+    let x = load("var_axis0_ddof1", "x").data;
+    let (var, _) = reduce::var_axis(&x, &[6, 40], Some(&[0]), 1.0, false, None, &Never).unwrap();
+    failures.extend(compare("var_axis0_ddof1", &var, Tol::Ulp(4)));
+
+    let x = load("std_axes02", "x").data;
+    let (std, _) =
+        reduce::std_axis(&x, &[4, 5, 6], Some(&[0, 2]), 0.0, false, None, &Never).unwrap();
+    failures.extend(compare("std_axes02", &std, Tol::Ulp(4)));
+    assert_all(failures);
+}
+
+#[test]
+fn arg_extremes_match_numpy() {
+    let x = load("argmax_axis1", "x").data;
+    let indices = |(idx, _): (Vec<i64>, Vec<usize>)| -> Vec<f64> {
+        idx.into_iter().map(|i| i as f64).collect()
+    };
+    let mut failures = Vec::new();
+    let argmax = reduce::argmax_axis(&x, &[8, 16], Some(1), false, &Never).unwrap();
+    failures.extend(compare("argmax_axis1", &indices(argmax), Tol::Ulp(0)));
+    let argmin = reduce::argmin_axis(&x, &[8, 16], Some(0), false, &Never).unwrap();
+    failures.extend(compare("argmin_axis0", &indices(argmin), Tol::Ulp(0)));
+    let nanargmax = reduce::nanargmax_axis(&x, &[8, 16], Some(0), false, &Never).unwrap();
+    failures.extend(compare("nanargmax_axis0", &indices(nanargmax), Tol::Ulp(0)));
+    assert_all(failures);
+}
+
+#[test]
+fn cumulative_matches_numpy() {
+    let x = load("cumsum_axis1", "x").data;
+    let view = View::new(&x, &[6, 10], &[10, 1], 0).unwrap();
+    let mut failures = Vec::new();
+    let (cumsum, _) =
+        cumulative::cumulative::<f64, f64>(view, Some(1), CumulativeOp::Sum, &Never).unwrap();
+    failures.extend(compare("cumsum_axis1", &cumsum, Tol::Ulp(0)));
+    let (nancumprod, _) =
+        cumulative::cumulative::<f64, f64>(view, Some(0), CumulativeOp::NanProd, &Never).unwrap();
+    failures.extend(compare("nancumprod_axis0", &nancumprod, Tol::Ulp(0)));
+    assert_all(failures);
+}
+
+#[test]
+fn ufunc_methods_match_numpy() {
+    let x = load("subtract_reduce_axis0", "x").data;
+    let ufunc = |name| Ufunc::parse(name).unwrap();
+    let mut failures = Vec::new();
+    let (reduced, _) = ufunc("subtract")
+        .reduce(&x, &[5, 8], Some(&[0]), false, None, &Never)
+        .unwrap();
+    failures.extend(compare("subtract_reduce_axis0", &reduced, Tol::Ulp(0)));
+    let accumulated = ufunc("maximum").accumulate(&x, &[5, 8], 1, &Never).unwrap();
+    failures.extend(compare(
+        "maximum_accumulate_axis1",
+        &accumulated,
+        Tol::Ulp(0),
+    ));
+    // The indices generate.py used.
+    let (reduceat, _) = ufunc("add")
+        .reduceat(&x, &[5, 8], &[0, 3, 3, 6, 2], 1, &Never)
+        .unwrap();
+    failures.extend(compare("add_reduceat_axis1", &reduceat, Tol::Ulp(0)));
+    assert_all(failures);
+}
@@ -0,0 +1,449 @@
+#!/usr/bin/env python3
+"""Regenerate the golden fixtures read by ``tests/golden.rs``.
+
+    pip install -r packages/numpy-core/tests/golden/requirements.txt
+    python3 packages/numpy-core/tests/golden/generate.py
+
+Inputs are drawn from a fixed seed, so rerunning only changes the expected
+outputs, which all come from NumPy; the script refuses to run without it.
+``SOURCE`` records the NumPy and Python versions that produced the files. CI
+reruns the script with the pinned NumPy and fails if the checked-in files change.
+
+Every case writes ``<case>.<input>.npy`` for each input and
+``<case>.expected.npy``, all little-endian ``.npy`` v1.0.
+"""
+
+import math
+import os
+import random
+import struct
+import sys
+from fractions import Fraction
+
+try:
+    import numpy as np
+except ImportError:
+    sys.exit("generate.py needs NumPy: pip install -r packages/numpy-core/tests/golden/requirements.txt")
+
+HERE = os.path.dirname(os.path.abspath(__file__))
+SEED = 36
+INF = math.inf
+NAN = math.nan
+
+
+def write_npy(path, values, shape, descr="<f8"):
+    header = "{'descr': '%s', 'fortran_order': False, 'shape': %s, }" % (
+        descr,
+        "(%d,)" % shape[0] if len(shape) == 1 else "(%s)" % ", ".join(map(str, shape)),
+    )
+    # Magic, version and header length take 10 bytes; pad to 64 with a newline.
+    header += " " * (63 - (10 + len(header)) % 64) + "\n"
+    code = "d" if descr == "<f8" else "q"
+    with open(path, "wb") as f:
+        f.write(b"\x93NUMPY\x01\x00")
+        f.write(struct.pack("<H", len(header)))
+        f.write(header.encode("latin1"))
+        f.write(struct.pack("<%d%s" % (len(values), code), *values))
+
+
+class Case:
+    def __init__(self, name, inputs, expected, shape=None, descr="<f8", input_descr="<f8"):
+        self.name = name
+        self.inputs = inputs
+        self.expected = expected
+        self.shape = shape
+        self.descr = descr
+        self.input_descr = input_descr
+
+
+def flat(x):
+    return [float(v) for v in x]
+
+
+def shape_of(values, shape):
+    return shape if shape is not None else (len(values),)
+
+
+# --- input helpers -----------------------------------------------------------
+
+
+def rational_dot(a, b):
+    return sum((Fraction(x) * Fraction(y) for x, y in zip(a, b)), Fraction(0))
+
+
+def rational_matmul(a, b, m, k, n):
+    return [
+        rational_dot(a[i * k : (i + 1) * k], b[j::n]) for i in range(m) for j in range(n)
+    ]
+
+
+def householder(v):
+    """Exactly orthogonal rational reflector ``I - 2 v v^T / (v^T v)``."""
+    n = len(v)
+    v = [Fraction(x) for x in v]
+    norm = sum(x * x for x in v)
+    return [
+        (1 if i == j else 0) - 2 * v[i] * v[j] / norm for i in range(n) for j in range(n)
+    ]
+
+
+# --- cases ------------------------------------------------------------------
+
+# (numpy name, low, high, edge inputs)
+UNARY = [
+    ("negative", -10, 10, [0.0, INF]),
+    ("absolute", -10, 10, [-0.0, -INF]),
+    ("sign", -10, 10, [0.0, -0.0, -INF]),
+    ("sqrt", 0, 100, [0.0, -1.0, INF]),
+    ("cbrt", -50, 50, [0.0, -27.0, INF]),
+    ("square", -10, 10, [INF]),
+    ("reciprocal", -10, 10, [0.0, -0.0]),
+    ("exp", -20, 20, [-INF, 800.0]),
+    ("exp2", -20, 20, [-INF, 2000.0]),
+    ("expm1", -2, 2, [1e-10, 800.0]),
+    ("log", 0.01, 100, [0.0, -1.0, INF]),
+    ("log2", 0.01, 100, [0.0, -1.0, INF]),
+    ("log10", 0.01, 100, [0.0, -1.0, INF]),
+    ("log1p", -0.9, 5, [-1.0, -2.0, 1e-10]),
+    ("sin", -10, 10, [0.0, INF]),
+    ("cos", -10, 10, [0.0, INF]),
+    ("tan", -1.5, 1.5, [0.0, INF]),
+    ("arcsin", -1, 1, [1.0, 1.5]),
+    ("arccos", -1, 1, [1.0, 1.5]),
+    ("arctan", -10, 10, [INF, -INF]),
+    ("sinh", -5, 5, [0.0]),
+    ("cosh", -5, 5, [0.0]),
+    ("tanh", -5, 5, [INF, -INF]),
+    ("arcsinh", -50, 50, [0.0]),
+    ("arccosh", 1, 50, [1.0, 0.5]),
+    ("arctanh", -0.99, 0.99, [1.0, -1.0, 2.0]),
+    ("deg2rad", -720, 720, [180.0]),
+    ("rad2deg", -10, 10, [math.pi]),
+    ("round", -100, 100, [0.5, 1.5, 2.5, -2.5, -0.5, 0.25]),
+    ("floor", -100, 100, [-0.5, 2.0]),
+    ("ceil", -100, 100, [-0.5, 2.0]),
+    ("trunc", -100, 100, [-0.5, 2.0]),
+]
+
+# (numpy name, x range, y range, edge pairs)
+BINARY = [
+    ("add", (-10, 10), (-10, 10), [(INF, -INF), (NAN, 1.0)]),
+    ("subtract", (-10, 10), (-10, 10), [(INF, INF), (1.0, NAN)]),
+    ("multiply", (-10, 10), (-10, 10), [(INF, 0.0), (-0.0, 3.0)]),
+    ("divide", (-10, 10), (0.5, 10), [(1.0, 0.0), (-1.0, 0.0), (0.0, 0.0)]),
+    ("power", (0.1, 10), (-4, 4), [(-2.0, 3.0), (-8.0, 1 / 3), (NAN, 0.0), (1.0, NAN)]),
+    ("maximum", (-10, 10), (-10, 10), [(NAN, 1.0), (1.0, NAN), (-INF, INF)]),
+    ("minimum", (-10, 10), (-10, 10), [(NAN, 1.0), (1.0, NAN), (-INF, INF)]),
+    ("fmod", (-10, 10), (0.5, 3), [(-7.0, 3.0), (7.0, -3.0), (1.0, 0.0)]),
+    ("arctan2", (-10, 10), (-10, 10), [(0.0, -1.0), (1.0, 0.0), (INF, INF)]),
+    ("hypot", (-10, 10), (-10, 10), [(INF, NAN), (3.0, 4.0)]),
+]
+
+
+def unary_cases(rng):
+    for name, lo, hi, edges in UNARY:
+        x = [rng.uniform(lo, hi) for _ in range(64)] + edges + [NAN]
+        with np.errstate(all="ignore"):
+            expected = flat(getattr(np, name)(np.array(x)))
+        yield Case(name, {"x": x}, expected)
+
+
+def binary_cases(rng):
+    for name, (xlo, xhi), (ylo, yhi), edges in BINARY:
+        pairs = [(rng.uniform(xlo, xhi), rng.uniform(ylo, yhi)) for _ in range(64)] + edges
+        x, y = [p[0] for p in pairs], [p[1] for p in pairs]
+        with np.errstate(all="ignore"):
+            expected = flat(getattr(np, name)(np.array(x), np.array(y)))
+        yield Case(name, {"x": x, "y": y}, expected)
+
+
+def reduction_cases(rng):
+    x = [rng.uniform(0, 1) for _ in range(1000)]
+    signed = [rng.uniform(-10, 10) for _ in range(257)]
+    near_one = [rng.uniform(0.9, 1.1) for _ in range(200)]
+    holes = list(x[:300])
+    for i in rng.sample(range(300), 30):
+        holes[i] = NAN
+
+    inputs = {
+        "sum": x,
+        "prod": near_one,
+        "mean": x,
+        "var": x,
+        "std": x,
+        "max": signed,
+        "min": signed,
+        "nansum": holes,
+        "nanmean": holes,
+        "nanvar": holes,
+        "nanstd": holes,
+        "nanmax": holes,
+        "nanmin": holes,
+    }
+    for name, values in inputs.items():
+        expected = float(getattr(np, name)(np.array(values)))
+        yield Case(name, {"x": values}, [expected], shape=())
+
+    for name in ("argmax", "argmin"):
+        expected = int(getattr(np, name)(np.array(signed)))
+        yield Case(name, {"x": signed}, [expected], shape=(), descr="<i8")
+
+    for ord_name in ("1", "2", "inf"):
+        expected = float(np.linalg.norm(np.array(signed), float(ord_name)))
+        yield Case("norm_" + ord_name, {"x": signed}, [expected], shape=())
+
+
+def random_matrix(rng, rows, cols, lo=-1.0, hi=1.0):
+    return [rng.uniform(lo, hi) for _ in range(rows * cols)]
+
+
+def dominant(rng, n):
+    a = random_matrix(rng, n, n)
+    for i in range(n):
+        a[i * n + i] += n
+    return a
+
+
+def known_singular_values(rng):
+    """A 6x4 ``H1 @ diag(s) @ H2`` with exactly orthogonal reflectors."""
+    s = sorted((rng.uniform(0.5, 10) for _ in range(4)), reverse=True)
+    h1 = householder([rng.randint(-9, 9) or 1 for _ in range(6)])
+    h2 = householder([rng.randint(-9, 9) or 1 for _ in range(4)])
+    sigma = [Fraction(s[c]) if r == c else Fraction(0) for r in range(6) for c in range(4)]
+    return [float(v) for v in rational_matmul(rational_matmul(h1, sigma, 6, 6, 4), h2, 6, 4, 4)]
+
+
+def linalg_cases(rng):
+    a, b = random_matrix(rng, 7, 5), random_matrix(rng, 5, 6)
+    expected = flat((np.array(a).reshape(7, 5) @ np.array(b).reshape(5, 6)).ravel())
+    yield Case("matmul", {"a": a, "b": b}, expected, shape=(7, 6))
+
+    t = random_matrix(rng, 5, 7)
+    yield Case("transpose", {"x": t}, flat(np.array(t).reshape(5, 7).T.ravel()), shape=(7, 5))
+
+    u, v = [rng.uniform(0, 1) for _ in range(100)], [rng.uniform(0, 1) for _ in range(100)]
+    yield Case("dot", {"a": u, "b": v}, [float(np.dot(u, v))], shape=())
+
+    u, v = random_matrix(rng, 1, 4), random_matrix(rng, 1, 6)
+    yield Case("outer", {"a": u, "b": v}, flat(np.outer(u, v).ravel()), shape=(4, 6))
+
+    sq = random_matrix(rng, 6, 6, 0.0, 1.0)
+    yield Case("trace", {"x": sq}, [float(np.trace(np.array(sq).reshape(6, 6)))], shape=())
+
+    for n in (2, 3):
+        m = np.array(dominant(rng, n)).reshape(n, n)
+        yield Case("det_%d" % n, {"x": flat(m.ravel())}, [float(np.linalg.det(m))], shape=())
+        inv = flat(np.linalg.inv(m).ravel())
+        yield Case("inv_%d" % n, {"x": flat(m.ravel())}, inv, shape=(n, n))
+
+    m = random_matrix(rng, 6, 6)
+    spd = [float(v) for v in rational_matmul(m, [m[r * 6 + c] for c in range(6) for r in range(6)], 6, 6, 6)]
+    for i in range(6):
+        spd[i * 6 + i] += 6.0
+    expected = flat(np.linalg.cholesky(np.array(spd).reshape(6, 6)).ravel())
+    yield Case("cholesky", {"x": spd}, expected, shape=(6, 6))
+
+    a = known_singular_values(rng)
+    s = flat(np.linalg.svd(np.array(a).reshape(6, 4), compute_uv=False))
+    yield Case("svd", {"x": a}, s, shape=(4,))
+
+
+def fft_cases(rng):
+    for name, n, complex_input, inverse in (
+        ("fft_16", 16, False, False),
+        ("fft_12", 12, False, False),
+        ("fft_17", 17, True, False),
+        ("ifft_16", 16, True, True),
+        ("ifft_12", 12, True, True),
+    ):
+        re = [rng.uniform(-1, 1) for _ in range(n)]
+        im = [rng.uniform(-1, 1) for _ in range(n)] if complex_input else [0.0] * n
+        z = np.array(re) + 1j * np.array(im)
+        out = (np.fft.ifft if inverse else np.fft.fft)(z)
+        expected = flat(np.column_stack([out.real, out.imag]).ravel())
+        inputs = {"re": re, "im": im} if complex_input else {"re": re}
+        yield Case(name, inputs, expected, shape=(n, 2))
+
+
+def sort_cases(rng):
+    x = [rng.uniform(-100, 100) for _ in range(200)] + [NAN, INF, -INF, NAN, 0.0, -5.0, -5.0]
+    rng.shuffle(x)
+    yield Case("sort", {"x": x}, flat(np.sort(np.array(x))))
+
+
+def division_cases(rng):
+    edges = [
+        (-7.0, 3.0), (7.0, -3.0), (6.0, -3.0), (-0.0, 3.0), (1.0, 0.1),
+        (1.0, 0.0), (-1.0, 0.0), (0.0, 0.0), (-5.0, INF), (5.0, INF),
+        (INF, 3.0), (NAN, 2.0),
+    ]
+    pairs = [(rng.uniform(-20, 20), rng.uniform(-5, 5)) for _ in range(64)] + edges
+    x, y = [p[0] for p in pairs], [p[1] for p in pairs]
+    for name in ("floor_divide", "remainder"):
+        with np.errstate(all="ignore"):
+            expected = flat(getattr(np, name)(np.array(x), np.array(y)))
+        yield Case(name, {"x": x, "y": y}, expected)
+
+    edges = [(-7, 3), (7, -3), (-6, 3), (5, 0), (0, 0), (-(2**63), -1)]
+    pairs = [(rng.randint(-1000, 1000), rng.randint(-20, 20)) for _ in range(64)] + edges
+    x, y = [p[0] for p in pairs], [p[1] for p in pairs]
+    for name in ("floor_divide", "remainder"):
+        with np.errstate(all="ignore"):
+            result = getattr(np, name)(np.array(x, np.int64), np.array(y, np.int64))
+        expected = [int(v) for v in result]
+        yield Case(name + "_int", {"x": x, "y": y}, expected, descr="<i8", input_descr="<i8")
+
+
+def rounding_cases(rng):
+    x = [rng.uniform(-1000, 1000) for _ in range(64)]
+    x += [0.125, 0.375, 2.675, -1.005, 1250.0, 1350.0, -0.0, NAN, INF]
+    for decimals in (2, -2):
+        name = "round_%s%d" % ("m" if decimals < 0 else "", abs(decimals))
+        yield Case(name, {"x": x}, flat(np.round(np.array(x), decimals)))
+
+    ints = [rng.randint(-10000, 10000) for _ in range(64)] + [25, 35, -25, -35, 15]
+    expected = [int(v) for v in np.round(np.array(ints, np.int64), -1)]
+    yield Case("round_m1_int", {"x": ints}, expected, descr="<i8", input_descr="<i8")
+
+
+def norm_cases(rng):
+    x = [rng.uniform(-10, 10) for _ in range(257)]
+    for i in rng.sample(range(257), 20):
+        x[i] = 0.0
+    for name, p in (("3", 3.0), ("half", 0.5), ("minf", -INF), ("0", 0.0)):
+        expected = float(np.linalg.norm(np.array(x), p))
+        yield Case("norm_" + name, {"x": x}, [expected], shape=())
+
+    # Squaring these overflows or underflows without dnrm2's scaling.
+    for name, scale in (("huge", 1e300), ("tiny", 1e-300)):
+        v = [rng.uniform(-1, 1) * scale for _ in range(64)]
+        expected = float(np.linalg.norm(np.array(v)))
+        yield Case("norm_2_" + name, {"x": v}, [expected], shape=())
+
+    m = random_matrix(rng, 5, 8)
+    expected = flat(np.linalg.norm(np.array(m).reshape(5, 8), 3, axis=1))
+    yield Case("norm_3_axis1", {"x": m}, expected, shape=(5,))
+
+    # Built like the svd case so the 2-norms and nuclear norm are well conditioned.
+    a = known_singular_values(rng)
+    ords = {"fro": "fro", "nuc": "nuc", "1": 1, "m1": -1, "2": 2, "m2": -2, "inf": INF, "minf": -INF}
+    for name, order in ords.items():
+        expected = float(np.linalg.norm(np.array(a).reshape(6, 4), order))
+        yield Case("matrix_norm_" + name, {"x": a}, [expected], shape=())
+
+
+def moment_cases(rng):
+    # Spread ~1 around 1e9: a one-pass sum of squares loses every digit.
+    x = [1e9 + rng.uniform(0, 1) for _ in range(4096)]
+    yield Case("var_ill_conditioned", {"x": x}, [float(np.var(np.array(x)))], shape=())
+
+    m = [1e6 + rng.uniform(-1, 1) for _ in range(6 * 40)]
+    expected = flat(np.var(np.array(m).reshape(6, 40), axis=0, ddof=1))
+    yield Case("var_axis0_ddof1", {"x": m}, expected, shape=(40,))
+
+    # Shape (4, 5, 6) reduced over axes 0 and 2.
+    t = [rng.uniform(-10, 10) for _ in range(4 * 5 * 6)]
+    expected = flat(np.std(np.array(t).reshape(4, 5, 6), axis=(0, 2)))
+    yield Case("std_axes02", {"x": t}, expected, shape=(5,))
+
+
+def nan_cases(rng):
+    # NaN on either side, both sides, and neither.
+    x = [rng.uniform(-5, 5) for _ in range(64)]
+    y = [rng.uniform(-5, 5) for _ in range(64)]
+    for i in rng.sample(range(64), 12):
+        x[i] = NAN
+    for i in rng.sample(range(64), 12):
+        y[i] = NAN
+
+    for name in ("fmax", "fmin"):
+        expected = flat(getattr(np, name)(np.array(x), np.array(y)))
+        yield Case(name, {"x": x, "y": y}, expected)
+
+    for name in ("max", "min"):
+        expected = float(getattr(np, name)(np.array(x)))
+        yield Case(name + "_nan", {"x": x}, [expected], shape=())
+
+
+def arg_cases(rng):
+    # Shape (8, 16) drawn from few values, so lanes tie, with NaNs in some rows.
+    x = [float(rng.randint(-3, 3)) for _ in range(8 * 16)]
+    for i in rng.sample(range(8 * 16), 10):
+        x[i] = NAN
+    m = np.array(x).reshape(8, 16)
+    for name, shape in (
+        ("argmax_axis1", (8,)),
+        ("argmin_axis0", (16,)),
+        ("nanargmax_axis0", (16,)),
+    ):
+        func, axis = name.split("_axis")
+        expected = [int(v) for v in getattr(np, func)(m, axis=int(axis))]
+        yield Case(name, {"x": x}, expected, shape=shape, descr="<i8")
+
+
+def cumulative_cases(rng):
+    # Shape (6, 10): cumsum along the rows, nancumprod down the columns.
+    x = [rng.uniform(-2, 2) for _ in range(6 * 10)]
+    for i in rng.sample(range(6 * 10), 6):
+        x[i] = NAN
+    m = np.array(x).reshape(6, 10)
+    yield Case("cumsum_axis1", {"x": x}, flat(np.cumsum(m, axis=1).ravel()), shape=(6, 10))
+    yield Case("nancumprod_axis0", {"x": x}, flat(np.nancumprod(m, axis=0).ravel()), shape=(6, 10))
+
+
+def ufunc_cases(rng):
+    # Shape (5, 8): subtract.reduce down the columns, maximum.accumulate along
+    # the rows and add.reduceat along the rows, including a repeated and a
+    # decreasing index; golden.rs repeats the indices.
+    x = [rng.uniform(-4, 4) for _ in range(5 * 8)]
+    for i in rng.sample(range(5 * 8), 3):
+        x[i] = NAN
+    m = np.array(x).reshape(5, 8)
+    reduced = flat(np.subtract.reduce(m, axis=0))
+    accumulated = flat(np.maximum.accumulate(m, axis=1).ravel())
+    reduceat = flat(np.add.reduceat(m, [0, 3, 3, 6, 2], axis=1).ravel())
+    yield Case("subtract_reduce_axis0", {"x": x}, reduced)
+    yield Case("maximum_accumulate_axis1", {"x": x}, accumulated, shape=(5, 8))
+    yield Case("add_reduceat_axis1", {"x": x}, reduceat, shape=(5, 5))
+
+
+def main():
+    rng = random.Random(SEED)
+    for old in os.listdir(HERE):
+        if old.endswith(".npy"):
+            os.remove(os.path.join(HERE, old))
+
+    groups = (
+        unary_cases,
+        binary_cases,
+        reduction_cases,
+        linalg_cases,
+        fft_cases,
+        sort_cases,
+        division_cases,
+        rounding_cases,
+        norm_cases,
+        moment_cases,
+        nan_cases,
+        arg_cases,
+        cumulative_cases,
+        ufunc_cases,
+    )
+    count = 0
+    for group in groups:
+        for case in group(rng):
+            for key, values in case.inputs.items():
+                path = os.path.join(HERE, "%s.%s.npy" % (case.name, key))
+                write_npy(path, values, (len(values),), case.input_descr)
+            path = os.path.join(HERE, case.name + ".expected.npy")
+            write_npy(path, case.expected, shape_of(case.expected, case.shape), case.descr)
+            count += 1
+
+    source = "numpy %s" % np.__version__
+    with open(os.path.join(HERE, "SOURCE"), "w") as f:
+        f.write("%s, python %s, seed %d\n" % (source, sys.version.split()[0], SEED))
+    print("wrote %d cases from %s" % (count, source))
+
+
+if __name__ == "__main__":
+    main()
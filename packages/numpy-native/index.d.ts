@@ -16,3 +16,283 @@ export declare function mulScalarF64BuffersInto(input: Buffer, scalar: number, o
 export declare function addF64Buffers(left: Buffer, right: Buffer, output: Buffer): Buffer
 export declare function addF64BuffersInto(left: Buffer, right: Buffer, output: Buffer): void
 export declare function transposeF64Buffer(input: Float64Array, rows: number, cols: number, output: Buffer): Buffer
+export interface LoadtxtOptions {
+  /** Field separator. Defaults to any run of whitespace. */
+  delimiter?: string
+  /** Number of leading lines to skip, including comment lines. */
+  skiprows?: number
+  /** Columns to read; negative indices count from the end. */
+  usecols?: Array<number>
+  /** Prefixes that start a comment. Defaults to `['#']`. */
+  comments?: Array<string>
+  /** One of `float64` (default), `float32`, `int32` or `int64`. */
+  dtype?: string
+  /** Minimum number of dimensions of the result, as in NumPy. Defaults to 0. */
+  ndmin?: number
+}
+export interface GenfromtxtOptions {
+  delimiter?: string
+  skipHeader?: number
+  usecols?: Array<number>
+  comments?: Array<string>
+  /** Strings treated as missing in addition to empty fields. */
+  missingValues?: Array<string>
+  /** Value substituted for missing fields. Defaults to NaN. */
+  fillingValues?: number
+}
+export interface SavetxtOptions {
+  /**
+   * printf-style format: one spec for every column, one spec per column,
+   * or a whole-row format. Defaults to `%.18e`.
+   */
+  fmt?: Array<string>
+  delimiter?: string
+  newline?: string
+  header?: string
+  footer?: string
+  comments?: string
+}
+export interface TextArray {
+  data: Float64Array | Float32Array | Int32Array | BigInt64Array
+  shape: Array<number>
+}
+/** Load numeric text data, like `np.loadtxt`. */
+export declare function loadtxt(path: string, options?: LoadtxtOptions | undefined | null): TextArray
+export declare function loadtxtAsync(path: string, options?: LoadtxtOptions | undefined | null, token?: CancellationToken | undefined | null): Promise<TextArray>
+/**
+ * Load float text data with missing-value handling, like `np.genfromtxt`.
+ *
+ * Empty fields and `missing_values` become `filling_values` (NaN by default);
+ * fields that do not parse as numbers become NaN.
+ */
+export declare function genfromtxt(path: string, options?: GenfromtxtOptions | undefined | null): TextArray
+export declare function genfromtxtAsync(path: string, options?: GenfromtxtOptions | undefined | null, token?: CancellationToken | undefined | null): Promise<TextArray>
+/** Write a 1-D or 2-D float64 array as text, like `np.savetxt`. */
+export declare function savetxt(path: string, data: Float64Array, shape: Array<number>, options?: SavetxtOptions | undefined | null): void
+export declare class NpyMemmap {
+  /** Map the data region of a `.npy` file, like `np.load(path, mmap_mode=mode)`. */
+  static open(path: string, mode?: string | undefined | null): NpyMemmap
+  /**
+   * Map a headerless binary file, like `np.memmap(path, dtype, mode, offset, shape)`.
+   *
+   * When `shape` is omitted the mapping is 1-D and covers the rest of the file.
+   */
+  static openRaw(path: string, dtype: string, mode?: string | undefined | null, offset?: number | undefined | null, shape?: Array<number> | undefined | null): NpyMemmap
+  get dtype(): string
+  get shape(): Array<number>
+  get fortranOrder(): boolean
+  get mode(): string
+  get writable(): boolean
+  get offset(): number
+  get byteLength(): number
+  /**
+   * An ArrayBuffer backed directly by the mapped data region.
+   *
+   * The buffer keeps the mapping alive after this object is collected. For
+   * `'r'` and `'c'` writes into it never reach the file.
+   */
+  buffer(): ArrayBuffer
+  /** Write dirty pages back to the file. Only valid for `'r+'` mappings. */
+  flush(): void
+}
+/**
+ * Per-axis selection for `NativeArray.slice`, following Python's slice rules.
+ *
+ * Setting `index` selects a single position and drops the axis.
+ */
+export interface SliceSpec {
+  start?: number
+  stop?: number
+  step?: number
+  index?: number
+}
+/**
+ * An N-D array whose buffer, shape, strides and dtype live in Rust.
+ *
+ * Views share the underlying buffer, and operations return new handles, so
+ * chains of native calls never materialize JS typed arrays in between.
+ */
+export declare class NativeArray {
+  /** Copy float64 data into a new array. `shape` defaults to 1-D. */
+  static fromFloat64(data: Float64Array, shape?: Array<number> | undefined | null): NativeArray
+  /** Copy float32 data into a new array. `shape` defaults to 1-D. */
+  static fromFloat32(data: Float32Array, shape?: Array<number> | undefined | null): NativeArray
+  static full(shape: Array<number>, value: number, dtype?: string | undefined | null): NativeArray
+  get dtype(): string
+  get shape(): Array<number>
+  /** Strides in bytes, as NumPy reports them. */
+  get strides(): Array<number>
+  get ndim(): number
+  get size(): number
+  get cContiguous(): boolean
+  /** Whether `other` is a view over the same buffer. */
+  sharesMemory(other: NativeArray): boolean
+  /** Basic slicing view; axes without a spec are taken whole. */
+  slice(specs: Array<SliceSpec>): NativeArray
+  /**
+   * Reshape without copying when the data is C-contiguous; otherwise copy
+   * first, as `np.reshape` does. One dimension may be -1.
+   */
+  reshape(shape: Array<number>): NativeArray
+  /** Permute axes as a view; reverses them when `axes` is omitted. */
+  transpose(axes?: Array<number> | undefined | null): NativeArray
+  /** A C-contiguous copy with its own buffer. */
+  copy(): NativeArray
+  astype(dtype: string): NativeArray
+  /** Copy the elements out in C order. */
+  toFloat64Array(): Float64Array
+  /** Copy the elements out in C order, rounding float64 data to float32. */
+  toFloat32Array(): Float32Array
+  add(other: NativeArray): NativeArray
+  subtract(other: NativeArray): NativeArray
+  multiply(other: NativeArray): NativeArray
+  divide(other: NativeArray): NativeArray
+  /** Floored quotient, like `np.floor_divide`. */
+  floorDivide(other: NativeArray): NativeArray
+  /** Remainder with the sign of the divisor, like `np.remainder` / `np.mod`. */
+  remainder(other: NativeArray): NativeArray
+  addScalar(scalar: number): NativeArray
+  mulScalar(scalar: number): NativeArray
+  sqrt(): NativeArray
+  /** `np.round(a, decimals)`, rounding half to even. */
+  round(decimals?: number | undefined | null): NativeArray
+  /** `np.rint`: nearest integer, half to even. */
+  rint(): NativeArray
+  /** `np.fix`: round toward zero. */
+  fix(): NativeArray
+  sum(): number
+  /**
+   * Cumulative sum along `axis`, or over the flattened array when omitted.
+   * `dtype` picks the accumulator and defaults to the array's own.
+   */
+  cumsum(axis?: number | undefined | null, dtype?: string | undefined | null): NativeArray
+  cumprod(axis?: number | undefined | null, dtype?: string | undefined | null): NativeArray
+  /** `cumsum` counting NaN as zero. */
+  nancumsum(axis?: number | undefined | null, dtype?: string | undefined | null): NativeArray
+  /** `cumprod` counting NaN as one. */
+  nancumprod(axis?: number | undefined | null, dtype?: string | undefined | null): NativeArray
+  /** 2-D matrix product. */
+  matmul(other: NativeArray): NativeArray
+}
+/**
+ * A lazily recorded elementwise expression, compiled into a single fused
+ * loop like numexpr.
+ *
+ * `Expr.input(0).addScalar(3).mulScalar(2).sqrt().evaluate([a])` reads `a`
+ * once and writes one output, with no intermediate arrays. Trees nest at
+ * most 1024 levels deep.
+ */
+export declare class Expr {
+  /** Reference the `index`-th array passed to `evaluate`. */
+  static input(index: number): Expr
+  static constant(value: number): Expr
+  add(other: Expr): Expr
+  sub(other: Expr): Expr
+  mul(other: Expr): Expr
+  div(other: Expr): Expr
+  pow(other: Expr): Expr
+  maximum(other: Expr): Expr
+  minimum(other: Expr): Expr
+  addScalar(value: number): Expr
+  subScalar(value: number): Expr
+  mulScalar(value: number): Expr
+  divScalar(value: number): Expr
+  powScalar(value: number): Expr
+  neg(): Expr
+  abs(): Expr
+  sqrt(): Expr
+  square(): Expr
+  exp(): Expr
+  log(): Expr
+  sin(): Expr
+  cos(): Expr
+  tanh(): Expr
+  /** Number of arrays `evaluate` expects. */
+  get inputCount(): number
+  /** Run the fused loop once over equal-length inputs. */
+  evaluate(inputs: Array<Float64Array>): Float64Array
+  /** Run the fused loop into a caller-owned Buffer over Float64Array memory. */
+  evaluateInto(inputs: Array<Float64Array>, output: Buffer): void
+}
+/**
+ * Discrete Fourier transform of any length, like `np.fft.fft` / `np.fft.ifft`.
+ *
+ * Takes separate real and optional imaginary parts and returns interleaved
+ * `[re0, im0, re1, im1, ...]`. Power-of-two lengths use radix-2; others use
+ * Bluestein's algorithm.
+ */
+export declare function fftF64(real: Float64Array, imag?: Float64Array | undefined | null, inverse?: boolean | undefined | null): Float64Array
+export declare function fftF64Async(real: Float64Array, imag?: Float64Array | undefined | null, inverse?: boolean | undefined | null, token?: CancellationToken | undefined | null): Promise<Float64Array>
+export interface SvdResult {
+  /** Left singular vectors, `m x k` row-major with `k = min(m, n)`. */
+  u: Float64Array
+  /** Singular values in descending order. */
+  s: Float64Array
+  /** Right singular vectors, `k x n` row-major. */
+  vt: Float64Array
+}
+export interface NormOptions {
+  /**
+   * A number (including `Infinity`), `'fro'` or `'nuc'`. Defaults to the
+   * 2-norm for vectors and Frobenius for matrices.
+   */
+  ord?: number | string
+  /**
+   * One axis for vector norms or two for matrix norms; negative indices
+   * count from the end. Defaults to every axis of a 1-D or 2-D input.
+   */
+  axis?: Array<number>
+  keepdims?: boolean
+}
+export interface NormResult {
+  data: Float64Array
+  shape: Array<number>
+}
+/** `A @ B` for row-major `m x k` and `k x n` matrices. */
+export declare function matmulF64(a: Float64Array, b: Float64Array, m: number, k: number, n: number): Float64Array
+export declare function matmulF64Async(a: Float64Array, b: Float64Array, m: number, k: number, n: number, token?: CancellationToken | undefined | null): Promise<Float64Array>
+/** Lower-triangular `L` with `A = L @ L.T` for a symmetric positive-definite `n x n` matrix. */
+export declare function choleskyF64(a: Float64Array, n: number): Float64Array
+export declare function choleskyF64Async(a: Float64Array, n: number, token?: CancellationToken | undefined | null): Promise<Float64Array>
+/**
+ * Reduced singular value decomposition of a row-major `m x n` matrix,
+ * like `np.linalg.svd(a, full_matrices=False)`.
+ */
+export declare function svdF64(a: Float64Array, m: number, n: number): SvdResult
+export declare function svdF64Async(a: Float64Array, m: number, n: number, token?: CancellationToken | undefined | null): Promise<SvdResult>
+/** Vector or matrix norm of a C-order array, like `np.linalg.norm`. */
+export declare function normF64(a: Float64Array, shape: Array<number>, options?: NormOptions | undefined | null): NormResult
+/** Sorted copy of `a` in NumPy order: ascending with NaN last. */
+export declare function sortF64(a: Float64Array): Float64Array
+export declare function sortF64Async(a: Float64Array, token?: CancellationToken | undefined | null): Promise<Float64Array>
+/**
+ * A cooperative cancellation flag shared with kernels running off the main
+ * thread. Kernels poll it between blocks of work, so cancelling stops a job
+ * within one block rather than immediately.
+ */
+export declare class CancellationToken {
+  constructor()
+  /** A token that is cancelled when `signal` fires its `abort` event. */
+  static fromAbortSignal(signal: AbortSignal): CancellationToken
+  cancel(): void
+  get cancelled(): boolean
+}
+/**
+ * `np.<op>.at(target, indices, values)`, updating `target` in place.
+ *
+ * `op` names a binary ufunc such as `add`, `subtract`, `multiply`,
+ * `maximum` or `minimum`. `indices` is a tuple of index arrays for the
+ * leading axes of `target`, a C-order array of `shape` (1-D by default).
+ * Unlike `target[indices] += values`, a repeated index is applied every time.
+ */
+export declare function ufuncAtF64(op: string, target: Float64Array, indices: Array<Array<number>>, values: Float64Array, shape?: Array<number> | undefined | null): void
+/** `ufuncAtF64` over float32 data. */
+export declare function ufuncAtF32(op: string, target: Float32Array, indices: Array<Array<number>>, values: Float32Array, shape?: Array<number> | undefined | null): void
+/**
+ * Test hook: panics with `message` so the JS suite can check that a Rust
+ * panic surfaces as an exception rather than killing the process.
+ * Only exported by `test-hooks` builds.
+ */
+export declare function __triggerPanic(message: string): void
+/** Test hook: the same panic raised on the libuv thread pool. Only exported by `test-hooks` builds. */
+export declare function __triggerPanicAsync(message: string): Promise<void>
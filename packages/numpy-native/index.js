@@ -310,7 +310,7 @@ if (!nativeBinding) {
   throw new Error(`Failed to load native binding`)
 }
 
-const { addScalarF64, mulScalarF64, addF64, addScalarF64Buffer, mulScalarF64Buffer, addF64Buffer, addScalarF64Buffers, addScalarF64BuffersInto, mulScalarF64Buffers, mulScalarF64BuffersInto, addF64Buffers, addF64BuffersInto, transposeF64Buffer } = nativeBinding
+const { addScalarF64, mulScalarF64, addF64, addScalarF64Buffer, mulScalarF64Buffer, addF64Buffer, addScalarF64Buffers, addScalarF64BuffersInto, mulScalarF64Buffers, mulScalarF64BuffersInto, addF64Buffers, addF64BuffersInto, transposeF64Buffer, NpyMemmap, loadtxt, genfromtxt, savetxt, NativeArray, Expr, loadtxtAsync, genfromtxtAsync, fftF64, fftF64Async, matmulF64, matmulF64Async, choleskyF64, choleskyF64Async, svdF64, svdF64Async, sortF64, sortF64Async, CancellationToken, ufuncAtF64, ufuncAtF32, __triggerPanic, __triggerPanicAsync, normF64 } = nativeBinding
 
 module.exports.addScalarF64 = addScalarF64
 module.exports.mulScalarF64 = mulScalarF64
@@ -325,3 +325,27 @@ module.exports.mulScalarF64BuffersInto = mulScalarF64BuffersInto
 module.exports.addF64Buffers = addF64Buffers
 module.exports.addF64BuffersInto = addF64BuffersInto
 module.exports.transposeF64Buffer = transposeF64Buffer
+module.exports.NpyMemmap = NpyMemmap
+module.exports.loadtxt = loadtxt
+module.exports.genfromtxt = genfromtxt
+module.exports.savetxt = savetxt
+module.exports.NativeArray = NativeArray
+module.exports.Expr = Expr
+module.exports.loadtxtAsync = loadtxtAsync
+module.exports.genfromtxtAsync = genfromtxtAsync
+module.exports.fftF64 = fftF64
+module.exports.fftF64Async = fftF64Async
+module.exports.matmulF64 = matmulF64
+module.exports.matmulF64Async = matmulF64Async
+module.exports.choleskyF64 = choleskyF64
+module.exports.choleskyF64Async = choleskyF64Async
+module.exports.svdF64 = svdF64
+module.exports.svdF64Async = svdF64Async
+module.exports.sortF64 = sortF64
+module.exports.sortF64Async = sortF64Async
+module.exports.CancellationToken = CancellationToken
+module.exports.ufuncAtF64 = ufuncAtF64
+module.exports.ufuncAtF32 = ufuncAtF32
+module.exports.__triggerPanic = __triggerPanic
+module.exports.__triggerPanicAsync = __triggerPanicAsync
+module.exports.normF64 = normF64
@@ -0,0 +1,780 @@
+use std::sync::Arc;
+
+use napi::bindgen_prelude::{Float32Array, Float64Array};
+use napi::{Error, Result, Status};
+use napi_derive::napi;
+
+use sylphx_numpy_core::cumulative::{self, CumulativeOp, Scalar, View};
+use sylphx_numpy_core::division::Divmod;
+use sylphx_numpy_core::rounding::Round;
+use sylphx_numpy_core::shape::{
+    broadcast_shapes, broadcast_strides, c_strides, checked_size, infer_shape, normalize_axis,
+    normalize_index, to_usize_shape,
+};
+pub use sylphx_numpy_core::DType;
+use sylphx_numpy_core::{Never, NumpyError};
+
+use crate::buffer::Output;
+use crate::error::to_napi;
+
+pub enum Storage {
+    Float64(Output<f64>),
+    Float32(Output<f32>),
+}
+
+impl Storage {
+    fn dtype(&self) -> DType {
+        match self {
+            Self::Float64(_) => DType::Float64,
+            Self::Float32(_) => DType::Float32,
+        }
+    }
+}
+
+/// Per-axis selection for `NativeArray.slice`, following Python's slice rules.
+///
+/// Setting `index` selects a single position and drops the axis.
+#[napi(object)]
+pub struct SliceSpec {
+    pub start: Option<i64>,
+    pub stop: Option<i64>,
+    pub step: Option<i64>,
+    pub index: Option<i64>,
+}
+
+/// An N-D array whose buffer, shape, strides and dtype live in Rust.
+///
+/// Views share the underlying buffer, and operations return new handles, so
+/// chains of native calls never materialize JS typed arrays in between.
+#[napi]
+#[derive(Clone)]
+pub struct NativeArray {
+    storage: Arc<Storage>,
+    shape: Vec<usize>,
+    /// Strides in elements, not bytes.
+    strides: Vec<isize>,
+    offset: usize,
+}
+
+#[napi]
+impl NativeArray {
+    /// Copy float64 data into a new array. `shape` defaults to 1-D.
+    #[napi(factory)]
+    pub fn from_float64(data: &[f64], shape: Option<Vec<i64>>) -> Result<Self> {
+        crate::error::guard("NativeArray.fromFloat64", || {
+            let shape = resolve_shape(shape, data.len())?;
+            let storage = Output::from_iter_exact(data.len(), data.iter().copied())?;
+            Ok(Self::contiguous(Storage::Float64(storage), shape))
+        })
+    }
+
+    /// Copy float32 data into a new array. `shape` defaults to 1-D.
+    #[napi(factory)]
+    pub fn from_float32(data: &[f32], shape: Option<Vec<i64>>) -> Result<Self> {
+        crate::error::guard("NativeArray.fromFloat32", || {
+            let shape = resolve_shape(shape, data.len())?;
+            let storage = Output::from_iter_exact(data.len(), data.iter().copied())?;
+            Ok(Self::contiguous(Storage::Float32(storage), shape))
+        })
+    }
+
+    #[napi(factory)]
+    pub fn full(shape: Vec<i64>, value: f64, dtype: Option<String>) -> Result<Self> {
+        crate::error::guard("NativeArray.full", || {
+            let shape = to_usize_shape(&shape).map_err(to_napi)?;
+            let size = checked_size(&shape).map_err(to_napi)?;
+            let storage =
+                match DType::parse(dtype.as_deref().unwrap_or("float64")).map_err(to_napi)? {
+                    DType::Float64 => {
+                        Storage::Float64(Output::from_iter_exact(size, std::iter::repeat(value))?)
+                    }
+                    DType::Float32 => Storage::Float32(Output::from_iter_exact(
+                        size,
+                        std::iter::repeat(value as f32),
+                    )?),
+                };
+            Ok(Self::contiguous(storage, shape))
+        })
+    }
+
+    #[napi(getter)]
+    pub fn dtype(&self) -> Result<String> {
+        crate::error::guard("NativeArray.dtype", || {
+            Ok(self.storage.dtype().name().to_string())
+        })
+    }
+
+    #[napi(getter)]
+    pub fn shape(&self) -> Result<Vec<i64>> {
+        crate::error::guard("NativeArray.shape", || {
+            Ok(self.shape.iter().map(|&dim| dim as i64).collect())
+        })
+    }
+
+    /// Strides in bytes, as NumPy reports them.
+    #[napi(getter)]
+    pub fn strides(&self) -> Result<Vec<i64>> {
+        crate::error::guard("NativeArray.strides", || {
+            let itemsize = self.storage.dtype().itemsize() as i64;
+            Ok(self
+                .strides
+                .iter()
+                .map(|&stride| stride as i64 * itemsize)
+                .collect())
+        })
+    }
+
+    #[napi(getter)]
+    pub fn ndim(&self) -> Result<u32> {
+        crate::error::guard("NativeArray.ndim", || Ok(self.shape.len() as u32))
+    }
+
+    #[napi(getter)]
+    pub fn size(&self) -> Result<i64> {
+        crate::error::guard("NativeArray.size", || Ok(self.size_usize() as i64))
+    }
+
+    #[napi(getter)]
+    pub fn c_contiguous(&self) -> Result<bool> {
+        crate::error::guard("NativeArray.cContiguous", || Ok(self.is_c_contiguous()))
+    }
+
+    /// Whether `other` is a view over the same buffer.
+    #[napi]
+    pub fn shares_memory(&self, other: &NativeArray) -> Result<bool> {
+        crate::error::guard("NativeArray.sharesMemory", || {
+            Ok(Arc::ptr_eq(&self.storage, &other.storage))
+        })
+    }
+
+    /// Basic slicing view; axes without a spec are taken whole.
+    #[napi]
+    pub fn slice(&self, specs: Vec<SliceSpec>) -> Result<NativeArray> {
+        crate::error::guard("NativeArray.slice", || {
+            if specs.len() > self.shape.len() {
+                return Err(Error::new(
+                    Status::InvalidArg,
+                    format!(
+                        "too many indices for array: array is {}-dimensional, but {} were indexed",
+                        self.shape.len(),
+                        specs.len()
+                    ),
+                ));
+            }
+
+            let mut offset = self.offset as isize;
+            let mut shape = Vec::with_capacity(self.shape.len());
+            let mut strides = Vec::with_capacity(self.shape.len());
+
+            for (axis, (&dim, &stride)) in self.shape.iter().zip(&self.strides).enumerate() {
+                let Some(spec) = specs.get(axis) else {
+                    shape.push(dim);
+                    strides.push(stride);
+                    continue;
+                };
+
+                if let Some(index) = spec.index {
+                    let position = normalize_index(index, dim, axis).map_err(to_napi)?;
+                    offset += position as isize * stride;
+                    continue;
+                }
+
+                let (start, len, step) = slice_indices(spec, dim)?;
+                offset += start * stride;
+                shape.push(len);
+                strides.push(stride * step);
+            }
+
+            Ok(self.view(shape, strides, offset as usize))
+        })
+    }
+
+    /// Reshape without copying when the data is C-contiguous; otherwise copy
+    /// first, as `np.reshape` does. One dimension may be -1.
+    #[napi]
+    pub fn reshape(&self, shape: Vec<i64>) -> Result<NativeArray> {
+        crate::error::guard("NativeArray.reshape", || {
+            let size = self.size_usize();
+            let shape = infer_shape(&shape, size).map_err(to_napi)?;
+            let base = if self.is_c_contiguous() {
+                self.clone()
+            } else {
+                self.copy()?
+            };
+            let strides = c_strides(&shape);
+            Ok(base.view(shape, strides, base.offset))
+        })
+    }
+
+    /// Permute axes as a view; reverses them when `axes` is omitted.
+    #[napi]
+    pub fn transpose(&self, axes: Option<Vec<i64>>) -> Result<NativeArray> {
+        crate::error::guard("NativeArray.transpose", || {
+            let ndim = self.shape.len();
+            let axes = match axes {
+                Some(axes) => {
+                    if axes.len() != ndim {
+                        return Err(to_napi(NumpyError::value("axes don't match array")));
+                    }
+                    let mut seen = vec![false; ndim];
+                    let mut resolved = Vec::with_capacity(ndim);
+                    for axis in axes {
+                        let axis = normalize_axis(axis, ndim).map_err(to_napi)?;
+                        if std::mem::replace(&mut seen[axis], true) {
+                            return Err(to_napi(NumpyError::value("repeated axis in transpose")));
+                        }
+                        resolved.push(axis);
+                    }
+                    resolved
+                }
+                None => (0..ndim).rev().collect(),
+            };
+
+            let shape = axes.iter().map(|&axis| self.shape[axis]).collect();
+            let strides = axes.iter().map(|&axis| self.strides[axis]).collect();
+            Ok(self.view(shape, strides, self.offset))
+        })
+    }
+
+    /// A C-contiguous copy with its own buffer.
+    #[napi]
+    pub fn copy(&self) -> Result<NativeArray> {
+        crate::error::guard("NativeArray.copy", || {
+            let storage = match &*self.storage {
+                Storage::Float64(data) => Storage::Float64(self.gather(data.as_slice())?),
+                Storage::Float32(data) => Storage::Float32(self.gather(data.as_slice())?),
+            };
+            Ok(Self::contiguous(storage, self.shape.clone()))
+        })
+    }
+
+    #[napi]
+    pub fn astype(&self, dtype: String) -> Result<NativeArray> {
+        crate::error::guard("NativeArray.astype", || {
+            let dtype = DType::parse(&dtype).map_err(to_napi)?;
+            if dtype == self.storage.dtype() {
+                return self.copy();
+            }
+            let size = self.size_usize();
+            let storage = match (&*self.storage, dtype) {
+                (Storage::Float32(data), DType::Float64) => Storage::Float64(
+                    Output::from_iter_exact(size, self.values(data.as_slice()).map(f64::from))?,
+                ),
+                (Storage::Float64(data), DType::Float32) => Storage::Float32(
+                    Output::from_iter_exact(size, self.values(data.as_slice()).map(|v| v as f32))?,
+                ),
+                _ => unreachable!("same-dtype casts return early"),
+            };
+            Ok(Self::contiguous(storage, self.shape.clone()))
+        })
+    }
+
+    /// Copy the elements out in C order.
+    #[napi]
+    pub fn to_float64_array(&self) -> Result<Float64Array> {
+        crate::error::guard("NativeArray.toFloat64Array", || {
+            let output = match &*self.storage {
+                Storage::Float64(data) => self.gather(data.as_slice())?,
+                Storage::Float32(data) => Output::from_iter_exact(
+                    self.size_usize(),
+                    self.values(data.as_slice()).map(f64::from),
+                )?,
+            };
+            Ok(output.into_float64_array())
+        })
+    }
+
+    /// Copy the elements out in C order, rounding float64 data to float32.
+    #[napi]
+    pub fn to_float32_array(&self) -> Result<Float32Array> {
+        crate::error::guard("NativeArray.toFloat32Array", || {
+            let values: Vec<f32> = match &*self.storage {
+                Storage::Float64(data) => self.values(data.as_slice()).map(|v| v as f32).collect(),
+                Storage::Float32(data) => self.values(data.as_slice()).collect(),
+            };
+            Ok(values.into())
+        })
+    }
+
+    #[napi]
+    pub fn add(&self, other: &NativeArray) -> Result<NativeArray> {
+        crate::error::guard("NativeArray.add", || {
+            self.binary(other, |a, b| a + b, |a, b| a + b)
+        })
+    }
+
+    #[napi]
+    pub fn subtract(&self, other: &NativeArray) -> Result<NativeArray> {
+        crate::error::guard("NativeArray.subtract", || {
+            self.binary(other, |a, b| a - b, |a, b| a - b)
+        })
+    }
+
+    #[napi]
+    pub fn multiply(&self, other: &NativeArray) -> Result<NativeArray> {
+        crate::error::guard("NativeArray.multiply", || {
+            self.binary(other, |a, b| a * b, |a, b| a * b)
+        })
+    }
+
+    #[napi]
+    pub fn divide(&self, other: &NativeArray) -> Result<NativeArray> {
+        crate::error::guard("NativeArray.divide", || {
+            self.binary(other, |a, b| a / b, |a, b| a / b)
+        })
+    }
+
+    /// Floored quotient, like `np.floor_divide`.
+    #[napi]
+    pub fn floor_divide(&self, other: &NativeArray) -> Result<NativeArray> {
+        crate::error::guard("NativeArray.floorDivide", || {
+            self.binary(other, Divmod::floor_divide, Divmod::floor_divide)
+        })
+    }
+
+    /// Remainder with the sign of the divisor, like `np.remainder` / `np.mod`.
+    #[napi]
+    pub fn remainder(&self, other: &NativeArray) -> Result<NativeArray> {
+        crate::error::guard("NativeArray.remainder", || {
+            self.binary(other, Divmod::remainder, Divmod::remainder)
+        })
+    }
+
+    #[napi]
+    pub fn add_scalar(&self, scalar: f64) -> Result<NativeArray> {
+        crate::error::guard("NativeArray.addScalar", || {
+            self.unary(|a| a + scalar, |a| a + scalar as f32)
+        })
+    }
+
+    #[napi]
+    pub fn mul_scalar(&self, scalar: f64) -> Result<NativeArray> {
+        crate::error::guard("NativeArray.mulScalar", || {
+            self.unary(|a| a * scalar, |a| a * scalar as f32)
+        })
+    }
+
+    #[napi]
+    pub fn sqrt(&self) -> Result<NativeArray> {
+        crate::error::guard("NativeArray.sqrt", || self.unary(f64::sqrt, f32::sqrt))
+    }
+
+    /// `np.round(a, decimals)`, rounding half to even.
+    #[napi]
+    pub fn round(&self, decimals: Option<i32>) -> Result<NativeArray> {
+        crate::error::guard("NativeArray.round", || {
+            let decimals = decimals.unwrap_or(0);
+            self.unary(|a| a.round_to(decimals), |a| a.round_to(decimals))
+        })
+    }
+
+    /// `np.rint`: nearest integer, half to even.
+    #[napi]
+    pub fn rint(&self) -> Result<NativeArray> {
+        crate::error::guard("NativeArray.rint", || {
+            self.unary(f64::round_ties_even, f32::round_ties_even)
+        })
+    }
+
+    /// `np.fix`: round toward zero.
+    #[napi]
+    pub fn fix(&self) -> Result<NativeArray> {
+        crate::error::guard("NativeArray.fix", || self.unary(f64::trunc, f32::trunc))
+    }
+
+    #[napi]
+    pub fn sum(&self) -> Result<f64> {
+        crate::error::guard("NativeArray.sum", || {
+            Ok(match &*self.storage {
+                Storage::Float64(data) => self.values(data.as_slice()).sum(),
+                Storage::Float32(data) => self.values(data.as_slice()).map(f64::from).sum(),
+            })
+        })
+    }
+
+    /// Cumulative sum along `axis`, or over the flattened array when omitted.
+    /// `dtype` picks the accumulator and defaults to the array's own.
+    #[napi]
+    pub fn cumsum(&self, axis: Option<i64>, dtype: Option<String>) -> Result<NativeArray> {
+        crate::error::guard("NativeArray.cumsum", || {
+            self.cumulative(CumulativeOp::Sum, axis, dtype)
+        })
+    }
+
+    #[napi]
+    pub fn cumprod(&self, axis: Option<i64>, dtype: Option<String>) -> Result<NativeArray> {
+        crate::error::guard("NativeArray.cumprod", || {
+            self.cumulative(CumulativeOp::Prod, axis, dtype)
+        })
+    }
+
+    /// `cumsum` counting NaN as zero.
+    #[napi]
+    pub fn nancumsum(&self, axis: Option<i64>, dtype: Option<String>) -> Result<NativeArray> {
+        crate::error::guard("NativeArray.nancumsum", || {
+            self.cumulative(CumulativeOp::NanSum, axis, dtype)
+        })
+    }
+
+    /// `cumprod` counting NaN as one.
+    #[napi]
+    pub fn nancumprod(&self, axis: Option<i64>, dtype: Option<String>) -> Result<NativeArray> {
+        crate::error::guard("NativeArray.nancumprod", || {
+            self.cumulative(CumulativeOp::NanProd, axis, dtype)
+        })
+    }
+
+    /// 2-D matrix product.
+    #[napi]
+    pub fn matmul(&self, other: &NativeArray) -> Result<NativeArray> {
+        crate::error::guard("NativeArray.matmul", || {
+            let (&[m, k], &[k2, n]) = (self.shape.as_slice(), other.shape.as_slice()) else {
+                return Err(to_napi(NumpyError::value("matmul requires 2D arrays")));
+            };
+            if k != k2 {
+                return Err(to_napi(NumpyError::shape_mismatch(
+                format!(
+                    "matmul: Input operand 1 has a mismatch in its core dimension 0 (size {} is different from {})",
+                    k2, k
+                ),
+                &self.shape,
+                &other.shape,
+            )));
+            }
+
+            let (left, right) = self.promoted_pair(other)?;
+            let storage = match (&*left.storage, &*right.storage) {
+                (Storage::Float64(a), Storage::Float64(b)) => Storage::Float64(matmul_kernel(
+                    &left,
+                    a.as_slice(),
+                    &right,
+                    b.as_slice(),
+                    m,
+                    k,
+                    n,
+                )?),
+                (Storage::Float32(a), Storage::Float32(b)) => Storage::Float32(matmul_kernel(
+                    &left,
+                    a.as_slice(),
+                    &right,
+                    b.as_slice(),
+                    m,
+                    k,
+                    n,
+                )?),
+                _ => unreachable!("operands were promoted to a common dtype"),
+            };
+            Ok(Self::contiguous(storage, vec![m, n]))
+        })
+    }
+}
+
+impl NativeArray {
+    fn contiguous(storage: Storage, shape: Vec<usize>) -> Self {
+        let strides = c_strides(&shape);
+        Self {
+            storage: Arc::new(storage),
+            shape,
+            strides,
+            offset: 0,
+        }
+    }
+
+    fn view(&self, shape: Vec<usize>, strides: Vec<isize>, offset: usize) -> Self {
+        Self {
+            storage: Arc::clone(&self.storage),
+            shape,
+            strides,
+            offset,
+        }
+    }
+
+    fn size_usize(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    fn is_c_contiguous(&self) -> bool {
+        let mut expected = 1isize;
+        for (&dim, &stride) in self.shape.iter().zip(&self.strides).rev() {
+            if dim != 1 && stride != expected {
+                return false;
+            }
+            expected *= dim as isize;
+        }
+        true
+    }
+
+    /// Elements in C order, following this view's strides.
+    fn values<'a, T: Copy>(&'a self, data: &'a [T]) -> impl Iterator<Item = T> + 'a {
+        StridedOffsets::new(&self.shape, &self.strides, self.offset).map(move |index| data[index])
+    }
+
+    fn gather<T: Copy>(&self, data: &[T]) -> Result<Output<T>> {
+        let size = self.size_usize();
+        if self.is_c_contiguous() {
+            return Output::from_iter_exact(
+                size,
+                data[self.offset..self.offset + size].iter().copied(),
+            );
+        }
+        Output::from_iter_exact(size, self.values(data))
+    }
+
+    fn unary(&self, op64: impl Fn(f64) -> f64, op32: impl Fn(f32) -> f32) -> Result<NativeArray> {
+        let size = self.size_usize();
+        let storage = match &*self.storage {
+            Storage::Float64(data) => Storage::Float64(Output::from_iter_exact(
+                size,
+                self.values(data.as_slice()).map(op64),
+            )?),
+            Storage::Float32(data) => Storage::Float32(Output::from_iter_exact(
+                size,
+                self.values(data.as_slice()).map(op32),
+            )?),
+        };
+        Ok(Self::contiguous(storage, self.shape.clone()))
+    }
+
+    /// Accumulate straight from this view's strides into a C-contiguous array.
+    fn cumulative(
+        &self,
+        op: CumulativeOp,
+        axis: Option<i64>,
+        dtype: Option<String>,
+    ) -> Result<NativeArray> {
+        let dtype = match dtype {
+            Some(dtype) => DType::parse(&dtype).map_err(to_napi)?,
+            None => self.storage.dtype(),
+        };
+        let (storage, shape) = match (&*self.storage, dtype) {
+            (Storage::Float64(data), DType::Float64) => {
+                self.accumulate(data.as_slice(), op, axis, Storage::Float64)?
+            }
+            (Storage::Float64(data), DType::Float32) => {
+                self.accumulate(data.as_slice(), op, axis, Storage::Float32)?
+            }
+            (Storage::Float32(data), DType::Float64) => {
+                self.accumulate(data.as_slice(), op, axis, Storage::Float64)?
+            }
+            (Storage::Float32(data), DType::Float32) => {
+                self.accumulate(data.as_slice(), op, axis, Storage::Float32)?
+            }
+        };
+        Ok(Self::contiguous(storage, shape))
+    }
+
+    fn accumulate<T: Scalar, U: Scalar>(
+        &self,
+        data: &[T],
+        op: CumulativeOp,
+        axis: Option<i64>,
+        storage: fn(Output<U>) -> Storage,
+    ) -> Result<(Storage, Vec<usize>)> {
+        let view = View::new(data, &self.shape, &self.strides, self.offset).map_err(to_napi)?;
+        let (out, shape) = cumulative::cumulative(view, axis, op, &Never).map_err(to_napi)?;
+        Ok((storage(Output::from_iter_exact(out.len(), out)?), shape))
+    }
+
+    fn promoted_pair(&self, other: &NativeArray) -> Result<(NativeArray, NativeArray)> {
+        let dtype = self.storage.dtype().promote(other.storage.dtype());
+        let cast = |array: &NativeArray| {
+            if array.storage.dtype() == dtype {
+                Ok(array.clone())
+            } else {
+                array.astype(dtype.name().to_string())
+            }
+        };
+        Ok((cast(self)?, cast(other)?))
+    }
+
+    /// Broadcasting elementwise binary op with NumPy type promotion.
+    fn binary(
+        &self,
+        other: &NativeArray,
+        op64: impl Fn(f64, f64) -> f64,
+        op32: impl Fn(f32, f32) -> f32,
+    ) -> Result<NativeArray> {
+        let shape = broadcast_shapes(&self.shape, &other.shape).map_err(to_napi)?;
+        let (left, right) = self.promoted_pair(other)?;
+        let left_strides = broadcast_strides(&left.shape, &left.strides, &shape);
+        let right_strides = broadcast_strides(&right.shape, &right.strides, &shape);
+        let size = checked_size(&shape).map_err(to_napi)?;
+
+        let storage = match (&*left.storage, &*right.storage) {
+            (Storage::Float64(a), Storage::Float64(b)) => Storage::Float64(binary_kernel(
+                (a.as_slice(), &left_strides, left.offset),
+                (b.as_slice(), &right_strides, right.offset),
+                &shape,
+                size,
+                op64,
+            )?),
+            (Storage::Float32(a), Storage::Float32(b)) => Storage::Float32(binary_kernel(
+                (a.as_slice(), &left_strides, left.offset),
+                (b.as_slice(), &right_strides, right.offset),
+                &shape,
+                size,
+                op32,
+            )?),
+            _ => unreachable!("operands were promoted to a common dtype"),
+        };
+        Ok(Self::contiguous(storage, shape))
+    }
+}
+
+fn binary_kernel<T: Copy>(
+    (left, left_strides, left_offset): (&[T], &[isize], usize),
+    (right, right_strides, right_offset): (&[T], &[isize], usize),
+    shape: &[usize],
+    size: usize,
+    op: impl Fn(T, T) -> T,
+) -> Result<Output<T>> {
+    let left_offsets = StridedOffsets::new(shape, left_strides, left_offset);
+    let right_offsets = StridedOffsets::new(shape, right_strides, right_offset);
+    Output::from_iter_exact(
+        size,
+        left_offsets
+            .zip(right_offsets)
+            .map(|(i, j)| op(left[i], right[j])),
+    )
+}
+
+fn matmul_kernel<T>(
+    left: &NativeArray,
+    a: &[T],
+    right: &NativeArray,
+    b: &[T],
+    m: usize,
+    k: usize,
+    n: usize,
+) -> Result<Output<T>>
+where
+    T: Copy + Default + std::ops::Add<Output = T> + std::ops::Mul<Output = T>,
+{
+    let a = left.gather(a)?;
+    let b = right.gather(b)?;
+    let (a, b) = (a.as_slice(), b.as_slice());
+    let mut output = Output::uninit(m * n)?;
+    // SAFETY: each row is zeroed before it is accumulated into.
+    let out = unsafe { output.as_mut_slice() };
+
+    for i in 0..m {
+        let row = &mut out[i * n..(i + 1) * n];
+        row.fill(T::default());
+        for kk in 0..k {
+            let a_val = a[i * k + kk];
+            for (value, &b_val) in row.iter_mut().zip(&b[kk * n..(kk + 1) * n]) {
+                *value = *value + a_val * b_val;
+            }
+        }
+    }
+    Ok(unsafe { output.assume_init() })
+}
+
+/// Storage offsets of every element of a strided view, in C order.
+struct StridedOffsets<'a> {
+    shape: &'a [usize],
+    strides: &'a [isize],
+    index: Vec<usize>,
+    current: isize,
+    remaining: usize,
+}
+
+impl<'a> StridedOffsets<'a> {
+    fn new(shape: &'a [usize], strides: &'a [isize], offset: usize) -> Self {
+        Self {
+            shape,
+            strides,
+            index: vec![0; shape.len()],
+            current: offset as isize,
+            remaining: shape.iter().product(),
+        }
+    }
+}
+
+impl Iterator for StridedOffsets<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let value = self.current as usize;
+
+        for axis in (0..self.shape.len()).rev() {
+            self.index[axis] += 1;
+            self.current += self.strides[axis];
+            if self.index[axis] < self.shape[axis] {
+                break;
+            }
+            self.current -= self.strides[axis] * self.shape[axis] as isize;
+            self.index[axis] = 0;
+        }
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+pub(crate) fn resolve_shape(shape: Option<Vec<i64>>, len: usize) -> Result<Vec<usize>> {
+    match shape {
+        Some(shape) => infer_shape(&shape, len).map_err(to_napi),
+        None => Ok(vec![len]),
+    }
+}
+
+/// Python's `slice.indices`: returns `(start, len, step)` for a dimension.
+fn slice_indices(spec: &SliceSpec, dim: usize) -> Result<(isize, usize, isize)> {
+    let step = spec.step.unwrap_or(1);
+    if step == 0 {
+        return Err(Error::new(
+            Status::InvalidArg,
+            "slice step cannot be zero".to_string(),
+        ));
+    }
+
+    let dim = dim as i64;
+    let clamp = |value: Option<i64>, default: i64| -> i64 {
+        match value {
+            None => default,
+            Some(v) if v < 0 => {
+                let v = v + dim;
+                if v < 0 {
+                    if step < 0 {
+                        -1
+                    } else {
+                        0
+                    }
+                } else {
+                    v
+                }
+            }
+            Some(v) if v >= dim => {
+                if step < 0 {
+                    dim - 1
+                } else {
+                    dim
+                }
+            }
+            Some(v) => v,
+        }
+    };
+
+    let (start, stop) = if step > 0 {
+        (clamp(spec.start, 0), clamp(spec.stop, dim))
+    } else {
+        (clamp(spec.start, dim - 1), clamp(spec.stop, -1))
+    };
+
+    let len = if step > 0 && stop > start {
+        (stop - start + step - 1) / step
+    } else if step < 0 && start > stop {
+        (start - stop - step - 1) / -step
+    } else {
+        0
+    };
+
+    Ok((start.max(0) as isize, len as usize, step as isize))
+}
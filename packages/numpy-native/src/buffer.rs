@@ -0,0 +1,139 @@
+use std::alloc::{alloc, dealloc, Layout};
+use std::mem::MaybeUninit;
+use std::ptr::NonNull;
+
+use napi::bindgen_prelude::Float64Array;
+use napi::Result;
+use sylphx_numpy_core::{ErrorKind, NumpyError};
+
+/// Outputs are aligned to a cache line so SIMD stores never split one.
+const OUTPUT_ALIGN: usize = 64;
+
+/// Rust-owned numeric storage that can be handed to JS as an external
+/// ArrayBuffer without copying.
+///
+/// Every element is initialized: storage starts as an [`UninitOutput`] and
+/// only becomes an `Output` once it is fully written.
+pub struct Output<T: Copy> {
+    ptr: NonNull<T>,
+    len: usize,
+}
+
+pub type OutputF64 = Output<f64>;
+
+unsafe impl<T: Copy + Send> Send for Output<T> {}
+unsafe impl<T: Copy + Sync> Sync for Output<T> {}
+
+/// An [`Output`] allocation whose elements have not all been written yet.
+pub struct UninitOutput<T: Copy>(Output<T>);
+
+impl<T: Copy> UninitOutput<T> {
+    pub fn as_uninit_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        unsafe { std::slice::from_raw_parts_mut(self.0.ptr.as_ptr().cast(), self.0.len) }
+    }
+
+    /// The allocation as `&mut [T]`, for kernels that write their whole output.
+    ///
+    /// # Safety
+    ///
+    /// The caller must not read an element through the slice before writing it.
+    pub unsafe fn as_mut_slice(&mut self) -> &mut [T] {
+        self.0.as_mut_slice()
+    }
+
+    /// # Safety
+    ///
+    /// Every element must have been written through [`Self::as_uninit_mut`].
+    pub unsafe fn assume_init(self) -> Output<T> {
+        self.0
+    }
+}
+
+impl<T: Copy> Output<T> {
+    /// Allocate without initializing; nothing zero-fills the memory.
+    pub fn uninit(len: usize) -> Result<UninitOutput<T>> {
+        Self::allocate(len, |layout| unsafe { alloc(layout) }).map(UninitOutput)
+    }
+
+    fn allocate(len: usize, alloc: impl FnOnce(Layout) -> *mut u8) -> Result<Self> {
+        if len == 0 {
+            return Ok(Self {
+                ptr: NonNull::dangling(),
+                len,
+            });
+        }
+
+        let layout = layout_for::<T>(len)?;
+        let ptr = NonNull::new(alloc(layout) as *mut T)
+            .ok_or_else(|| crate::error::to_napi(NumpyError::memory(layout.size())))?;
+        Ok(Self { ptr, len })
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+
+    /// Collect exactly `len` values into a fresh allocation.
+    pub fn from_iter_exact(len: usize, values: impl IntoIterator<Item = T>) -> Result<Self> {
+        let mut output = Self::uninit(len)?;
+        let mut written = 0;
+        for (slot, value) in output.as_uninit_mut().iter_mut().zip(values) {
+            slot.write(value);
+            written += 1;
+        }
+        if written != len {
+            return Err(crate::error::to_napi(NumpyError::new(
+                ErrorKind::Value,
+                format!("Expected {} output values, got {}", len, written),
+            )));
+        }
+        Ok(unsafe { output.assume_init() })
+    }
+}
+
+impl Output<f64> {
+    /// Transfer ownership to V8; the allocation is freed by the ArrayBuffer finalizer.
+    pub fn into_float64_array(self) -> Float64Array {
+        let this = std::mem::ManuallyDrop::new(self);
+        if this.len == 0 {
+            return Float64Array::new(Vec::new());
+        }
+
+        unsafe {
+            Float64Array::with_external_data(this.ptr.as_ptr(), this.len, |ptr, len| {
+                if let Ok(layout) = layout_for::<f64>(len) {
+                    dealloc(ptr as *mut u8, layout);
+                }
+            })
+        }
+    }
+}
+
+impl<T: Copy> Drop for Output<T> {
+    fn drop(&mut self) {
+        if self.len > 0 {
+            if let Ok(layout) = layout_for::<T>(self.len) {
+                unsafe { dealloc(self.ptr.as_ptr() as *mut u8, layout) };
+            }
+        }
+    }
+}
+
+fn layout_for<T>(len: usize) -> Result<Layout> {
+    len.checked_mul(std::mem::size_of::<T>())
+        .and_then(|size| Layout::from_size_align(size, OUTPUT_ALIGN).ok())
+        .ok_or_else(|| {
+            crate::error::to_napi(NumpyError::new(
+                ErrorKind::Memory,
+                format!("Output length {} overflows the address space", len),
+            ))
+        })
+}
@@ -0,0 +1,35 @@
+use std::panic::{self, AssertUnwindSafe};
+
+use napi::{Error, Result, Status};
+
+use sylphx_numpy_core::{ErrorKind, NumpyError};
+
+/// Convert a core error into the N-API error thrown to JS.
+///
+/// The message is the core JSON payload so the `@sylphx/numpy` TS layer can
+/// rethrow it as the matching NumPy error class with its structured fields.
+/// Cancellation keeps the plain `AbortError`-style message async callers expect.
+pub(crate) fn to_napi(error: NumpyError) -> Error {
+    let status = match error.kind {
+        ErrorKind::Abort => return Error::new(Status::Cancelled, error.message.clone()),
+        ErrorKind::Memory => Status::GenericFailure,
+        _ => Status::InvalidArg,
+    };
+    Error::new(status, error.to_json())
+}
+
+/// Run the body of an exported function, turning a Rust panic into a JS
+/// exception that names the function instead of aborting the Node process.
+pub(crate) fn guard<T>(name: &str, body: impl FnOnce() -> Result<T>) -> Result<T> {
+    panic::catch_unwind(AssertUnwindSafe(body)).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+            .unwrap_or("unknown panic payload");
+        Err(Error::new(
+            Status::GenericFailure,
+            format!("Rust panic in {}: {}", name, message),
+        ))
+    })
+}
@@ -0,0 +1,365 @@
+use std::sync::{Arc, OnceLock};
+
+use napi::bindgen_prelude::{Buffer, Float64Array};
+use napi::Result;
+use napi_derive::napi;
+use sylphx_numpy_core::elementwise::{self, BinaryOp, UnaryOp};
+use sylphx_numpy_core::{validate, NumpyError};
+
+use crate::buffer::OutputF64;
+use crate::error::to_napi;
+
+/// Elements per block. Registers for one block stay in L1 while every
+/// instruction of the fused program runs over it.
+const BLOCK: usize = 256;
+
+/// Deepest expression accepted. Compiling and dropping a tree both recurse
+/// once per level, so the cap keeps them well inside the native stack.
+const MAX_DEPTH: usize = 1024;
+
+enum Node {
+    Input(u32),
+    Constant(f64),
+    Unary(UnaryOp, Arc<Node>),
+    Binary(BinaryOp, Arc<Node>, Arc<Node>),
+}
+
+/// One step of the compiled program; operands are register indices.
+enum Instr {
+    Load {
+        input: usize,
+        dst: usize,
+    },
+    Fill {
+        value: f64,
+        dst: usize,
+    },
+    Unary {
+        op: UnaryOp,
+        src: usize,
+        dst: usize,
+    },
+    Binary {
+        op: BinaryOp,
+        left: usize,
+        right: usize,
+        dst: usize,
+    },
+}
+
+struct Program {
+    instrs: Vec<Instr>,
+    registers: usize,
+    inputs: usize,
+}
+
+/// A lazily recorded elementwise expression, compiled into a single fused
+/// loop like numexpr.
+///
+/// `Expr.input(0).addScalar(3).mulScalar(2).sqrt().evaluate([a])` reads `a`
+/// once and writes one output, with no intermediate arrays. Trees nest at
+/// most 1024 levels deep.
+#[napi]
+pub struct Expr {
+    node: Arc<Node>,
+    depth: usize,
+    program: OnceLock<Program>,
+}
+
+#[napi]
+impl Expr {
+    /// Reference the `index`-th array passed to `evaluate`.
+    #[napi(factory)]
+    pub fn input(index: u32) -> Result<Self> {
+        crate::error::guard("Expr.input", || Ok(Self::leaf(Node::Input(index))))
+    }
+
+    #[napi(factory)]
+    pub fn constant(value: f64) -> Result<Self> {
+        crate::error::guard("Expr.constant", || Ok(Self::leaf(Node::Constant(value))))
+    }
+
+    #[napi]
+    pub fn add(&self, other: &Expr) -> Result<Self> {
+        crate::error::guard("Expr.add", || self.binary(BinaryOp::Add, other))
+    }
+
+    #[napi]
+    pub fn sub(&self, other: &Expr) -> Result<Self> {
+        crate::error::guard("Expr.sub", || self.binary(BinaryOp::Sub, other))
+    }
+
+    #[napi]
+    pub fn mul(&self, other: &Expr) -> Result<Self> {
+        crate::error::guard("Expr.mul", || self.binary(BinaryOp::Mul, other))
+    }
+
+    #[napi]
+    pub fn div(&self, other: &Expr) -> Result<Self> {
+        crate::error::guard("Expr.div", || self.binary(BinaryOp::Div, other))
+    }
+
+    #[napi]
+    pub fn pow(&self, other: &Expr) -> Result<Self> {
+        crate::error::guard("Expr.pow", || self.binary(BinaryOp::Pow, other))
+    }
+
+    #[napi]
+    pub fn maximum(&self, other: &Expr) -> Result<Self> {
+        crate::error::guard("Expr.maximum", || self.binary(BinaryOp::Maximum, other))
+    }
+
+    #[napi]
+    pub fn minimum(&self, other: &Expr) -> Result<Self> {
+        crate::error::guard("Expr.minimum", || self.binary(BinaryOp::Minimum, other))
+    }
+
+    #[napi]
+    pub fn add_scalar(&self, value: f64) -> Result<Self> {
+        crate::error::guard("Expr.addScalar", || {
+            self.binary(BinaryOp::Add, &Self::leaf(Node::Constant(value)))
+        })
+    }
+
+    #[napi]
+    pub fn sub_scalar(&self, value: f64) -> Result<Self> {
+        crate::error::guard("Expr.subScalar", || {
+            self.binary(BinaryOp::Sub, &Self::leaf(Node::Constant(value)))
+        })
+    }
+
+    #[napi]
+    pub fn mul_scalar(&self, value: f64) -> Result<Self> {
+        crate::error::guard("Expr.mulScalar", || {
+            self.binary(BinaryOp::Mul, &Self::leaf(Node::Constant(value)))
+        })
+    }
+
+    #[napi]
+    pub fn div_scalar(&self, value: f64) -> Result<Self> {
+        crate::error::guard("Expr.divScalar", || {
+            self.binary(BinaryOp::Div, &Self::leaf(Node::Constant(value)))
+        })
+    }
+
+    #[napi]
+    pub fn pow_scalar(&self, value: f64) -> Result<Self> {
+        crate::error::guard("Expr.powScalar", || {
+            self.binary(BinaryOp::Pow, &Self::leaf(Node::Constant(value)))
+        })
+    }
+
+    #[napi]
+    pub fn neg(&self) -> Result<Self> {
+        crate::error::guard("Expr.neg", || self.unary(UnaryOp::Neg))
+    }
+
+    #[napi]
+    pub fn abs(&self) -> Result<Self> {
+        crate::error::guard("Expr.abs", || self.unary(UnaryOp::Abs))
+    }
+
+    #[napi]
+    pub fn sqrt(&self) -> Result<Self> {
+        crate::error::guard("Expr.sqrt", || self.unary(UnaryOp::Sqrt))
+    }
+
+    #[napi]
+    pub fn square(&self) -> Result<Self> {
+        crate::error::guard("Expr.square", || self.unary(UnaryOp::Square))
+    }
+
+    #[napi]
+    pub fn exp(&self) -> Result<Self> {
+        crate::error::guard("Expr.exp", || self.unary(UnaryOp::Exp))
+    }
+
+    #[napi]
+    pub fn log(&self) -> Result<Self> {
+        crate::error::guard("Expr.log", || self.unary(UnaryOp::Log))
+    }
+
+    #[napi]
+    pub fn sin(&self) -> Result<Self> {
+        crate::error::guard("Expr.sin", || self.unary(UnaryOp::Sin))
+    }
+
+    #[napi]
+    pub fn cos(&self) -> Result<Self> {
+        crate::error::guard("Expr.cos", || self.unary(UnaryOp::Cos))
+    }
+
+    #[napi]
+    pub fn tanh(&self) -> Result<Self> {
+        crate::error::guard("Expr.tanh", || self.unary(UnaryOp::Tanh))
+    }
+
+    /// Number of arrays `evaluate` expects.
+    #[napi(getter)]
+    pub fn input_count(&self) -> Result<u32> {
+        crate::error::guard("Expr.inputCount", || Ok(self.program().inputs as u32))
+    }
+
+    /// Run the fused loop once over equal-length inputs.
+    #[napi]
+    pub fn evaluate(&self, inputs: Vec<Float64Array>) -> Result<Float64Array> {
+        crate::error::guard("Expr.evaluate", || {
+            let inputs: Vec<&[f64]> = inputs.iter().map(|input| input.as_ref()).collect();
+            let len = self.validate_inputs(&inputs)?;
+            let mut output = OutputF64::uninit(len)?;
+            // SAFETY: `run` copies every block of the result into `output` and
+            // never reads it.
+            self.program()
+                .run(&inputs, unsafe { output.as_mut_slice() });
+            Ok(unsafe { output.assume_init() }.into_float64_array())
+        })
+    }
+
+    /// Run the fused loop into a caller-owned Buffer over Float64Array memory.
+    #[napi]
+    pub fn evaluate_into(&self, inputs: Vec<Float64Array>, mut output: Buffer) -> Result<()> {
+        crate::error::guard("Expr.evaluateInto", || {
+            let inputs: Vec<&[f64]> = inputs.iter().map(|input| input.as_ref()).collect();
+            let len = self.validate_inputs(&inputs)?;
+            let output = crate::output_as_f64_mut(&mut output, len)?;
+            self.program().run(&inputs, output);
+            Ok(())
+        })
+    }
+}
+
+impl Expr {
+    fn leaf(node: Node) -> Self {
+        Self {
+            node: Arc::new(node),
+            depth: 1,
+            program: OnceLock::new(),
+        }
+    }
+
+    fn nested(node: Node, depth: usize) -> Result<Self> {
+        if depth > MAX_DEPTH {
+            return Err(to_napi(NumpyError::value(format!(
+                "Expression is nested more than {} levels deep",
+                MAX_DEPTH
+            ))));
+        }
+        Ok(Self {
+            node: Arc::new(node),
+            depth,
+            program: OnceLock::new(),
+        })
+    }
+
+    fn unary(&self, op: UnaryOp) -> Result<Self> {
+        Self::nested(Node::Unary(op, Arc::clone(&self.node)), self.depth + 1)
+    }
+
+    fn binary(&self, op: BinaryOp, other: &Expr) -> Result<Self> {
+        Self::nested(
+            Node::Binary(op, Arc::clone(&self.node), Arc::clone(&other.node)),
+            self.depth.max(other.depth) + 1,
+        )
+    }
+
+    fn program(&self) -> &Program {
+        self.program.get_or_init(|| Program::compile(&self.node))
+    }
+
+    fn validate_inputs(&self, inputs: &[&[f64]]) -> Result<usize> {
+        let expected = self.program().inputs;
+        if inputs.len() < expected {
+            return Err(to_napi(NumpyError::value(format!(
+                "Expression reads {} inputs, got {}",
+                expected,
+                inputs.len()
+            ))));
+        }
+
+        let len = inputs.first().map_or(0, |input| input.len());
+        for input in &inputs[..expected] {
+            validate::same_len(len, input.len()).map_err(to_napi)?;
+        }
+        Ok(len)
+    }
+}
+
+impl Program {
+    /// Lower the tree to register code in post-order, reusing registers like
+    /// a stack so the register file is only as deep as the tree.
+    fn compile(root: &Node) -> Self {
+        let mut program = Self {
+            instrs: Vec::new(),
+            registers: 0,
+            inputs: 0,
+        };
+        program.emit(root, 0);
+        program
+    }
+
+    fn emit(&mut self, node: &Node, dst: usize) {
+        self.registers = self.registers.max(dst + 1);
+        match node {
+            Node::Input(index) => {
+                let input = *index as usize;
+                self.inputs = self.inputs.max(input + 1);
+                self.instrs.push(Instr::Load { input, dst });
+            }
+            Node::Constant(value) => self.instrs.push(Instr::Fill { value: *value, dst }),
+            Node::Unary(op, operand) => {
+                self.emit(operand, dst);
+                self.instrs.push(Instr::Unary {
+                    op: *op,
+                    src: dst,
+                    dst,
+                });
+            }
+            Node::Binary(op, left, right) => {
+                self.emit(left, dst);
+                self.emit(right, dst + 1);
+                self.instrs.push(Instr::Binary {
+                    op: *op,
+                    left: dst,
+                    right: dst + 1,
+                    dst,
+                });
+            }
+        }
+    }
+
+    fn run(&self, inputs: &[&[f64]], output: &mut [f64]) {
+        let mut registers = vec![[0.0f64; BLOCK]; self.registers];
+        let mut start = 0;
+
+        while start < output.len() {
+            let len = BLOCK.min(output.len() - start);
+
+            for instr in &self.instrs {
+                match *instr {
+                    Instr::Load { input, dst } => {
+                        registers[dst][..len].copy_from_slice(&inputs[input][start..start + len]);
+                    }
+                    Instr::Fill { value, dst } => registers[dst][..len].fill(value),
+                    Instr::Unary { op, src, dst } => {
+                        debug_assert_eq!(src, dst);
+                        elementwise::unary_in_place(op, &mut registers[dst][..len]);
+                    }
+                    Instr::Binary {
+                        op,
+                        left,
+                        right,
+                        dst,
+                    } => {
+                        debug_assert!(left == dst && right == dst + 1);
+                        let (head, tail) = registers.split_at_mut(right);
+                        elementwise::binary_in_place(op, &mut head[left][..len], &tail[0][..len])
+                            .expect("registers hold one block each");
+                    }
+                }
+            }
+
+            output[start..start + len].copy_from_slice(&registers[0][..len]);
+            start += len;
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use napi::bindgen_prelude::{AsyncTask, Float64Array};
+use napi::Result;
+use napi_derive::napi;
+use sylphx_numpy_core::fft;
+
+use crate::buffer::OutputF64;
+use crate::error::to_napi;
+use crate::task::{Cancel, CancellationToken, NativeTask};
+
+/// Discrete Fourier transform of any length, like `np.fft.fft` / `np.fft.ifft`.
+///
+/// Takes separate real and optional imaginary parts and returns interleaved
+/// `[re0, im0, re1, im1, ...]`. Power-of-two lengths use radix-2; others use
+/// Bluestein's algorithm.
+#[napi]
+pub fn fft_f64(real: &[f64], imag: Option<&[f64]>, inverse: Option<bool>) -> Result<Float64Array> {
+    crate::error::guard("fftF64", || {
+        fft(real, imag, inverse.unwrap_or(false), &Cancel::none())
+    })
+}
+
+#[napi]
+pub fn fft_f64_async(
+    real: Float64Array,
+    imag: Option<Float64Array>,
+    inverse: Option<bool>,
+    token: Option<&CancellationToken>,
+) -> Result<AsyncTask<NativeTask<Float64Array>>> {
+    crate::error::guard("fftF64Async", || {
+        let cancel = Cancel::from_token(token);
+        Ok(AsyncTask::new(NativeTask::new("fftF64Async", move || {
+            fft(&real, imag.as_deref(), inverse.unwrap_or(false), &cancel)
+        })))
+    })
+}
+
+fn fft(real: &[f64], imag: Option<&[f64]>, inverse: bool, cancel: &Cancel) -> Result<Float64Array> {
+    let mut output = OutputF64::uninit(real.len().saturating_mul(2))?;
+    // SAFETY: `fft_into` transforms a copy of the input and only writes `out`.
+    fft::fft_into(
+        real,
+        imag,
+        inverse,
+        unsafe { output.as_mut_slice() },
+        cancel,
+    )
+    .map_err(to_napi)?;
+    Ok(unsafe { output.assume_init() }.into_float64_array())
+}
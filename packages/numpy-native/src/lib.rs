@@ -1,53 +1,76 @@
 #![deny(clippy::all)]
 
+pub mod array;
+mod buffer;
+mod error;
+pub mod expr;
+pub mod fft;
+pub mod linalg;
+pub mod memmap;
+pub mod sort;
+pub mod task;
+pub mod text;
+pub mod ufunc;
+
 use napi::bindgen_prelude::{Buffer, Float64Array};
 use napi::{Error, Result, Status};
 use napi_derive::napi;
 
-fn uninit_vec(len: usize) -> Vec<f64> {
-    vec![0.0; len]
-}
+use sylphx_numpy_core::elementwise::{self, BinaryOp};
+use sylphx_numpy_core::{validate, NumpyError};
+
+use crate::buffer::OutputF64;
 
 #[napi]
-pub fn add_scalar_f64(input: &[f64], scalar: f64) -> Float64Array {
-    let mut output = uninit_vec(input.len());
-    add_scalar_into(input, scalar, &mut output);
-    output.into()
+pub fn add_scalar_f64(input: &[f64], scalar: f64) -> Result<Float64Array> {
+    crate::error::guard("addScalarF64", || {
+        let output = OutputF64::from_iter_exact(input.len(), input.iter().map(|&x| x + scalar))?;
+        Ok(output.into_float64_array())
+    })
 }
 
 #[napi]
-pub fn mul_scalar_f64(input: &[f64], scalar: f64) -> Float64Array {
-    let mut output = uninit_vec(input.len());
-    mul_scalar_into(input, scalar, &mut output);
-    output.into()
+pub fn mul_scalar_f64(input: &[f64], scalar: f64) -> Result<Float64Array> {
+    crate::error::guard("mulScalarF64", || {
+        let output = OutputF64::from_iter_exact(input.len(), input.iter().map(|&x| x * scalar))?;
+        Ok(output.into_float64_array())
+    })
 }
 
 #[napi]
 pub fn add_f64(left: &[f64], right: &[f64]) -> Result<Float64Array> {
-    let mut output = uninit_vec(left.len());
-    add_into(left, right, &mut output)?;
-    Ok(output.into())
+    crate::error::guard("addF64", || {
+        validate::same_len(left.len(), right.len()).map_err(error::to_napi)?;
+        let sums = left.iter().zip(right).map(|(&l, &r)| l + r);
+        Ok(OutputF64::from_iter_exact(left.len(), sums)?.into_float64_array())
+    })
 }
 
 #[napi]
 pub fn add_scalar_f64_buffer(input: &[f64], scalar: f64, mut output: Buffer) -> Result<Buffer> {
-    let output_slice = output_as_f64_mut(&mut output, input.len())?;
-    add_scalar_into(input, scalar, output_slice);
-    Ok(output)
+    crate::error::guard("addScalarF64Buffer", || {
+        let output_slice = output_as_f64_mut(&mut output, input.len())?;
+        add_scalar_into(input, scalar, output_slice)?;
+        Ok(output)
+    })
 }
 
 #[napi]
 pub fn mul_scalar_f64_buffer(input: &[f64], scalar: f64, mut output: Buffer) -> Result<Buffer> {
-    let output_slice = output_as_f64_mut(&mut output, input.len())?;
-    mul_scalar_into(input, scalar, output_slice);
-    Ok(output)
+    crate::error::guard("mulScalarF64Buffer", || {
+        let output_slice = output_as_f64_mut(&mut output, input.len())?;
+        mul_scalar_into(input, scalar, output_slice)?;
+        Ok(output)
+    })
 }
 
 #[napi]
 pub fn add_f64_buffer(left: &[f64], right: &[f64], mut output: Buffer) -> Result<Buffer> {
-    let output_slice = output_as_f64_mut(&mut output, left.len())?;
-    add_into(left, right, output_slice)?;
-    Ok(output)
+    crate::error::guard("addF64Buffer", || {
+        let output_slice = output_as_f64_mut(&mut output, left.len())?;
+        add_into(left, right, output_slice)?;
+        Ok(output)
+    })
 }
 
 #[napi]
@@ -56,11 +79,13 @@ pub fn add_scalar_f64_buffers(
     scalar: f64,
     mut output: Buffer,
 ) -> Result<Buffer> {
-    let input_len = input.len() / std::mem::size_of::<f64>();
-    let input_slice = buffer_as_f64(&mut input, input_len)?;
-    let output_slice = output_as_f64_mut(&mut output, input_len)?;
-    add_scalar_into(input_slice, scalar, output_slice);
-    Ok(output)
+    crate::error::guard("addScalarF64Buffers", || {
+        let input_len = input.len() / std::mem::size_of::<f64>();
+        let input_slice = buffer_as_f64(&mut input, input_len)?;
+        let output_slice = output_as_f64_mut(&mut output, input_len)?;
+        add_scalar_into(input_slice, scalar, output_slice)?;
+        Ok(output)
+    })
 }
 
 #[napi]
@@ -69,11 +94,13 @@ pub fn add_scalar_f64_buffers_into(
     scalar: f64,
     mut output: Buffer,
 ) -> Result<()> {
-    let input_len = input.len() / std::mem::size_of::<f64>();
-    let input_slice = buffer_as_f64(&mut input, input_len)?;
-    let output_slice = output_as_f64_mut(&mut output, input_len)?;
-    add_scalar_into(input_slice, scalar, output_slice);
-    Ok(())
+    crate::error::guard("addScalarF64BuffersInto", || {
+        let input_len = input.len() / std::mem::size_of::<f64>();
+        let input_slice = buffer_as_f64(&mut input, input_len)?;
+        let output_slice = output_as_f64_mut(&mut output, input_len)?;
+        add_scalar_into(input_slice, scalar, output_slice)?;
+        Ok(())
+    })
 }
 
 #[napi]
@@ -82,11 +109,13 @@ pub fn mul_scalar_f64_buffers(
     scalar: f64,
     mut output: Buffer,
 ) -> Result<Buffer> {
-    let input_len = input.len() / std::mem::size_of::<f64>();
-    let input_slice = buffer_as_f64(&mut input, input_len)?;
-    let output_slice = output_as_f64_mut(&mut output, input_len)?;
-    mul_scalar_into(input_slice, scalar, output_slice);
-    Ok(output)
+    crate::error::guard("mulScalarF64Buffers", || {
+        let input_len = input.len() / std::mem::size_of::<f64>();
+        let input_slice = buffer_as_f64(&mut input, input_len)?;
+        let output_slice = output_as_f64_mut(&mut output, input_len)?;
+        mul_scalar_into(input_slice, scalar, output_slice)?;
+        Ok(output)
+    })
 }
 
 #[napi]
@@ -95,31 +124,37 @@ pub fn mul_scalar_f64_buffers_into(
     scalar: f64,
     mut output: Buffer,
 ) -> Result<()> {
-    let input_len = input.len() / std::mem::size_of::<f64>();
-    let input_slice = buffer_as_f64(&mut input, input_len)?;
-    let output_slice = output_as_f64_mut(&mut output, input_len)?;
-    mul_scalar_into(input_slice, scalar, output_slice);
-    Ok(())
+    crate::error::guard("mulScalarF64BuffersInto", || {
+        let input_len = input.len() / std::mem::size_of::<f64>();
+        let input_slice = buffer_as_f64(&mut input, input_len)?;
+        let output_slice = output_as_f64_mut(&mut output, input_len)?;
+        mul_scalar_into(input_slice, scalar, output_slice)?;
+        Ok(())
+    })
 }
 
 #[napi]
 pub fn add_f64_buffers(mut left: Buffer, mut right: Buffer, mut output: Buffer) -> Result<Buffer> {
-    let left_len = left.len() / std::mem::size_of::<f64>();
-    let left_slice = buffer_as_f64(&mut left, left_len)?;
-    let right_slice = buffer_as_f64(&mut right, left_len)?;
-    let output_slice = output_as_f64_mut(&mut output, left_len)?;
-    add_into(left_slice, right_slice, output_slice)?;
-    Ok(output)
+    crate::error::guard("addF64Buffers", || {
+        let left_len = left.len() / std::mem::size_of::<f64>();
+        let left_slice = buffer_as_f64(&mut left, left_len)?;
+        let right_slice = buffer_as_f64(&mut right, left_len)?;
+        let output_slice = output_as_f64_mut(&mut output, left_len)?;
+        add_into(left_slice, right_slice, output_slice)?;
+        Ok(output)
+    })
 }
 
 #[napi]
 pub fn add_f64_buffers_into(mut left: Buffer, mut right: Buffer, mut output: Buffer) -> Result<()> {
-    let left_len = left.len() / std::mem::size_of::<f64>();
-    let left_slice = buffer_as_f64(&mut left, left_len)?;
-    let right_slice = buffer_as_f64(&mut right, left_len)?;
-    let output_slice = output_as_f64_mut(&mut output, left_len)?;
-    add_into(left_slice, right_slice, output_slice)?;
-    Ok(())
+    crate::error::guard("addF64BuffersInto", || {
+        let left_len = left.len() / std::mem::size_of::<f64>();
+        let left_slice = buffer_as_f64(&mut left, left_len)?;
+        let right_slice = buffer_as_f64(&mut right, left_len)?;
+        let output_slice = output_as_f64_mut(&mut output, left_len)?;
+        add_into(left_slice, right_slice, output_slice)?;
+        Ok(())
+    })
 }
 
 #[napi]
@@ -129,26 +164,14 @@ pub fn transpose_f64_buffer(
     cols: u32,
     mut output: Buffer,
 ) -> Result<Buffer> {
-    let rows = rows as usize;
-    let cols = cols as usize;
-    let expected_len = rows
-        .checked_mul(cols)
-        .ok_or_else(|| Error::new(Status::InvalidArg, "Matrix dimensions overflow".to_string()))?;
-
-    if input.len() != expected_len {
-        return Err(Error::new(
-            Status::InvalidArg,
-            format!(
-                "Expected input length {}, got {}",
-                expected_len,
-                input.len()
-            ),
-        ));
-    }
-
-    let output_slice = output_as_f64_mut(&mut output, expected_len)?;
-    transpose_into(input, rows, cols, output_slice);
-    Ok(output)
+    crate::error::guard("transposeF64Buffer", || {
+        let (rows, cols) = (rows as usize, cols as usize);
+        validate::matrix_len("Input", input.len(), rows, cols).map_err(error::to_napi)?;
+        let output_slice = output_as_f64_mut(&mut output, input.len())?;
+        sylphx_numpy_core::linalg::transpose_into(input, rows, cols, output_slice)
+            .map_err(error::to_napi)?;
+        Ok(output)
+    })
 }
 
 fn buffer_as_f64(buffer: &mut Buffer, expected_len: usize) -> Result<&[f64]> {
@@ -168,14 +191,15 @@ fn validate_f64_bytes(bytes: &[u8], expected_len: usize) -> Result<()> {
     let expected_bytes = expected_len * F64_BYTES;
 
     if bytes.len() != expected_bytes {
-        return Err(Error::new(
-            Status::InvalidArg,
+        return Err(error::to_napi(NumpyError::shape_mismatch(
             format!(
                 "Expected output byte length {}, got {}",
                 expected_bytes,
                 bytes.len()
             ),
-        ));
+            &[expected_bytes],
+            &[bytes.len()],
+        )));
     }
 
     if bytes.as_ptr() as usize % F64_BYTES != 0 {
@@ -188,209 +212,14 @@ fn validate_f64_bytes(bytes: &[u8], expected_len: usize) -> Result<()> {
     Ok(())
 }
 
-fn ensure_equal_len(left_len: usize, right_len: usize) -> Result<()> {
-    if left_len != right_len {
-        return Err(Error::new(
-            Status::InvalidArg,
-            format!(
-                "Expected equal input lengths, got {} and {}",
-                left_len, right_len
-            ),
-        ));
-    }
-    Ok(())
-}
-
-fn add_scalar_into(input: &[f64], scalar: f64, output: &mut [f64]) {
-    debug_assert_eq!(input.len(), output.len());
-
-    #[cfg(target_arch = "aarch64")]
-    unsafe {
-        add_scalar_into_neon(input, scalar, output);
-    }
-
-    #[cfg(not(target_arch = "aarch64"))]
-    add_scalar_into_unrolled(input, scalar, output);
-}
-
-#[cfg(not(target_arch = "aarch64"))]
-fn add_scalar_into_unrolled(input: &[f64], scalar: f64, output: &mut [f64]) {
-    let len = input.len();
-    let unrolled_len = len - (len % 4);
-    let mut i = 0;
-
-    while i < unrolled_len {
-        output[i] = input[i] + scalar;
-        output[i + 1] = input[i + 1] + scalar;
-        output[i + 2] = input[i + 2] + scalar;
-        output[i + 3] = input[i + 3] + scalar;
-        i += 4;
-    }
-
-    while i < len {
-        output[i] = input[i] + scalar;
-        i += 1;
-    }
-}
-
-fn mul_scalar_into(input: &[f64], scalar: f64, output: &mut [f64]) {
-    debug_assert_eq!(input.len(), output.len());
-
-    #[cfg(target_arch = "aarch64")]
-    unsafe {
-        mul_scalar_into_neon(input, scalar, output);
-    }
-
-    #[cfg(not(target_arch = "aarch64"))]
-    mul_scalar_into_unrolled(input, scalar, output);
-}
-
-#[cfg(not(target_arch = "aarch64"))]
-fn mul_scalar_into_unrolled(input: &[f64], scalar: f64, output: &mut [f64]) {
-    let len = input.len();
-    let unrolled_len = len - (len % 4);
-    let mut i = 0;
-
-    while i < unrolled_len {
-        output[i] = input[i] * scalar;
-        output[i + 1] = input[i + 1] * scalar;
-        output[i + 2] = input[i + 2] * scalar;
-        output[i + 3] = input[i + 3] * scalar;
-        i += 4;
-    }
-
-    while i < len {
-        output[i] = input[i] * scalar;
-        i += 1;
-    }
-}
-
-#[cfg(target_arch = "aarch64")]
-unsafe fn add_scalar_into_neon(input: &[f64], scalar: f64, output: &mut [f64]) {
-    use std::arch::aarch64::{vaddq_f64, vdupq_n_f64, vld1q_f64, vst1q_f64};
-
-    // The public wrappers validate equal input/output lengths before reaching this hot loop.
-    let len = input.len();
-    let vector_len = len - (len % 8);
-    let scalar_vector = vdupq_n_f64(scalar);
-    let input_ptr = input.as_ptr();
-    let output_ptr = output.as_mut_ptr();
-    let mut i = 0;
-
-    while i < vector_len {
-        let left0 = vld1q_f64(input_ptr.add(i));
-        let left1 = vld1q_f64(input_ptr.add(i + 2));
-        let left2 = vld1q_f64(input_ptr.add(i + 4));
-        let left3 = vld1q_f64(input_ptr.add(i + 6));
-
-        vst1q_f64(output_ptr.add(i), vaddq_f64(left0, scalar_vector));
-        vst1q_f64(output_ptr.add(i + 2), vaddq_f64(left1, scalar_vector));
-        vst1q_f64(output_ptr.add(i + 4), vaddq_f64(left2, scalar_vector));
-        vst1q_f64(output_ptr.add(i + 6), vaddq_f64(left3, scalar_vector));
-
-        i += 8;
-    }
-
-    while i < len {
-        *output_ptr.add(i) = *input_ptr.add(i) + scalar;
-        i += 1;
-    }
+fn add_scalar_into(input: &[f64], scalar: f64, output: &mut [f64]) -> Result<()> {
+    elementwise::scalar_into(BinaryOp::Add, input, scalar, output).map_err(error::to_napi)
 }
 
-#[cfg(target_arch = "aarch64")]
-unsafe fn mul_scalar_into_neon(input: &[f64], scalar: f64, output: &mut [f64]) {
-    use std::arch::aarch64::{vdupq_n_f64, vld1q_f64, vmulq_f64, vst1q_f64};
-
-    // The public wrappers validate equal input/output lengths before reaching this hot loop.
-    let len = input.len();
-    let vector_len = len - (len % 8);
-    let scalar_vector = vdupq_n_f64(scalar);
-    let input_ptr = input.as_ptr();
-    let output_ptr = output.as_mut_ptr();
-    let mut i = 0;
-
-    while i < vector_len {
-        let left0 = vld1q_f64(input_ptr.add(i));
-        let left1 = vld1q_f64(input_ptr.add(i + 2));
-        let left2 = vld1q_f64(input_ptr.add(i + 4));
-        let left3 = vld1q_f64(input_ptr.add(i + 6));
-
-        vst1q_f64(output_ptr.add(i), vmulq_f64(left0, scalar_vector));
-        vst1q_f64(output_ptr.add(i + 2), vmulq_f64(left1, scalar_vector));
-        vst1q_f64(output_ptr.add(i + 4), vmulq_f64(left2, scalar_vector));
-        vst1q_f64(output_ptr.add(i + 6), vmulq_f64(left3, scalar_vector));
-
-        i += 8;
-    }
-
-    while i < len {
-        *output_ptr.add(i) = *input_ptr.add(i) * scalar;
-        i += 1;
-    }
+fn mul_scalar_into(input: &[f64], scalar: f64, output: &mut [f64]) -> Result<()> {
+    elementwise::scalar_into(BinaryOp::Mul, input, scalar, output).map_err(error::to_napi)
 }
 
 fn add_into(left: &[f64], right: &[f64], output: &mut [f64]) -> Result<()> {
-    ensure_equal_len(left.len(), right.len())?;
-    if left.len() != output.len() {
-        return Err(Error::new(
-            Status::InvalidArg,
-            format!(
-                "Expected output length {}, got {}",
-                left.len(),
-                output.len()
-            ),
-        ));
-    }
-
-    let len = left.len();
-    let unrolled_len = len - (len % 4);
-    let mut i = 0;
-
-    while i < unrolled_len {
-        output[i] = left[i] + right[i];
-        output[i + 1] = left[i + 1] + right[i + 1];
-        output[i + 2] = left[i + 2] + right[i + 2];
-        output[i + 3] = left[i + 3] + right[i + 3];
-        i += 4;
-    }
-
-    while i < len {
-        output[i] = left[i] + right[i];
-        i += 1;
-    }
-
-    Ok(())
-}
-
-fn transpose_into(input: &[f64], rows: usize, cols: usize, output: &mut [f64]) {
-    debug_assert_eq!(input.len(), output.len());
-
-    const TILE: usize = 32;
-
-    let mut row_block = 0;
-    while row_block < rows {
-        let row_max = (row_block + TILE).min(rows);
-        let mut col_block = 0;
-
-        while col_block < cols {
-            let col_max = (col_block + TILE).min(cols);
-            let mut row = row_block;
-
-            while row < row_max {
-                let input_offset = row * cols;
-                let mut col = col_block;
-
-                while col < col_max {
-                    output[col * rows + row] = input[input_offset + col];
-                    col += 1;
-                }
-
-                row += 1;
-            }
-
-            col_block += TILE;
-        }
-
-        row_block += TILE;
-    }
+    elementwise::binary_into(BinaryOp::Add, left, right, output).map_err(error::to_napi)
 }
@@ -0,0 +1,185 @@
+use napi::bindgen_prelude::{AsyncTask, Either, Float64Array};
+use napi::Result;
+use napi_derive::napi;
+use sylphx_numpy_core::norm::{self, NormOrd};
+use sylphx_numpy_core::{linalg, validate};
+
+use crate::buffer::OutputF64;
+use crate::error::to_napi;
+use crate::task::{Cancel, CancellationToken, NativeTask};
+
+#[napi(object, object_from_js = false)]
+pub struct SvdResult {
+    /// Left singular vectors, `m x k` row-major with `k = min(m, n)`.
+    pub u: Float64Array,
+    /// Singular values in descending order.
+    pub s: Float64Array,
+    /// Right singular vectors, `k x n` row-major.
+    pub vt: Float64Array,
+}
+
+#[napi(object)]
+pub struct NormOptions {
+    /// A number (including `Infinity`), `'fro'` or `'nuc'`. Defaults to the
+    /// 2-norm for vectors and Frobenius for matrices.
+    pub ord: Option<Either<f64, String>>,
+    /// One axis for vector norms or two for matrix norms; negative indices
+    /// count from the end. Defaults to every axis of a 1-D or 2-D input.
+    pub axis: Option<Vec<i64>>,
+    pub keepdims: Option<bool>,
+}
+
+#[napi(object, object_from_js = false)]
+pub struct NormResult {
+    pub data: Float64Array,
+    pub shape: Vec<u32>,
+}
+
+/// `A @ B` for row-major `m x k` and `k x n` matrices.
+#[napi]
+pub fn matmul_f64(a: &[f64], b: &[f64], m: u32, k: u32, n: u32) -> Result<Float64Array> {
+    crate::error::guard("matmulF64", || {
+        matmul(a, b, m as usize, k as usize, n as usize, &Cancel::none())
+    })
+}
+
+#[napi]
+pub fn matmul_f64_async(
+    a: Float64Array,
+    b: Float64Array,
+    m: u32,
+    k: u32,
+    n: u32,
+    token: Option<&CancellationToken>,
+) -> Result<AsyncTask<NativeTask<Float64Array>>> {
+    crate::error::guard("matmulF64Async", || {
+        let cancel = Cancel::from_token(token);
+        Ok(AsyncTask::new(NativeTask::new(
+            "matmulF64Async",
+            move || matmul(&a, &b, m as usize, k as usize, n as usize, &cancel),
+        )))
+    })
+}
+
+/// Lower-triangular `L` with `A = L @ L.T` for a symmetric positive-definite `n x n` matrix.
+#[napi]
+pub fn cholesky_f64(a: &[f64], n: u32) -> Result<Float64Array> {
+    crate::error::guard("choleskyF64", || cholesky(a, n as usize, &Cancel::none()))
+}
+
+#[napi]
+pub fn cholesky_f64_async(
+    a: Float64Array,
+    n: u32,
+    token: Option<&CancellationToken>,
+) -> Result<AsyncTask<NativeTask<Float64Array>>> {
+    crate::error::guard("choleskyF64Async", || {
+        let cancel = Cancel::from_token(token);
+        Ok(AsyncTask::new(NativeTask::new(
+            "choleskyF64Async",
+            move || cholesky(&a, n as usize, &cancel),
+        )))
+    })
+}
+
+/// Reduced singular value decomposition of a row-major `m x n` matrix,
+/// like `np.linalg.svd(a, full_matrices=False)`.
+#[napi]
+pub fn svd_f64(a: &[f64], m: u32, n: u32) -> Result<SvdResult> {
+    crate::error::guard("svdF64", || svd(a, m as usize, n as usize, &Cancel::none()))
+}
+
+#[napi]
+pub fn svd_f64_async(
+    a: Float64Array,
+    m: u32,
+    n: u32,
+    token: Option<&CancellationToken>,
+) -> Result<AsyncTask<NativeTask<SvdResult>>> {
+    crate::error::guard("svdF64Async", || {
+        let cancel = Cancel::from_token(token);
+        Ok(AsyncTask::new(NativeTask::new("svdF64Async", move || {
+            svd(&a, m as usize, n as usize, &cancel)
+        })))
+    })
+}
+
+/// Vector or matrix norm of a C-order array, like `np.linalg.norm`.
+#[napi]
+pub fn norm_f64(a: &[f64], shape: Vec<u32>, options: Option<NormOptions>) -> Result<NormResult> {
+    crate::error::guard("normF64", || {
+        let options = options.unwrap_or(NormOptions {
+            ord: None,
+            axis: None,
+            keepdims: None,
+        });
+        let ord = match options.ord {
+            None => None,
+            Some(Either::A(p)) => Some(NormOrd::P(p)),
+            Some(Either::B(name)) => Some(NormOrd::parse(&name).map_err(to_napi)?),
+        };
+        let shape: Vec<usize> = shape.into_iter().map(|dim| dim as usize).collect();
+        let (data, shape) = norm::norm(
+            a,
+            &shape,
+            ord,
+            options.axis.as_deref(),
+            options.keepdims.unwrap_or(false),
+            &Cancel::none(),
+        )
+        .map_err(to_napi)?;
+        Ok(NormResult {
+            data: data.into(),
+            shape: shape.into_iter().map(|dim| dim as u32).collect(),
+        })
+    })
+}
+
+fn matmul(
+    a: &[f64],
+    b: &[f64],
+    m: usize,
+    k: usize,
+    n: usize,
+    cancel: &Cancel,
+) -> Result<Float64Array> {
+    let mut output = OutputF64::uninit(validate::checked_len(m, n).map_err(to_napi)?)?;
+    // SAFETY: `matmul_into` zeroes each block of rows before accumulating into it.
+    linalg::matmul_into(a, b, m, k, n, unsafe { output.as_mut_slice() }, cancel)
+        .map_err(to_napi)?;
+    Ok(unsafe { output.assume_init() }.into_float64_array())
+}
+
+fn cholesky(a: &[f64], n: usize, cancel: &Cancel) -> Result<Float64Array> {
+    let mut output = OutputF64::uninit(a.len())?;
+    // SAFETY: `cholesky_into` zeroes `L` before filling in the lower triangle.
+    linalg::cholesky_into(a, n, unsafe { output.as_mut_slice() }, cancel).map_err(to_napi)?;
+    Ok(unsafe { output.assume_init() }.into_float64_array())
+}
+
+fn svd(a: &[f64], m: usize, n: usize, cancel: &Cancel) -> Result<SvdResult> {
+    validate::matrix_len("A", a.len(), m, n).map_err(to_napi)?;
+    let k = m.min(n);
+    let mut u = OutputF64::uninit(m * k)?;
+    let mut s = OutputF64::uninit(k)?;
+    let mut vt = OutputF64::uninit(k * n)?;
+    // SAFETY: `svd_into` works on its own copies and writes every singular
+    // value and whole factors into `u`, `s` and `vt` at the end.
+    linalg::svd_into(
+        a,
+        m,
+        n,
+        unsafe { u.as_mut_slice() },
+        unsafe { s.as_mut_slice() },
+        unsafe { vt.as_mut_slice() },
+        cancel,
+    )
+    .map_err(to_napi)?;
+
+    let (u, s, vt) = unsafe { (u.assume_init(), s.assume_init(), vt.assume_init()) };
+    Ok(SvdResult {
+        u: u.into_float64_array(),
+        s: s.into_float64_array(),
+        vt: vt.into_float64_array(),
+    })
+}
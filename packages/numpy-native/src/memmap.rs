@@ -0,0 +1,478 @@
+use std::fs::{File, OpenOptions};
+use std::sync::Arc;
+
+use memmap2::{MmapMut, MmapOptions};
+use napi::{Env, Error, JsArrayBuffer, Result, Status};
+use napi_derive::napi;
+use sylphx_numpy_core::NumpyError;
+
+use crate::error::to_napi;
+
+const NPY_MAGIC: &[u8] = b"\x93NUMPY";
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum MmapMode {
+    ReadOnly,
+    ReadWrite,
+    CopyOnWrite,
+}
+
+impl MmapMode {
+    fn parse(mode: Option<&str>) -> Result<Self> {
+        match mode.unwrap_or("r") {
+            "r" | "readonly" => Ok(Self::ReadOnly),
+            "r+" | "readwrite" => Ok(Self::ReadWrite),
+            "c" | "copyonwrite" => Ok(Self::CopyOnWrite),
+            other => Err(Error::new(
+                Status::InvalidArg,
+                format!("mmap_mode must be one of 'r', 'r+' or 'c', got '{}'", other),
+            )),
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::ReadOnly => "r",
+            Self::ReadWrite => "r+",
+            Self::CopyOnWrite => "c",
+        }
+    }
+}
+
+struct NpyHeader {
+    descr: String,
+    fortran_order: bool,
+    shape: Vec<usize>,
+    data_offset: usize,
+}
+
+/// A `.npy` file or raw binary region mapped into memory.
+///
+/// `'r'` and `'c'` map the pages copy-on-write, so a write into the buffer
+/// stays private to the process and `flush` is rejected; `'r'` only differs
+/// in reporting `writable` as false. A read-only mapping would turn a stray
+/// JS write into a segfault, since an ArrayBuffer cannot be made read-only.
+/// Only `'r+'` writes reach the file.
+#[napi]
+pub struct NpyMemmap {
+    map: Arc<MmapMut>,
+    data_ptr: usize,
+    mode: MmapMode,
+    descr: String,
+    fortran_order: bool,
+    shape: Vec<usize>,
+    data_offset: usize,
+    byte_length: usize,
+}
+
+#[napi]
+impl NpyMemmap {
+    /// Map the data region of a `.npy` file, like `np.load(path, mmap_mode=mode)`.
+    #[napi(factory)]
+    pub fn open(path: String, mode: Option<String>) -> Result<Self> {
+        crate::error::guard("NpyMemmap.open", || {
+            let mode = MmapMode::parse(mode.as_deref())?;
+            let file = open_file(&path, mode)?;
+            let file_len = file_len(&file, &path)?;
+            let header = read_npy_header(&file, &path)?;
+            let itemsize = descr_itemsize(&header.descr)?;
+            let byte_length = checked_byte_length(&header.shape, itemsize)?;
+
+            if header
+                .data_offset
+                .checked_add(byte_length)
+                .map_or(true, |end| end > file_len)
+            {
+                return Err(Error::new(
+                    Status::InvalidArg,
+                    format!(
+                    "'{}' is truncated: header describes {} data bytes at offset {}, file has {}",
+                    path, byte_length, header.data_offset, file_len
+                ),
+                ));
+            }
+
+            Self::map(
+                &file,
+                &path,
+                mode,
+                header.descr,
+                header.fortran_order,
+                header.shape,
+                header.data_offset,
+                byte_length,
+            )
+        })
+    }
+
+    /// Map a headerless binary file, like `np.memmap(path, dtype, mode, offset, shape)`.
+    ///
+    /// When `shape` is omitted the mapping is 1-D and covers the rest of the file.
+    #[napi(factory)]
+    pub fn open_raw(
+        path: String,
+        dtype: String,
+        mode: Option<String>,
+        offset: Option<i64>,
+        shape: Option<Vec<i64>>,
+    ) -> Result<Self> {
+        crate::error::guard("NpyMemmap.openRaw", || {
+            let mode = MmapMode::parse(mode.as_deref())?;
+            let itemsize = descr_itemsize(&dtype)?;
+            let file = open_file(&path, mode)?;
+            let file_len = file_len(&file, &path)?;
+            let offset = usize::try_from(offset.unwrap_or(0)).map_err(|_| {
+                Error::new(
+                    Status::InvalidArg,
+                    "offset must be non-negative".to_string(),
+                )
+            })?;
+
+            if offset % itemsize != 0 {
+                return Err(Error::new(
+                    Status::InvalidArg,
+                    format!(
+                        "offset {} is not a multiple of the {}-byte dtype",
+                        offset, itemsize
+                    ),
+                ));
+            }
+
+            if offset > file_len {
+                return Err(Error::new(
+                    Status::InvalidArg,
+                    format!(
+                        "offset {} is past the end of '{}' ({} bytes)",
+                        offset, path, file_len
+                    ),
+                ));
+            }
+
+            let shape = match shape {
+                Some(shape) => shape
+                    .into_iter()
+                    .map(|dim| {
+                        usize::try_from(dim).map_err(|_| {
+                            Error::new(
+                                Status::InvalidArg,
+                                format!("negative dimension {} in shape", dim),
+                            )
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?,
+                None => {
+                    let available = file_len - offset;
+                    if available % itemsize != 0 {
+                        return Err(Error::new(
+                        Status::InvalidArg,
+                        format!(
+                            "Size of available data ({} bytes) is not a multiple of the {}-byte dtype",
+                            available, itemsize
+                        ),
+                    ));
+                    }
+                    vec![available / itemsize]
+                }
+            };
+
+            let byte_length = checked_byte_length(&shape, itemsize)?;
+            if offset
+                .checked_add(byte_length)
+                .map_or(true, |end| end > file_len)
+            {
+                return Err(Error::new(
+                    Status::InvalidArg,
+                    format!(
+                        "Mapping {} bytes at offset {} exceeds the size of '{}' ({} bytes)",
+                        byte_length, offset, path, file_len
+                    ),
+                ));
+            }
+
+            Self::map(&file, &path, mode, dtype, false, shape, offset, byte_length)
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn map(
+        file: &File,
+        path: &str,
+        mode: MmapMode,
+        descr: String,
+        fortran_order: bool,
+        shape: Vec<usize>,
+        data_offset: usize,
+        byte_length: usize,
+    ) -> Result<Self> {
+        let mut options = MmapOptions::new();
+        // Map the whole file rather than `offset..offset + len`: mmap offsets must be
+        // page aligned, and the header is small enough that the extra page is free.
+        options.len(data_offset + byte_length);
+
+        let map = unsafe {
+            match mode {
+                MmapMode::ReadWrite => options.map_mut(file),
+                MmapMode::ReadOnly | MmapMode::CopyOnWrite => options.map_copy(file),
+            }
+        }
+        .map_err(|err| {
+            Error::new(
+                Status::GenericFailure,
+                format!("Failed to memory-map '{}': {}", path, err),
+            )
+        })?;
+
+        let data_ptr = unsafe { map.as_ptr().add(data_offset) } as usize;
+
+        Ok(Self {
+            map: Arc::new(map),
+            data_ptr,
+            mode,
+            descr,
+            fortran_order,
+            shape,
+            data_offset,
+            byte_length,
+        })
+    }
+
+    #[napi(getter)]
+    pub fn dtype(&self) -> Result<String> {
+        crate::error::guard("NpyMemmap.dtype", || Ok(self.descr.clone()))
+    }
+
+    #[napi(getter)]
+    pub fn shape(&self) -> Result<Vec<i64>> {
+        crate::error::guard("NpyMemmap.shape", || {
+            Ok(self.shape.iter().map(|&dim| dim as i64).collect())
+        })
+    }
+
+    #[napi(getter)]
+    pub fn fortran_order(&self) -> Result<bool> {
+        crate::error::guard("NpyMemmap.fortranOrder", || Ok(self.fortran_order))
+    }
+
+    #[napi(getter)]
+    pub fn mode(&self) -> Result<String> {
+        crate::error::guard("NpyMemmap.mode", || Ok(self.mode.as_str().to_string()))
+    }
+
+    #[napi(getter)]
+    pub fn writable(&self) -> Result<bool> {
+        crate::error::guard("NpyMemmap.writable", || Ok(self.mode != MmapMode::ReadOnly))
+    }
+
+    #[napi(getter)]
+    pub fn offset(&self) -> Result<i64> {
+        crate::error::guard("NpyMemmap.offset", || Ok(self.data_offset as i64))
+    }
+
+    #[napi(getter)]
+    pub fn byte_length(&self) -> Result<i64> {
+        crate::error::guard("NpyMemmap.byteLength", || Ok(self.byte_length as i64))
+    }
+
+    /// An ArrayBuffer backed directly by the mapped data region.
+    ///
+    /// The buffer keeps the mapping alive after this object is collected. For
+    /// `'r'` and `'c'` writes into it never reach the file.
+    #[napi]
+    pub fn buffer(&self, env: Env) -> Result<JsArrayBuffer> {
+        crate::error::guard("NpyMemmap.buffer", || {
+            if self.byte_length == 0 {
+                return Ok(env.create_arraybuffer(0)?.into_raw());
+            }
+
+            let map = Arc::clone(&self.map);
+            let buffer = unsafe {
+                env.create_arraybuffer_with_borrowed_data(
+                    self.data_ptr as *mut u8,
+                    self.byte_length,
+                    map,
+                    |map, _env| drop(map),
+                )
+            }?;
+            Ok(buffer.into_raw())
+        })
+    }
+
+    /// Write dirty pages back to the file. Only valid for `'r+'` mappings.
+    #[napi]
+    pub fn flush(&self) -> Result<()> {
+        crate::error::guard("NpyMemmap.flush", || {
+            if self.mode != MmapMode::ReadWrite {
+                return Err(Error::new(
+                    Status::InvalidArg,
+                    format!(
+                        "Cannot flush a memmap opened with mode '{}'; use 'r+' to write through",
+                        self.mode.as_str()
+                    ),
+                ));
+            }
+
+            self.map
+                .flush_range(self.data_offset, self.byte_length)
+                .map_err(|err| {
+                    Error::new(
+                        Status::GenericFailure,
+                        format!("Failed to flush memmap: {}", err),
+                    )
+                })
+        })
+    }
+}
+
+fn open_file(path: &str, mode: MmapMode) -> Result<File> {
+    OpenOptions::new()
+        .read(true)
+        .write(mode == MmapMode::ReadWrite)
+        .open(path)
+        .map_err(|err| {
+            Error::new(
+                Status::GenericFailure,
+                format!("Failed to open '{}': {}", path, err),
+            )
+        })
+}
+
+fn file_len(file: &File, path: &str) -> Result<usize> {
+    let len = file
+        .metadata()
+        .map_err(|err| {
+            Error::new(
+                Status::GenericFailure,
+                format!("Failed to stat '{}': {}", path, err),
+            )
+        })?
+        .len();
+    usize::try_from(len).map_err(|_| {
+        Error::new(
+            Status::InvalidArg,
+            format!("'{}' is too large to map on this platform", path),
+        )
+    })
+}
+
+fn checked_byte_length(shape: &[usize], itemsize: usize) -> Result<usize> {
+    shape
+        .iter()
+        .try_fold(itemsize, |acc, &dim| acc.checked_mul(dim))
+        .ok_or_else(|| Error::new(Status::InvalidArg, "Array size overflows usize".to_string()))
+}
+
+fn read_npy_header(file: &File, path: &str) -> Result<NpyHeader> {
+    use std::io::Read;
+
+    let invalid =
+        |message: String| Error::new(Status::InvalidArg, format!("'{}': {}", path, message));
+    let mut reader = file;
+    let mut preamble = [0u8; 8];
+    reader
+        .read_exact(&mut preamble)
+        .map_err(|_| invalid("file is too short to be a .npy file".to_string()))?;
+
+    if &preamble[..6] != NPY_MAGIC {
+        return Err(invalid("missing .npy magic string".to_string()));
+    }
+
+    let major = preamble[6];
+    let header_len_bytes = match major {
+        1 => 2,
+        2 | 3 => 4,
+        _ => {
+            return Err(invalid(format!(
+                "unsupported .npy format version {}.{}",
+                major, preamble[7]
+            )))
+        }
+    };
+
+    let mut len_bytes = [0u8; 4];
+    reader
+        .read_exact(&mut len_bytes[..header_len_bytes])
+        .map_err(|_| invalid("truncated .npy header".to_string()))?;
+    let header_len = u32::from_le_bytes(len_bytes) as usize;
+
+    let mut header = vec![0u8; header_len];
+    reader
+        .read_exact(&mut header)
+        .map_err(|_| invalid("truncated .npy header".to_string()))?;
+    let header =
+        String::from_utf8(header).map_err(|_| invalid("header is not valid UTF-8".to_string()))?;
+
+    let descr = header_value(&header, "descr")
+        .and_then(|value| value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')))
+        .ok_or_else(|| {
+            invalid("header has no 'descr' string (structured dtypes are unsupported)".to_string())
+        })?
+        .to_string();
+
+    let fortran_order = match header_value(&header, "fortran_order") {
+        Some("True") => true,
+        Some("False") => false,
+        _ => return Err(invalid("header has no 'fortran_order' flag".to_string())),
+    };
+
+    let shape = header_value(&header, "shape")
+        .and_then(|value| value.strip_prefix('(').and_then(|v| v.strip_suffix(')')))
+        .ok_or_else(|| invalid("header has no 'shape' tuple".to_string()))?
+        .split(',')
+        .map(str::trim)
+        .filter(|dim| !dim.is_empty())
+        .map(|dim| {
+            dim.parse::<usize>()
+                .map_err(|_| invalid(format!("invalid dimension '{}'", dim)))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(NpyHeader {
+        descr,
+        fortran_order,
+        shape,
+        data_offset: 6 + 2 + header_len_bytes + header_len,
+    })
+}
+
+/// Extract the raw literal for `key` from the Python dict repr NumPy writes.
+fn header_value<'a>(header: &'a str, key: &str) -> Option<&'a str> {
+    let quoted = format!("'{}'", key);
+    let start = header.find(&quoted)? + quoted.len();
+    let rest = header[start..].trim_start().strip_prefix(':')?.trim_start();
+
+    let end = match rest.chars().next()? {
+        '\'' => rest[1..].find('\'')? + 2,
+        '(' => rest.find(')')? + 1,
+        _ => rest.find([',', '}']).unwrap_or(rest.len()),
+    };
+    Some(rest[..end].trim())
+}
+
+fn descr_itemsize(descr: &str) -> Result<usize> {
+    let (byte_order, code) = match descr.as_bytes().first() {
+        Some(b'<' | b'>' | b'|' | b'=') => descr.split_at(1),
+        _ => ("=", descr),
+    };
+
+    if byte_order == ">" && !matches!(code, "b1" | "i1" | "u1") {
+        return Err(to_napi(NumpyError::dtype(
+            format!(
+                "Big-endian dtype '{}' cannot be mapped into a JS typed array",
+                descr
+            ),
+            descr,
+        )));
+    }
+
+    match code {
+        "b1" | "i1" | "u1" => Ok(1),
+        "i2" | "u2" | "f2" => Ok(2),
+        "i4" | "u4" | "f4" => Ok(4),
+        "i8" | "u8" | "f8" | "c8" => Ok(8),
+        "c16" => Ok(16),
+        _ => Err(to_napi(NumpyError::dtype(
+            format!("Unsupported dtype '{}' for memory mapping", descr),
+            descr,
+        ))),
+    }
+}
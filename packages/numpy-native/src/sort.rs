@@ -0,0 +1,33 @@
+use napi::bindgen_prelude::{AsyncTask, Float64Array};
+use napi::Result;
+use napi_derive::napi;
+use sylphx_numpy_core::sort;
+
+use crate::buffer::OutputF64;
+use crate::error::to_napi;
+use crate::task::{Cancel, CancellationToken, NativeTask};
+
+/// Sorted copy of `a` in NumPy order: ascending with NaN last.
+#[napi]
+pub fn sort_f64(a: &[f64]) -> Result<Float64Array> {
+    crate::error::guard("sortF64", || sort(a, &Cancel::none()))
+}
+
+#[napi]
+pub fn sort_f64_async(
+    a: Float64Array,
+    token: Option<&CancellationToken>,
+) -> Result<AsyncTask<NativeTask<Float64Array>>> {
+    crate::error::guard("sortF64Async", || {
+        let cancel = Cancel::from_token(token);
+        Ok(AsyncTask::new(NativeTask::new("sortF64Async", move || {
+            sort(&a, &cancel)
+        })))
+    })
+}
+
+fn sort(a: &[f64], cancel: &Cancel) -> Result<Float64Array> {
+    let mut output = OutputF64::from_iter_exact(a.len(), a.iter().copied())?;
+    sort::sort_in_place(output.as_mut_slice(), cancel).map_err(to_napi)?;
+    Ok(output.into_float64_array())
+}
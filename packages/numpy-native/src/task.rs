@@ -0,0 +1,157 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+#[cfg(feature = "test-hooks")]
+use napi::bindgen_prelude::AsyncTask;
+use napi::bindgen_prelude::{ToNapiValue, TypeName};
+use napi::{Env, Error, JsFunction, JsObject, Result, Status, Task};
+use napi_derive::napi;
+use sylphx_numpy_core::Interrupt;
+
+use crate::error::to_napi;
+
+/// A cooperative cancellation flag shared with kernels running off the main
+/// thread. Kernels poll it between blocks of work, so cancelling stops a job
+/// within one block rather than immediately.
+#[napi]
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    flag: Arc<AtomicBool>,
+}
+
+#[napi]
+impl CancellationToken {
+    #[napi(constructor)]
+    pub fn new() -> Result<Self> {
+        crate::error::guard("CancellationToken.new", || Ok(Self::default()))
+    }
+
+    /// A token that is cancelled when `signal` fires its `abort` event.
+    #[napi(factory)]
+    pub fn from_abort_signal(env: Env, signal: JsObject) -> Result<Self> {
+        crate::error::guard("CancellationToken.fromAbortSignal", || {
+            let token = Self::default();
+            if signal.get_named_property::<bool>("aborted")? {
+                token.flag.store(true, Ordering::Release);
+                return Ok(token);
+            }
+
+            let flag = Arc::clone(&token.flag);
+            let listener = env.create_function_from_closure("onabort", move |ctx| {
+                flag.store(true, Ordering::Release);
+                ctx.env.get_undefined()
+            })?;
+            let add_event_listener: JsFunction = signal.get_named_property("addEventListener")?;
+            add_event_listener.call(
+                Some(&signal),
+                &[
+                    env.create_string("abort")?.into_unknown(),
+                    listener.into_unknown(),
+                ],
+            )?;
+            Ok(token)
+        })
+    }
+
+    #[napi]
+    pub fn cancel(&self) -> Result<()> {
+        crate::error::guard("CancellationToken.cancel", || {
+            self.flag.store(true, Ordering::Release);
+            Ok(())
+        })
+    }
+
+    #[napi(getter)]
+    pub fn cancelled(&self) -> Result<bool> {
+        crate::error::guard("CancellationToken.cancelled", || {
+            Ok(self.flag.load(Ordering::Acquire))
+        })
+    }
+}
+
+/// The kernel-side view of an optional `CancellationToken`.
+#[derive(Clone, Default)]
+pub struct Cancel(Option<Arc<AtomicBool>>);
+
+impl Cancel {
+    pub fn none() -> Self {
+        Self(None)
+    }
+
+    pub fn from_token(token: Option<&CancellationToken>) -> Self {
+        Self(token.map(|token| Arc::clone(&token.flag)))
+    }
+
+    /// Fail with an `AbortError`-style message once the token is cancelled.
+    pub fn check(&self) -> Result<()> {
+        Interrupt::check(self).map_err(to_napi)
+    }
+}
+
+impl Interrupt for Cancel {
+    fn is_interrupted(&self) -> bool {
+        self.0
+            .as_ref()
+            .is_some_and(|flag| flag.load(Ordering::Acquire))
+    }
+}
+
+type Work<T> = Box<dyn FnOnce() -> Result<T> + Send>;
+
+/// Runs one kernel invocation on the libuv thread pool and resolves to its output.
+/// `name` is the exported JS function, reported if the kernel panics.
+pub struct NativeTask<T> {
+    name: &'static str,
+    work: Option<Work<T>>,
+}
+
+impl<T> NativeTask<T> {
+    pub fn new(name: &'static str, work: impl FnOnce() -> Result<T> + Send + 'static) -> Self {
+        Self {
+            name,
+            work: Some(Box::new(work)),
+        }
+    }
+}
+
+impl<T> Task for NativeTask<T>
+where
+    T: ToNapiValue + TypeName + Send + 'static,
+{
+    type Output = T;
+    type JsValue = T;
+
+    fn compute(&mut self) -> Result<T> {
+        let work = self.work.take().ok_or_else(|| {
+            Error::new(
+                Status::GenericFailure,
+                "Native task was already run".to_string(),
+            )
+        })?;
+        crate::error::guard(self.name, work)
+    }
+
+    fn resolve(&mut self, _env: Env, output: T) -> Result<T> {
+        Ok(output)
+    }
+}
+
+/// Test hook: panics with `message` so the JS suite can check that a Rust
+/// panic surfaces as an exception rather than killing the process.
+#[cfg(feature = "test-hooks")]
+#[napi(js_name = "__triggerPanic")]
+pub fn trigger_panic(message: String) -> Result<()> {
+    crate::error::guard("__triggerPanic", || panic!("{}", message))
+}
+
+/// Test hook: the same panic raised on the libuv thread pool.
+#[cfg(feature = "test-hooks")]
+#[napi(js_name = "__triggerPanicAsync")]
+pub fn trigger_panic_async(message: String) -> Result<AsyncTask<NativeTask<()>>> {
+    crate::error::guard("__triggerPanicAsync", || {
+        Ok(AsyncTask::new(NativeTask::new(
+            "__triggerPanicAsync",
+            move || panic!("{}", message),
+        )))
+    })
+}
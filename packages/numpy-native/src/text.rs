@@ -0,0 +1,731 @@
+use std::fmt::Write as _;
+use std::fs;
+
+use napi::bindgen_prelude::{
+    AsyncTask, BigInt64Array, Either4, Float32Array, Float64Array, Int32Array,
+};
+use napi::{Error, Result, Status};
+use napi_derive::napi;
+use sylphx_numpy_core::NumpyError;
+
+use crate::error::to_napi;
+
+use crate::task::{Cancel, CancellationToken, NativeTask};
+
+/// Lines parsed between cancellation checks.
+const LINES_PER_CHECK: usize = 4096;
+
+#[napi(object)]
+pub struct LoadtxtOptions {
+    /// Field separator. Defaults to any run of whitespace.
+    pub delimiter: Option<String>,
+    /// Number of leading lines to skip, including comment lines.
+    pub skiprows: Option<u32>,
+    /// Columns to read; negative indices count from the end.
+    pub usecols: Option<Vec<i32>>,
+    /// Prefixes that start a comment. Defaults to `['#']`.
+    pub comments: Option<Vec<String>>,
+    /// One of `float64` (default), `float32`, `int32` or `int64`.
+    pub dtype: Option<String>,
+    /// Minimum number of dimensions of the result, as in NumPy. Defaults to 0.
+    pub ndmin: Option<u32>,
+}
+
+#[napi(object)]
+pub struct GenfromtxtOptions {
+    pub delimiter: Option<String>,
+    pub skip_header: Option<u32>,
+    pub usecols: Option<Vec<i32>>,
+    pub comments: Option<Vec<String>>,
+    /// Strings treated as missing in addition to empty fields.
+    pub missing_values: Option<Vec<String>>,
+    /// Value substituted for missing fields. Defaults to NaN.
+    pub filling_values: Option<f64>,
+}
+
+#[napi(object)]
+pub struct SavetxtOptions {
+    /// printf-style format: one spec for every column, one spec per column,
+    /// or a whole-row format. Defaults to `%.18e`.
+    pub fmt: Option<Vec<String>>,
+    pub delimiter: Option<String>,
+    pub newline: Option<String>,
+    pub header: Option<String>,
+    pub footer: Option<String>,
+    pub comments: Option<String>,
+}
+
+#[napi(object, object_from_js = false)]
+pub struct TextArray {
+    pub data: Either4<Float64Array, Float32Array, Int32Array, BigInt64Array>,
+    pub shape: Vec<u32>,
+}
+
+#[derive(Clone, Copy)]
+enum TextDtype {
+    Float64,
+    Float32,
+    Int32,
+    Int64,
+}
+
+impl TextDtype {
+    fn parse(dtype: Option<&str>) -> Result<Self> {
+        match dtype.unwrap_or("float64") {
+            "float64" | "f8" | "<f8" => Ok(Self::Float64),
+            "float32" | "f4" | "<f4" => Ok(Self::Float32),
+            "int32" | "i4" | "<i4" => Ok(Self::Int32),
+            "int64" | "i8" | "<i8" => Ok(Self::Int64),
+            other => Err(to_napi(NumpyError::dtype(
+                format!("Unsupported loadtxt dtype '{}'", other),
+                other,
+            ))),
+        }
+    }
+}
+
+/// Parsed text grid before dtype conversion.
+struct Table<T> {
+    values: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+/// Load numeric text data, like `np.loadtxt`.
+#[napi]
+pub fn loadtxt(path: String, options: Option<LoadtxtOptions>) -> Result<TextArray> {
+    crate::error::guard("loadtxt", || load(&path, options, &Cancel::none()))
+}
+
+#[napi]
+pub fn loadtxt_async(
+    path: String,
+    options: Option<LoadtxtOptions>,
+    token: Option<&CancellationToken>,
+) -> Result<AsyncTask<NativeTask<TextArray>>> {
+    crate::error::guard("loadtxtAsync", || {
+        let cancel = Cancel::from_token(token);
+        Ok(AsyncTask::new(NativeTask::new("loadtxtAsync", move || {
+            load(&path, options, &cancel)
+        })))
+    })
+}
+
+fn load(path: &str, options: Option<LoadtxtOptions>, cancel: &Cancel) -> Result<TextArray> {
+    let options = options.unwrap_or(LoadtxtOptions {
+        delimiter: None,
+        skiprows: None,
+        usecols: None,
+        comments: None,
+        dtype: None,
+        ndmin: None,
+    });
+    let dtype = TextDtype::parse(options.dtype.as_deref())?;
+    let ndmin = options.ndmin.unwrap_or(0);
+    if ndmin > 2 {
+        return Err(Error::new(
+            Status::InvalidArg,
+            format!("Illegal value of ndmin keyword: {}", ndmin),
+        ));
+    }
+
+    let text = read_text(path)?;
+    let comments = comment_prefixes(options.comments);
+    let lines = data_lines(&text, options.skiprows.unwrap_or(0) as usize, &comments);
+    let delimiter = options.delimiter.as_deref();
+    let usecols = options.usecols.as_deref();
+
+    let (data, rows, cols) = match dtype {
+        TextDtype::Float64 => {
+            let table = parse_table(&lines, delimiter, usecols, cancel, parse_float)?;
+            (Either4::A(table.values.into()), table.rows, table.cols)
+        }
+        TextDtype::Float32 => {
+            let table = parse_table(&lines, delimiter, usecols, cancel, |field| {
+                field.parse::<f32>().map_err(|_| ())
+            })?;
+            (Either4::B(table.values.into()), table.rows, table.cols)
+        }
+        TextDtype::Int32 => {
+            let table = parse_table(&lines, delimiter, usecols, cancel, |field| {
+                field.parse::<i32>().map_err(|_| ())
+            })?;
+            (Either4::C(table.values.into()), table.rows, table.cols)
+        }
+        TextDtype::Int64 => {
+            let table = parse_table(&lines, delimiter, usecols, cancel, |field| {
+                field.parse::<i64>().map_err(|_| ())
+            })?;
+            (Either4::D(table.values.into()), table.rows, table.cols)
+        }
+    };
+
+    Ok(TextArray {
+        data,
+        shape: squeeze_shape(rows, cols, ndmin),
+    })
+}
+
+/// Load float text data with missing-value handling, like `np.genfromtxt`.
+///
+/// Empty fields and `missing_values` become `filling_values` (NaN by default);
+/// fields that do not parse as numbers become NaN.
+#[napi]
+pub fn genfromtxt(path: String, options: Option<GenfromtxtOptions>) -> Result<TextArray> {
+    crate::error::guard("genfromtxt", || generate(&path, options, &Cancel::none()))
+}
+
+#[napi]
+pub fn genfromtxt_async(
+    path: String,
+    options: Option<GenfromtxtOptions>,
+    token: Option<&CancellationToken>,
+) -> Result<AsyncTask<NativeTask<TextArray>>> {
+    crate::error::guard("genfromtxtAsync", || {
+        let cancel = Cancel::from_token(token);
+        Ok(AsyncTask::new(NativeTask::new(
+            "genfromtxtAsync",
+            move || generate(&path, options, &cancel),
+        )))
+    })
+}
+
+fn generate(path: &str, options: Option<GenfromtxtOptions>, cancel: &Cancel) -> Result<TextArray> {
+    let options = options.unwrap_or(GenfromtxtOptions {
+        delimiter: None,
+        skip_header: None,
+        usecols: None,
+        comments: None,
+        missing_values: None,
+        filling_values: None,
+    });
+    let text = read_text(path)?;
+    let comments = comment_prefixes(options.comments);
+    let lines = data_lines(&text, options.skip_header.unwrap_or(0) as usize, &comments);
+    let missing = options.missing_values.unwrap_or_default();
+    let fill = options.filling_values.unwrap_or(f64::NAN);
+
+    let table = parse_table(
+        &lines,
+        options.delimiter.as_deref(),
+        options.usecols.as_deref(),
+        cancel,
+        |field| {
+            if field.is_empty() || missing.iter().any(|value| value == field) {
+                Ok(fill)
+            } else {
+                Ok(parse_float(field).unwrap_or(f64::NAN))
+            }
+        },
+    )?;
+
+    Ok(TextArray {
+        shape: squeeze_shape(table.rows, table.cols, 0),
+        data: Either4::A(table.values.into()),
+    })
+}
+
+/// Write a 1-D or 2-D float64 array as text, like `np.savetxt`.
+#[napi]
+pub fn savetxt(
+    path: String,
+    data: &[f64],
+    shape: Vec<u32>,
+    options: Option<SavetxtOptions>,
+) -> Result<()> {
+    crate::error::guard("savetxt", || {
+        let options = options.unwrap_or(SavetxtOptions {
+            fmt: None,
+            delimiter: None,
+            newline: None,
+            header: None,
+            footer: None,
+            comments: None,
+        });
+        let (rows, cols) = match shape.as_slice() {
+            [len] => (*len as usize, 1),
+            [rows, cols] => (*rows as usize, *cols as usize),
+            _ => {
+                return Err(Error::new(
+                    Status::InvalidArg,
+                    format!(
+                        "Expected 1D or 2D array, got {}D array instead",
+                        shape.len()
+                    ),
+                ))
+            }
+        };
+
+        if rows * cols != data.len() {
+            return Err(Error::new(
+                Status::InvalidArg,
+                format!("Expected input length {}, got {}", rows * cols, data.len()),
+            ));
+        }
+
+        let delimiter = options.delimiter.as_deref().unwrap_or(" ");
+        let newline = options.newline.as_deref().unwrap_or("\n");
+        let comments = options.comments.as_deref().unwrap_or("# ");
+        let row_format = row_format(options.fmt, cols, delimiter)?;
+
+        let mut output = String::with_capacity(data.len() * 26);
+        if let Some(header) = options.header.as_deref().filter(|h| !h.is_empty()) {
+            write_commented(&mut output, header, comments, newline);
+        }
+
+        // Index rows rather than chunk `data`, so zero columns still give
+        // one empty line per row as in NumPy.
+        for row in 0..rows {
+            let mut values = data[row * cols..(row + 1) * cols].iter();
+            for piece in &row_format {
+                match piece {
+                    FormatPiece::Literal(text) => output.push_str(text),
+                    FormatPiece::Spec(spec) => {
+                        spec.write(&mut output, *values.next().unwrap_or(&f64::NAN))
+                    }
+                }
+            }
+            output.push_str(newline);
+        }
+
+        if let Some(footer) = options.footer.as_deref().filter(|f| !f.is_empty()) {
+            write_commented(&mut output, footer, comments, newline);
+        }
+
+        fs::write(&path, output).map_err(|err| {
+            Error::new(
+                Status::GenericFailure,
+                format!("Failed to write '{}': {}", path, err),
+            )
+        })
+    })
+}
+
+fn read_text(path: &str) -> Result<String> {
+    fs::read_to_string(path).map_err(|err| {
+        Error::new(
+            Status::GenericFailure,
+            format!("Failed to read '{}': {}", path, err),
+        )
+    })
+}
+
+fn comment_prefixes(comments: Option<Vec<String>>) -> Vec<String> {
+    comments
+        .unwrap_or_else(|| vec!["#".to_string()])
+        .into_iter()
+        .filter(|prefix| !prefix.is_empty())
+        .collect()
+}
+
+/// Non-empty lines after `skiprows`, with comments stripped, paired with their
+/// 1-based line number for error messages.
+fn data_lines<'a>(text: &'a str, skiprows: usize, comments: &[String]) -> Vec<(usize, &'a str)> {
+    text.lines()
+        .enumerate()
+        .skip(skiprows)
+        .filter_map(|(index, line)| {
+            let line = comments
+                .iter()
+                .filter_map(|prefix| line.find(prefix.as_str()))
+                .min()
+                .map_or(line, |end| &line[..end]);
+            let line = line.trim_end_matches('\r');
+            (!line.trim().is_empty()).then_some((index + 1, line))
+        })
+        .collect()
+}
+
+fn parse_table<T>(
+    lines: &[(usize, &str)],
+    delimiter: Option<&str>,
+    usecols: Option<&[i32]>,
+    cancel: &Cancel,
+    mut parse: impl FnMut(&str) -> std::result::Result<T, ()>,
+) -> Result<Table<T>> {
+    let mut values = Vec::new();
+    let mut fields = Vec::new();
+    let mut cols = None;
+
+    for (row, &(line_number, line)) in lines.iter().enumerate() {
+        if row % LINES_PER_CHECK == 0 {
+            cancel.check()?;
+        }
+        fields.clear();
+        match delimiter {
+            Some(delimiter) => fields.extend(line.split(delimiter).map(str::trim)),
+            None => fields.extend(line.split_whitespace()),
+        }
+
+        let row_cols = match usecols {
+            Some(usecols) => {
+                for &col in usecols {
+                    let index = resolve_column(col, fields.len(), line_number)?;
+                    values.push(parse_field(&mut parse, fields[index], line_number, index)?);
+                }
+                usecols.len()
+            }
+            None => {
+                for (index, field) in fields.iter().enumerate() {
+                    values.push(parse_field(&mut parse, field, line_number, index)?);
+                }
+                fields.len()
+            }
+        };
+
+        match cols {
+            None => cols = Some(row_cols),
+            Some(expected) if expected != row_cols => {
+                return Err(Error::new(
+                    Status::InvalidArg,
+                    format!(
+                        "the number of columns changed from {} to {} at row {}; use `usecols` to select a subset and avoid this error",
+                        expected, row_cols, line_number
+                    ),
+                ))
+            }
+            Some(_) => {}
+        }
+    }
+
+    Ok(Table {
+        values,
+        rows: lines.len(),
+        cols: cols.unwrap_or(0),
+    })
+}
+
+fn resolve_column(col: i32, len: usize, line_number: usize) -> Result<usize> {
+    let index = if col < 0 {
+        len as i64 + col as i64
+    } else {
+        col as i64
+    };
+    if index < 0 || index >= len as i64 {
+        return Err(Error::new(
+            Status::InvalidArg,
+            format!(
+                "invalid column index {} at row {} with {} columns",
+                col, line_number, len
+            ),
+        ));
+    }
+    Ok(index as usize)
+}
+
+fn parse_field<T>(
+    parse: &mut impl FnMut(&str) -> std::result::Result<T, ()>,
+    field: &str,
+    line_number: usize,
+    column: usize,
+) -> Result<T> {
+    parse(field).map_err(|_| {
+        Error::new(
+            Status::InvalidArg,
+            format!(
+                "could not convert string '{}' to number at row {}, column {}",
+                field,
+                line_number,
+                column + 1
+            ),
+        )
+    })
+}
+
+/// Correctly rounded decimal-to-binary conversion, accepting NumPy's
+/// spellings of the special values.
+fn parse_float(field: &str) -> std::result::Result<f64, ()> {
+    field.parse::<f64>().or_else(|_| {
+        let (negative, body) = match field.as_bytes().first() {
+            Some(b'-') => (true, &field[1..]),
+            Some(b'+') => (false, &field[1..]),
+            _ => (false, field),
+        };
+        let value = match body.to_ascii_lowercase().as_str() {
+            "nan" | "nan(ind)" => f64::NAN,
+            "inf" | "infinity" => f64::INFINITY,
+            _ => return Err(()),
+        };
+        Ok(if negative { -value } else { value })
+    })
+}
+
+fn squeeze_shape(rows: usize, cols: usize, ndmin: u32) -> Vec<u32> {
+    let (rows, cols) = (rows as u32, cols as u32);
+    match ndmin {
+        2 => vec![rows, cols],
+        _ if rows == 1 && cols == 1 && ndmin == 0 => vec![],
+        _ if rows == 1 => vec![cols],
+        _ if cols == 1 => vec![rows],
+        _ if rows == 0 => vec![0],
+        _ => vec![rows, cols],
+    }
+}
+
+fn write_commented(output: &mut String, text: &str, comments: &str, newline: &str) {
+    for line in text.split('\n') {
+        output.push_str(comments);
+        output.push_str(line);
+        output.push_str(newline);
+    }
+}
+
+enum FormatPiece {
+    Literal(String),
+    Spec(FormatSpec),
+}
+
+fn row_format(fmt: Option<Vec<String>>, cols: usize, delimiter: &str) -> Result<Vec<FormatPiece>> {
+    let fmt = fmt.unwrap_or_else(|| vec!["%.18e".to_string()]);
+
+    let pieces = match fmt.as_slice() {
+        [single] => {
+            let pieces = parse_format(single)?;
+            let specs = pieces
+                .iter()
+                .filter(|piece| matches!(piece, FormatPiece::Spec(_)))
+                .count();
+            if specs == 1 {
+                let mut row = Vec::new();
+                for col in 0..cols {
+                    if col > 0 {
+                        row.push(FormatPiece::Literal(delimiter.to_string()));
+                    }
+                    row.extend(parse_format(single)?);
+                }
+                row
+            } else if specs == cols {
+                pieces
+            } else {
+                return Err(Error::new(
+                    Status::InvalidArg,
+                    format!("fmt has wrong number of % formats:  {}", single),
+                ));
+            }
+        }
+        many if many.len() == cols => {
+            let mut row = Vec::new();
+            for (col, spec) in many.iter().enumerate() {
+                if col > 0 {
+                    row.push(FormatPiece::Literal(delimiter.to_string()));
+                }
+                row.extend(parse_format(spec)?);
+            }
+            row
+        }
+        many => {
+            return Err(Error::new(
+                Status::InvalidArg,
+                format!(
+                    "fmt has wrong shape.  {} formats for {} columns",
+                    many.len(),
+                    cols
+                ),
+            ))
+        }
+    };
+
+    Ok(pieces)
+}
+
+fn parse_format(fmt: &str) -> Result<Vec<FormatPiece>> {
+    let invalid = || Error::new(Status::InvalidArg, format!("invalid fmt: {}", fmt));
+    let mut pieces = Vec::new();
+    let mut literal = String::new();
+    let mut chars = fmt.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if ch != '%' {
+            literal.push(ch);
+            continue;
+        }
+        if chars.peek() == Some(&'%') {
+            chars.next();
+            literal.push('%');
+            continue;
+        }
+
+        let mut spec = FormatSpec::default();
+        while let Some(&flag) = chars.peek() {
+            match flag {
+                '-' => spec.left_align = true,
+                '+' => spec.plus_sign = true,
+                ' ' => spec.space_sign = true,
+                '0' => spec.zero_pad = true,
+                '#' => spec.alternate = true,
+                _ => break,
+            }
+            chars.next();
+        }
+        while let Some(digit) = chars.peek().and_then(|c| c.to_digit(10)) {
+            spec.width = spec.width * 10 + digit as usize;
+            chars.next();
+        }
+        if chars.peek() == Some(&'.') {
+            chars.next();
+            let mut precision = 0;
+            while let Some(digit) = chars.peek().and_then(|c| c.to_digit(10)) {
+                precision = precision * 10 + digit as usize;
+                chars.next();
+            }
+            spec.precision = Some(precision);
+        }
+        spec.conversion = match chars.next().ok_or_else(invalid)? {
+            conversion @ ('e' | 'E' | 'f' | 'F' | 'g' | 'G' | 'd' | 'i' | 's') => conversion,
+            _ => return Err(invalid()),
+        };
+
+        if !literal.is_empty() {
+            pieces.push(FormatPiece::Literal(std::mem::take(&mut literal)));
+        }
+        pieces.push(FormatPiece::Spec(spec));
+    }
+
+    if !literal.is_empty() {
+        pieces.push(FormatPiece::Literal(literal));
+    }
+    Ok(pieces)
+}
+
+/// A single C printf conversion, as Python's `%` operator applies it.
+#[derive(Default)]
+struct FormatSpec {
+    left_align: bool,
+    plus_sign: bool,
+    space_sign: bool,
+    zero_pad: bool,
+    alternate: bool,
+    width: usize,
+    precision: Option<usize>,
+    conversion: char,
+}
+
+impl FormatSpec {
+    fn write(&self, output: &mut String, value: f64) {
+        let body = if !value.is_finite() {
+            let text = if value.is_nan() { "nan" } else { "inf" };
+            if self.conversion.is_ascii_uppercase() {
+                text.to_ascii_uppercase()
+            } else {
+                text.to_string()
+            }
+        } else {
+            match self.conversion {
+                'e' | 'E' => {
+                    format_exponent(value.abs(), self.precision.unwrap_or(6), self.conversion)
+                }
+                'f' | 'F' => format!("{:.*}", self.precision.unwrap_or(6), value.abs()),
+                'g' | 'G' => format_general(
+                    value.abs(),
+                    self.precision.unwrap_or(6),
+                    self.alternate,
+                    self.conversion,
+                ),
+                'd' | 'i' => format!("{}", value.abs().trunc()),
+                _ => format_repr(value.abs()),
+            }
+        };
+
+        let negative = match self.conversion {
+            'd' | 'i' => value.trunc() < 0.0,
+            _ => value.is_sign_negative() && !value.is_nan(),
+        };
+        let sign = if negative {
+            "-"
+        } else if self.plus_sign {
+            "+"
+        } else if self.space_sign {
+            " "
+        } else {
+            ""
+        };
+
+        let len = sign.len() + body.len();
+        let padding = self.width.saturating_sub(len);
+        if self.left_align {
+            output.push_str(sign);
+            output.push_str(&body);
+            output.extend(std::iter::repeat(' ').take(padding));
+        } else if self.zero_pad && value.is_finite() {
+            output.push_str(sign);
+            output.extend(std::iter::repeat('0').take(padding));
+            output.push_str(&body);
+        } else {
+            output.extend(std::iter::repeat(' ').take(padding));
+            output.push_str(sign);
+            output.push_str(&body);
+        }
+    }
+}
+
+/// `%e` with C's exponent spelling: a sign and at least two digits.
+fn format_exponent(value: f64, precision: usize, conversion: char) -> String {
+    let formatted = format!("{:.*e}", precision, value);
+    let (mantissa, exponent) = formatted.split_once('e').unwrap_or((&formatted, "0"));
+    let exponent: i32 = exponent.parse().unwrap_or(0);
+    let mut result = String::with_capacity(mantissa.len() + 5);
+    result.push_str(mantissa);
+    result.push(conversion);
+    result.push(if exponent < 0 { '-' } else { '+' });
+    let _ = write!(result, "{:02}", exponent.abs());
+    result
+}
+
+fn format_general(value: f64, precision: usize, alternate: bool, conversion: char) -> String {
+    let precision = precision.max(1);
+    if value == 0.0 {
+        return if alternate {
+            format!("{:.*}", precision - 1, 0.0)
+        } else {
+            "0".to_string()
+        };
+    }
+
+    // The exponent after rounding to `precision` significant digits decides the style.
+    let exponent_form = format_exponent(value, precision - 1, 'e');
+    let exponent: i32 = exponent_form
+        .rsplit_once('e')
+        .and_then(|(_, exp)| exp.parse().ok())
+        .unwrap_or(0);
+
+    let mut text = if exponent >= -4 && exponent < precision as i32 {
+        format!("{:.*}", (precision as i32 - 1 - exponent) as usize, value)
+    } else {
+        format_exponent(
+            value,
+            precision - 1,
+            if conversion == 'G' { 'E' } else { 'e' },
+        )
+    };
+
+    if !alternate {
+        let (mantissa, suffix) = match text.find(['e', 'E']) {
+            Some(index) => text.split_at(index),
+            None => (text.as_str(), ""),
+        };
+        if mantissa.contains('.') {
+            let trimmed = mantissa.trim_end_matches('0').trim_end_matches('.');
+            text = format!("{}{}", trimmed, suffix);
+        }
+    }
+    text
+}
+
+/// Python's `str(float)`: the shortest repr that round-trips.
+fn format_repr(value: f64) -> String {
+    if value == 0.0 || (1e-4..1e16).contains(&value) {
+        return format!("{:?}", value);
+    }
+
+    let text = (1..=17)
+        .map(|digits| format_exponent(value, digits - 1, 'e'))
+        .find(|text| text.parse::<f64>().ok() == Some(value))
+        .unwrap_or_else(|| format_exponent(value, 16, 'e'));
+    let (mantissa, exponent) = text.split_once('e').unwrap_or((&text, ""));
+    let mantissa = if mantissa.contains('.') {
+        mantissa.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        mantissa
+    };
+    format!("{}e{}", mantissa, exponent)
+}
@@ -0,0 +1,55 @@
+use napi::bindgen_prelude::{Float32Array, Float64Array};
+use napi::Result;
+use napi_derive::napi;
+use sylphx_numpy_core::ufunc::Ufunc;
+use sylphx_numpy_core::{Float, Never};
+
+use crate::array::resolve_shape;
+use crate::error::to_napi;
+
+/// `np.<op>.at(target, indices, values)`, updating `target` in place.
+///
+/// `op` names a binary ufunc such as `add`, `subtract`, `multiply`,
+/// `maximum` or `minimum`. `indices` is a tuple of index arrays for the
+/// leading axes of `target`, a C-order array of `shape` (1-D by default).
+/// Unlike `target[indices] += values`, a repeated index is applied every time.
+#[napi]
+pub fn ufunc_at_f64(
+    op: String,
+    mut target: Float64Array,
+    indices: Vec<Vec<i64>>,
+    values: &[f64],
+    shape: Option<Vec<i64>>,
+) -> Result<()> {
+    crate::error::guard("ufuncAtF64", || {
+        at(&op, &mut target, &indices, values, shape)
+    })
+}
+
+/// `ufuncAtF64` over float32 data.
+#[napi]
+pub fn ufunc_at_f32(
+    op: String,
+    mut target: Float32Array,
+    indices: Vec<Vec<i64>>,
+    values: &[f32],
+    shape: Option<Vec<i64>>,
+) -> Result<()> {
+    crate::error::guard("ufuncAtF32", || {
+        at(&op, &mut target, &indices, values, shape)
+    })
+}
+
+fn at<T: Float>(
+    op: &str,
+    target: &mut [T],
+    indices: &[Vec<i64>],
+    values: &[T],
+    shape: Option<Vec<i64>>,
+) -> Result<()> {
+    let shape = resolve_shape(shape, target.len())?;
+    let indices: Vec<&[i64]> = indices.iter().map(Vec::as_slice).collect();
+    Ufunc::parse(op)
+        .and_then(|ufunc| ufunc.at(target, &shape, &indices, values, &Never))
+        .map_err(to_napi)
+}
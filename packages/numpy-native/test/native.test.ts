@@ -1,5 +1,10 @@
 import { expect, test } from 'bun:test'
+import { mkdtempSync, readFileSync, writeFileSync } from 'node:fs'
+import { tmpdir } from 'node:os'
+import { join } from 'node:path'
 import {
+  __triggerPanic,
+  __triggerPanicAsync,
   addF64,
   addF64Buffer,
   addF64Buffers,
@@ -8,11 +13,27 @@ import {
   addScalarF64Buffer,
   addScalarF64Buffers,
   addScalarF64BuffersInto,
+  CancellationToken,
+  choleskyF64,
+  Expr,
+  fftF64,
+  genfromtxt,
+  loadtxt,
+  loadtxtAsync,
+  matmulF64Async,
   mulScalarF64,
   mulScalarF64Buffer,
   mulScalarF64Buffers,
   mulScalarF64BuffersInto,
+  NativeArray,
+  normF64,
+  NpyMemmap,
+  savetxt,
+  sortF64Async,
+  svdF64Async,
   transposeF64Buffer,
+  ufuncAtF32,
+  ufuncAtF64,
 } from '../index.js'
 
 test('native float64 scalar and vector kernels match NumPy-style outputs', () => {
@@ -24,6 +45,17 @@ test('native float64 scalar and vector kernels match NumPy-style outputs', () =>
   expect(Array.from(addF64(a, b))).toEqual([5, 7, 9])
 })
 
+test('native allocating kernels write every element of Rust-owned outputs', () => {
+  for (const length of [0, 1, 3, 8, 1025]) {
+    const input = Float64Array.from({ length }, (_, i) => i)
+    const output = addScalarF64(input, 0.5)
+
+    expect(output.length).toBe(length)
+    expect(Array.from(output)).toEqual(Array.from(input, (value) => value + 0.5))
+    expect(Array.from(addF64(input, input))).toEqual(Array.from(input, (value) => value * 2))
+  }
+})
+
 function bytes(array: Float64Array): Buffer {
   return Buffer.from(array.buffer, array.byteOffset, array.byteLength)
 }
@@ -75,7 +107,7 @@ test('native void all-buffer kernels fill caller-owned output buffers', () => {
 
 test('native add rejects mismatched lengths', () => {
   expect(() => addF64(new Float64Array([1]), new Float64Array([1, 2]))).toThrow(
-    'Expected equal input lengths',
+    'operands could not be broadcast together with shapes (1,) (2,)',
   )
 })
 
@@ -98,5 +130,330 @@ test('native transpose buffer kernel rejects invalid dimensions', () => {
   const input = new Float64Array([1, 2, 3])
   const output = new Float64Array(3)
 
-  expect(() => transposeF64Buffer(input, 2, 2, bytes(output))).toThrow('Expected input length')
+  expect(() => transposeF64Buffer(input, 2, 2, bytes(output))).toThrow('Input has length 3, which does not match a 2x2 matrix')
+})
+
+function writeNpy(path: string, data: Float64Array, shape: number[]): void {
+  const shapeText = shape.length === 1 ? `(${shape[0]},)` : `(${shape.join(', ')})`
+  let header = `{'descr': '<f8', 'fortran_order': False, 'shape': ${shapeText}, }`
+  header += `${' '.repeat((64 - ((10 + header.length + 1) % 64)) % 64)}\n`
+
+  const preamble = Buffer.alloc(10)
+  preamble.set([0x93, ...Buffer.from('NUMPY'), 1, 0])
+  preamble.writeUInt16LE(header.length, 8)
+  writeFileSync(path, Buffer.concat([preamble, Buffer.from(header), bytes(data)]))
+}
+
+test('native memmap exposes .npy data without copying', () => {
+  const path = join(mkdtempSync(join(tmpdir(), 'numpy-native-')), 'a.npy')
+  writeNpy(path, new Float64Array([1, 2, 3, 4, 5, 6]), [2, 3])
+
+  const map = NpyMemmap.open(path, 'r')
+  expect(map.dtype).toBe('<f8')
+  expect(map.shape).toEqual([2, 3])
+  expect(map.fortranOrder).toBe(false)
+  expect(map.offset).toBe(128)
+  expect(Array.from(new Float64Array(map.buffer()))).toEqual([1, 2, 3, 4, 5, 6])
+})
+
+test('native memmap r+ writes flush back to disk', () => {
+  const path = join(mkdtempSync(join(tmpdir(), 'numpy-native-')), 'a.npy')
+  writeNpy(path, new Float64Array([1, 2, 3]), [3])
+
+  const map = NpyMemmap.open(path, 'r+')
+  new Float64Array(map.buffer())[1] = 42
+  map.flush()
+
+  const onDisk = readFileSync(path)
+  expect(onDisk.readDoubleLE(map.offset + 8)).toBe(42)
+})
+
+test('native memmap c and r modes keep writes private', () => {
+  const path = join(mkdtempSync(join(tmpdir(), 'numpy-native-')), 'a.npy')
+  writeNpy(path, new Float64Array([1, 2, 3]), [3])
+
+  const copy = NpyMemmap.open(path, 'c')
+  new Float64Array(copy.buffer())[0] = -1
+  expect(() => copy.flush()).toThrow("Cannot flush a memmap opened with mode 'c'")
+
+  // Writing through an 'r' buffer must not fault, and must not reach the file
+  const readOnly = NpyMemmap.open(path)
+  expect(readOnly.writable).toBe(false)
+  new Float64Array(readOnly.buffer())[1] = -2
+  expect(() => readOnly.flush()).toThrow("Cannot flush a memmap opened with mode 'r'")
+  expect(Array.from(new Float64Array(NpyMemmap.open(path).buffer()))).toEqual([1, 2, 3])
+  expect(() => NpyMemmap.open(path, 'w')).toThrow('mmap_mode must be one of')
+})
+
+test('native raw memmap infers a 1-D shape from the file size', () => {
+  const path = join(mkdtempSync(join(tmpdir(), 'numpy-native-')), 'a.bin')
+  writeFileSync(path, bytes(new Float64Array([7, 8, 9])))
+
+  const map = NpyMemmap.openRaw(path, '<f8', 'r', 8)
+  expect(map.shape).toEqual([2])
+  expect(Array.from(new Float64Array(map.buffer()))).toEqual([8, 9])
+})
+
+test('native raw memmap rejects misaligned and overflowing regions', () => {
+  const path = join(mkdtempSync(join(tmpdir(), 'numpy-native-')), 'a.bin')
+  writeFileSync(path, bytes(new Float64Array([7, 8, 9])))
+
+  expect(() => NpyMemmap.openRaw(path, '<f8', 'r', 4)).toThrow('offset 4 is not a multiple of the 8-byte dtype')
+  expect(() => NpyMemmap.openRaw(path, '|u1', 'r', 1, [2 ** 32 - 1, 2 ** 32 + 1])).toThrow('exceeds the size')
+})
+
+test('native loadtxt parses delimited text with NumPy options', () => {
+  const path = join(mkdtempSync(join(tmpdir(), 'numpy-native-')), 'a.csv')
+  writeFileSync(path, 'x,y,z\n1,2.5,3 # note\n# skipped\n4,5e-1,-inf\n')
+
+  const table = loadtxt(path, { delimiter: ',', skiprows: 1 })
+  expect(table.shape).toEqual([2, 3])
+  expect(Array.from(table.data as Float64Array)).toEqual([1, 2.5, 3, 4, 0.5, -Infinity])
+
+  const column = loadtxt(path, { delimiter: ',', skiprows: 1, usecols: [-1], dtype: 'float32' })
+  expect(column.shape).toEqual([2])
+  expect(column.data).toBeInstanceOf(Float32Array)
+
+  expect(() => loadtxt(path, { delimiter: ',' })).toThrow("could not convert string 'x'")
+})
+
+test('native loadtxt parses integer dtypes exactly', () => {
+  const path = join(mkdtempSync(join(tmpdir(), 'numpy-native-')), 'a.txt')
+  writeFileSync(path, '9007199254740993 2\n3 4\n')
+
+  const table = loadtxt(path, { dtype: 'int64' })
+  expect(Array.from(table.data as BigInt64Array)).toEqual([9007199254740993n, 2n, 3n, 4n])
+})
+
+test('native genfromtxt fills missing fields', () => {
+  const path = join(mkdtempSync(join(tmpdir(), 'numpy-native-')), 'a.csv')
+  writeFileSync(path, '1,,3\n4,N/A,x\n')
+
+  const table = genfromtxt(path, { delimiter: ',', missingValues: ['N/A'], fillingValues: -1 })
+  expect(table.shape).toEqual([2, 3])
+  expect(Array.from(table.data as Float64Array)).toEqual([1, -1, 3, 4, -1, Number.NaN])
+})
+
+test('native savetxt formats rows like NumPy', () => {
+  const path = join(mkdtempSync(join(tmpdir(), 'numpy-native-')), 'a.txt')
+  const data = new Float64Array([1, -2.5, 1e-10, 0.1])
+
+  savetxt(path, data, [2, 2])
+  expect(readFileSync(path, 'utf8')).toBe(
+    '1.000000000000000000e+00 -2.500000000000000000e+00\n' +
+      '1.000000000000000036e-10 1.000000000000000056e-01\n',
+  )
+
+  savetxt(path, data, [2, 2], { fmt: ['%5d', '%.3g'], delimiter: ',', header: 'a,b' })
+  expect(readFileSync(path, 'utf8')).toBe('# a,b\n    1,-2.5\n    0,0.1\n')
+  expect(Array.from(loadtxt(path, { delimiter: ',' }).data as Float64Array)).toEqual([1, -2.5, 0, 0.1])
+
+  savetxt(path, new Float64Array(0), [3, 0])
+  expect(readFileSync(path, 'utf8')).toBe('\n\n\n')
+})
+
+test('native arrays keep shape and strides in Rust and return views', () => {
+  const a = NativeArray.fromFloat64(new Float64Array([0, 1, 2, 3, 4, 5]), [2, 3])
+  expect(a.strides).toEqual([24, 8])
+
+  const t = a.transpose()
+  expect(t.shape).toEqual([3, 2])
+  expect(t.strides).toEqual([8, 24])
+  expect(t.sharesMemory(a)).toBe(true)
+  expect(Array.from(t.toFloat64Array())).toEqual([0, 3, 1, 4, 2, 5])
+
+  const row = a.slice([{ index: 1 }, { step: -1 }])
+  expect(row.shape).toEqual([3])
+  expect(row.sharesMemory(a)).toBe(true)
+  expect(Array.from(row.toFloat64Array())).toEqual([5, 4, 3])
+
+  expect(a.reshape([3, -1]).sharesMemory(a)).toBe(true)
+  expect(t.reshape([-1]).sharesMemory(a)).toBe(false)
+})
+
+test('native array ops chain without leaving Rust', () => {
+  const a = NativeArray.fromFloat64(new Float64Array([0, 1, 2, 3, 4, 5]), [2, 3])
+  const b = NativeArray.fromFloat32(new Float32Array([10, 20, 30]))
+
+  const sum = a.add(b)
+  expect(sum.dtype).toBe('float64')
+  expect(Array.from(sum.toFloat64Array())).toEqual([10, 21, 32, 13, 24, 35])
+  expect(Array.from(a.matmul(a.transpose()).toFloat64Array())).toEqual([5, 14, 14, 50])
+  expect(a.mulScalar(2).addScalar(1).sum()).toBe(36)
+  expect(() => a.add(NativeArray.fromFloat64(new Float64Array([1, 2])))).toThrow(
+    'operands could not be broadcast together with shapes (2,3) (2,)',
+  )
+})
+
+test('native cumulative kernels read strided views', () => {
+  const a = NativeArray.fromFloat64(new Float64Array([1, 2, 3, 4, 5, 6]), [2, 3])
+
+  expect(Array.from(a.cumsum().toFloat64Array())).toEqual([1, 3, 6, 10, 15, 21])
+  expect(Array.from(a.transpose().cumsum(1).toFloat64Array())).toEqual([1, 5, 2, 7, 3, 9])
+  expect(Array.from(a.cumprod(-1).toFloat64Array())).toEqual([1, 2, 6, 4, 20, 120])
+  expect(a.cumsum(0, 'float32').dtype).toBe('float32')
+
+  const b = NativeArray.fromFloat64(new Float64Array([2, Number.NaN, 3]))
+  expect(Array.from(b.nancumsum().toFloat64Array())).toEqual([2, 2, 5])
+  expect(Array.from(b.nancumprod().toFloat64Array())).toEqual([2, 2, 6])
+  expect(() => a.cumsum(2)).toThrow('axis 2 is out of bounds for array of dimension 2')
+})
+
+test('native ufunc.at applies repeated indices unbuffered', () => {
+  const counts = new Float64Array(4)
+  ufuncAtF64('add', counts, [[0, 2, 2, -1]], new Float64Array([1]))
+  expect(Array.from(counts)).toEqual([1, 0, 2, 1])
+
+  const a = new Float64Array([1, 2, 3, 4, 5, 6])
+  ufuncAtF64('maximum', a, [[0, 1, 0], [2]], new Float64Array([9, 0, 7]), [2, 3])
+  expect(Array.from(a)).toEqual([1, 2, 9, 4, 5, 6])
+  ufuncAtF64('multiply', a, [[1, 1]], new Float64Array([2, 3, 4]), [2, 3])
+  expect(Array.from(a)).toEqual([1, 2, 9, 16, 45, 96])
+
+  const b = new Float32Array([5, 5])
+  ufuncAtF32('subtract', b, [[1, 1]], new Float32Array([0.5, 1.5]))
+  expect(Array.from(b)).toEqual([5, 3])
+  ufuncAtF32('minimum', b, [[0]], new Float32Array([4]))
+  expect(Array.from(b)).toEqual([4, 3])
+
+  expect(() => ufuncAtF64('add', counts, [[1, 4]], new Float64Array([1]))).toThrow(
+    'index 4 is out of bounds for axis 0 with size 4',
+  )
+  expect(Array.from(counts)).toEqual([1, 0, 2, 1])
+})
+
+test('native remainder and floor division follow the divisor sign', () => {
+  const a = NativeArray.fromFloat64(new Float64Array([-7, 7, 1]))
+  const b = NativeArray.fromFloat32(new Float32Array([3, -3, 0]))
+
+  expect(Array.from(a.remainder(b).toFloat64Array().subarray(0, 2))).toEqual([2, -2])
+  expect(Number.isNaN(a.remainder(b).toFloat64Array()[2])).toBe(true)
+  expect(Array.from(a.floorDivide(b).toFloat64Array())).toEqual([-3, -3, Infinity])
+})
+
+test('native round is half to even with decimals', () => {
+  const a = NativeArray.fromFloat64(new Float64Array([0.5, 1.5, 2.5, -2.5, -2.7]))
+
+  expect(Array.from(a.round().toFloat64Array())).toEqual([0, 2, 2, -2, -3])
+  expect(Array.from(a.rint().toFloat64Array())).toEqual([0, 2, 2, -2, -3])
+  expect(Array.from(a.fix().toFloat64Array())).toEqual([0, 1, 2, -2, -2])
+  const b = NativeArray.fromFloat32(new Float32Array([0.125, 1250, 1350]))
+  expect(Array.from(b.round(2).toFloat32Array())).toEqual([Math.fround(0.12), 1250, 1350])
+  expect(Array.from(b.round(-2).toFloat32Array())).toEqual([0, 1200, 1400])
+})
+
+test('native norm covers vector and matrix orders along any axis', () => {
+  const a = new Float64Array([1, -2, 3, 4, 0, -3])
+
+  expect(Array.from(normF64(new Float64Array([3, -4, 0]), [3], { ord: 0 }).data)).toEqual([2])
+  expect(normF64(new Float64Array([1e200, 1e200]), [2]).data[0]).toBeCloseTo(Math.SQRT2 * 1e200, -186)
+  expect(Array.from(normF64(a, [2, 3], { ord: -Infinity }).data)).toEqual([6])
+  expect(normF64(a, [2, 3], { ord: 'nuc' }).data[0]).toBeGreaterThan(normF64(a, [2, 3], { ord: 2 }).data[0])
+  const rows = normF64(a, [2, 3], { ord: 1, axis: [-1], keepdims: true })
+  expect(Array.from(rows.data)).toEqual([6, 7])
+  expect(rows.shape).toEqual([2, 1])
+  expect(() => normF64(a, [2, 3], { ord: 'fro', axis: [0] })).toThrow("Invalid norm order 'fro' for vectors")
+  expect(() => normF64(a, [2, 3], { ord: 3 })).toThrow('Invalid norm order for matrices.')
+})
+
+test('native fused expressions evaluate chains in one pass', () => {
+  const a = Float64Array.from({ length: 1000 }, (_, i) => i)
+  const expr = Expr.input(0).addScalar(3).mulScalar(2).sqrt()
+
+  expect(expr.inputCount).toBe(1)
+  const result = expr.evaluate([a])
+  expect(result.length).toBe(1000)
+  expect(result[999]).toBeCloseTo(Math.sqrt((999 + 3) * 2), 12)
+
+  const x = Expr.input(0)
+  const y = Expr.input(1)
+  const clipped = x.mul(y).sub(x.square()).maximum(Expr.constant(0))
+  const output = new Float64Array(3)
+  clipped.evaluateInto([new Float64Array([1, 2, 3]), new Float64Array([2, 1, Number.NaN])], bytes(output))
+  expect(Array.from(output)).toEqual([1, 0, Number.NaN])
+
+  expect(() => clipped.evaluate([a])).toThrow('Expression reads 2 inputs, got 1')
+
+  let deep = Expr.input(0)
+  for (let level = 1; level < 1024; level++) deep = deep.neg()
+  expect(deep.evaluate([new Float64Array([2])])[0]).toBe(-2)
+  expect(() => deep.neg()).toThrow('Expression is nested more than 1024 levels deep')
+})
+
+test('native async kernels resolve off the main thread', async () => {
+  expect(Array.from(await matmulF64Async(new Float64Array([1, 2, 3, 4]), new Float64Array([5, 6, 7, 8]), 2, 2, 2))).toEqual([
+    19, 22, 43, 50,
+  ])
+  expect(Array.from(await sortF64Async(new Float64Array([3, Number.NaN, -1, 2])))).toEqual([-1, 2, 3, Number.NaN])
+
+  const { s } = await svdF64Async(new Float64Array([3, 2, 2, 2, 3, -2]), 2, 3)
+  expect(s[0]).toBeCloseTo(5, 12)
+  expect(s[1]).toBeCloseTo(3, 12)
+
+  const spectrum = fftF64(new Float64Array([1, 2, 3, 4, 5]))
+  expect(spectrum[0]).toBeCloseTo(15, 12)
+  expect(spectrum[3]).toBeCloseTo(3.440954801177933, 12)
+
+  const dir = mkdtempSync(join(tmpdir(), 'tsnum-async-'))
+  const path = join(dir, 'grid.txt')
+  writeFileSync(path, '1 2\n3 4\n')
+  const grid = await loadtxtAsync(path)
+  expect(grid.shape).toEqual([2, 2])
+})
+
+test('native async kernels reject once cancelled', async () => {
+  const token = new CancellationToken()
+  token.cancel()
+  await expect(matmulF64Async(new Float64Array(4), new Float64Array(4), 2, 2, 2, token)).rejects.toThrow(
+    'The operation was aborted',
+  )
+
+  const controller = new AbortController()
+  const fromSignal = CancellationToken.fromAbortSignal(controller.signal)
+  expect(fromSignal.cancelled).toBe(false)
+  controller.abort()
+  expect(fromSignal.cancelled).toBe(true)
+  await expect(sortF64Async(new Float64Array(10), fromSignal)).rejects.toThrow('The operation was aborted')
+})
+
+test('native errors carry structured NumPy error payloads', () => {
+  const payload = (fn: () => unknown) => {
+    try {
+      fn()
+    } catch (error) {
+      return JSON.parse((error as Error).message)
+    }
+    throw new Error('expected the call to throw')
+  }
+
+  const a = NativeArray.fromFloat64(new Float64Array(6), [2, 3])
+  expect(payload(() => a.add(NativeArray.fromFloat64(new Float64Array(2))))).toEqual({
+    name: 'ValueError',
+    message: 'operands could not be broadcast together with shapes (2,3) (2,)',
+    expectedShape: [2, 3],
+    actualShape: [2],
+  })
+  expect(payload(() => a.transpose([0, 2]))).toEqual({
+    name: 'AxisError',
+    message: 'axis 2 is out of bounds for array of dimension 2',
+    axis: 2,
+    ndim: 2,
+  })
+  expect(payload(() => choleskyF64(new Float64Array([1, 2, 2, 1]), 2)).name).toBe('LinAlgError')
+  const tooMany = () => ufuncAtF64('add', new Float64Array(2), [[0], [0]], new Float64Array([1]))
+  expect(payload(tooMany)).toEqual({
+    name: 'IndexError',
+    message: 'too many indices for array: array is 1-dimensional, but 2 were indexed',
+  })
+  expect(payload(() => NativeArray.full([2], 0, 'complex128')).name).toBe('TypeError')
+})
+
+test('Rust panics surface as JS exceptions and the addon keeps working', async () => {
+  expect(() => __triggerPanic('boom')).toThrow('Rust panic in __triggerPanic: boom')
+  await expect(__triggerPanicAsync('off thread')).rejects.toThrow(
+    'Rust panic in __triggerPanicAsync: off thread',
+  )
+
+  expect(Array.from(addScalarF64(new Float64Array([1, 2]), 1))).toEqual([2, 3])
+  expect(Array.from(await sortF64Async(new Float64Array([3, 1, 2])))).toEqual([1, 2, 3])
 })
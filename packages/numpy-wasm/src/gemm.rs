@@ -0,0 +1,435 @@
+//! Cache-blocked, packed GEMM for the browser build.
+//!
+//! `C = op(A) @ op(B)` in the BLIS layout: B is packed a `KC x NC` panel at a
+//! time into `NR`-wide strips, A a `MC x KC` block at a time into `MR`-tall
+//! strips, and an `MR x NR` register tile walks each pair of strips. With
+//! `simd128` the tile lives in `f64x2` (or `f32x4`) registers; other targets,
+//! including the native test build, run the same tile in scalar code.
+//! `f32` products accumulate in `f32`, as NumPy's float32 matmul does.
+
+use std::cell::RefCell;
+use std::ops::{Add, AddAssign, Mul, Range};
+
+use sylphx_numpy_core::{validate, Result};
+
+/// Rows of the register tile.
+const MR: usize = 4;
+/// Columns of the register tile: two `f64x2` lanes or one `f32x4`.
+const NR: usize = 4;
+/// Depth of a packed panel; an A strip and a B strip stay in L1.
+const KC: usize = 128;
+/// Rows of A packed at once.
+const MC: usize = 64;
+/// Columns of B packed at once.
+const NC: usize = 512;
+
+thread_local! {
+    /// Packing buffers reused across calls, so only the first call allocates.
+    static PACKED_F64: RefCell<(Vec<f64>, Vec<f64>)> = const { RefCell::new((Vec::new(), Vec::new())) };
+    static PACKED_F32: RefCell<(Vec<f32>, Vec<f32>)> = const { RefCell::new((Vec::new(), Vec::new())) };
+}
+
+/// An element type the GEMM can multiply: its register tile and its own
+/// packing buffers.
+pub trait Element: Copy + Default + Add<Output = Self> + Mul<Output = Self> + AddAssign {
+    fn with_packed<R>(f: impl FnOnce(&mut Vec<Self>, &mut Vec<Self>) -> R) -> R;
+
+    /// `MR x NR` product of a packed A strip and a packed B strip.
+    fn tile(a: &[Self], b: &[Self]) -> [[Self; NR]; MR];
+}
+
+impl Element for f64 {
+    fn with_packed<R>(f: impl FnOnce(&mut Vec<f64>, &mut Vec<f64>) -> R) -> R {
+        PACKED_F64.with(|packed| {
+            let (a, b) = &mut *packed.borrow_mut();
+            f(a, b)
+        })
+    }
+
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    fn tile(a: &[f64], b: &[f64]) -> [[f64; NR]; MR] {
+        use core::arch::wasm32::{
+            f64x2_add, f64x2_extract_lane, f64x2_mul, f64x2_splat, v128, v128_load,
+        };
+
+        let mut acc = [[f64x2_splat(0.0); NR / 2]; MR];
+        for (a, b) in a.chunks_exact(MR).zip(b.chunks_exact(NR)) {
+            // SAFETY: `b` holds `NR = 4` values, so both 16-byte loads are in
+            // bounds; `v128_load` has no alignment requirement.
+            let (b0, b1) = unsafe {
+                let b = b.as_ptr();
+                (
+                    v128_load(b as *const v128),
+                    v128_load(b.add(2) as *const v128),
+                )
+            };
+            for (row, &x) in acc.iter_mut().zip(a) {
+                let x = f64x2_splat(x);
+                row[0] = f64x2_add(row[0], f64x2_mul(x, b0));
+                row[1] = f64x2_add(row[1], f64x2_mul(x, b1));
+            }
+        }
+        acc.map(|[lo, hi]| {
+            [
+                f64x2_extract_lane::<0>(lo),
+                f64x2_extract_lane::<1>(lo),
+                f64x2_extract_lane::<0>(hi),
+                f64x2_extract_lane::<1>(hi),
+            ]
+        })
+    }
+
+    #[cfg(not(all(target_arch = "wasm32", target_feature = "simd128")))]
+    fn tile(a: &[f64], b: &[f64]) -> [[f64; NR]; MR] {
+        scalar_tile(a, b)
+    }
+}
+
+impl Element for f32 {
+    fn with_packed<R>(f: impl FnOnce(&mut Vec<f32>, &mut Vec<f32>) -> R) -> R {
+        PACKED_F32.with(|packed| {
+            let (a, b) = &mut *packed.borrow_mut();
+            f(a, b)
+        })
+    }
+
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    fn tile(a: &[f32], b: &[f32]) -> [[f32; NR]; MR] {
+        use core::arch::wasm32::{
+            f32x4_add, f32x4_extract_lane, f32x4_mul, f32x4_splat, v128, v128_load,
+        };
+
+        let mut acc = [f32x4_splat(0.0); MR];
+        for (a, b) in a.chunks_exact(MR).zip(b.chunks_exact(NR)) {
+            // SAFETY: `b` holds `NR = 4` values, exactly one 16-byte load;
+            // `v128_load` has no alignment requirement.
+            let b = unsafe { v128_load(b.as_ptr() as *const v128) };
+            for (row, &x) in acc.iter_mut().zip(a) {
+                *row = f32x4_add(*row, f32x4_mul(f32x4_splat(x), b));
+            }
+        }
+        acc.map(|row| {
+            [
+                f32x4_extract_lane::<0>(row),
+                f32x4_extract_lane::<1>(row),
+                f32x4_extract_lane::<2>(row),
+                f32x4_extract_lane::<3>(row),
+            ]
+        })
+    }
+
+    #[cfg(not(all(target_arch = "wasm32", target_feature = "simd128")))]
+    fn tile(a: &[f32], b: &[f32]) -> [[f32; NR]; MR] {
+        scalar_tile(a, b)
+    }
+}
+
+/// A row-major matrix operand, read as its transpose when `trans` is set.
+#[derive(Clone, Copy)]
+pub struct Operand<'a, T = f64> {
+    pub data: &'a [T],
+    pub trans: bool,
+}
+
+/// Strided read access to `op(X)`.
+#[derive(Clone, Copy)]
+struct View<'a, T> {
+    data: &'a [T],
+    row_stride: usize,
+    col_stride: usize,
+}
+
+impl<'a, T: Copy> View<'a, T> {
+    /// `op(X)` with `rows x cols` logical shape.
+    fn new(operand: Operand<'a, T>, rows: usize, cols: usize) -> Self {
+        let (row_stride, col_stride) = if operand.trans { (1, rows) } else { (cols, 1) };
+        Self {
+            data: operand.data,
+            row_stride,
+            col_stride,
+        }
+    }
+
+    fn transposed(self) -> Self {
+        Self {
+            row_stride: self.col_stride,
+            col_stride: self.row_stride,
+            ..self
+        }
+    }
+
+    fn at(&self, row: usize, col: usize) -> T {
+        self.data[row * self.row_stride + col * self.col_stride]
+    }
+}
+
+/// `out = op(A) @ op(B)` where `op(A)` is `m x k` and `op(B)` is `k x n`.
+/// A transposed operand is stored row-major with its dimensions swapped.
+pub fn gemm_into<T: Element>(
+    a: Operand<T>,
+    b: Operand<T>,
+    m: usize,
+    k: usize,
+    n: usize,
+    out: &mut [T],
+) -> Result<()> {
+    gemm_rows_into(a, b, m, k, n, 0..m, out)
+}
+
+/// Rows `rows` of `op(A) @ op(B)` into an `rows.len() x n` output, so
+/// threads can split one product by output rows.
+pub fn gemm_rows_into<T: Element>(
+    a: Operand<T>,
+    b: Operand<T>,
+    m: usize,
+    k: usize,
+    n: usize,
+    rows: Range<usize>,
+    out: &mut [T],
+) -> Result<()> {
+    let (a_rows, a_cols) = if a.trans { (k, m) } else { (m, k) };
+    let (b_rows, b_cols) = if b.trans { (n, k) } else { (k, n) };
+    validate::matrix_len("A", a.data.len(), a_rows, a_cols)?;
+    validate::matrix_len("B", b.data.len(), b_rows, b_cols)?;
+    let rows = rows.start.min(m)..rows.end.min(m);
+    validate::output_len(validate::checked_len(rows.len(), n)?, out.len())?;
+
+    out.fill(T::default());
+    let a = View::new(a, m, k);
+    // Packed as `op(B)^T` so both operands are cut into row strips.
+    let b = View::new(b, k, n).transposed();
+
+    T::with_packed(|packed_a, packed_b| {
+        for jc in (0..n).step_by(NC) {
+            let columns = jc..n.min(jc + NC);
+            for pc in (0..k).step_by(KC) {
+                let depth = pc..k.min(pc + KC);
+                let kc = depth.len();
+                pack(b, columns.clone(), depth.clone(), NR, packed_b);
+
+                for ic in rows.clone().step_by(MC) {
+                    pack(a, ic..rows.end.min(ic + MC), depth.clone(), MR, packed_a);
+
+                    for (s, a_strip) in packed_a.chunks_exact(kc * MR).enumerate() {
+                        let row0 = ic + s * MR;
+                        for (t, b_strip) in packed_b.chunks_exact(kc * NR).enumerate() {
+                            let col0 = jc + t * NR;
+                            let cols = NR.min(n - col0);
+                            let tile = T::tile(a_strip, b_strip);
+                            for (r, tile_row) in
+                                tile.iter().enumerate().take(MR.min(rows.end - row0))
+                            {
+                                let row = &mut out[(row0 - rows.start + r) * n + col0..][..cols];
+                                for (value, &x) in row.iter_mut().zip(tile_row) {
+                                    *value += x;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    });
+    Ok(())
+}
+
+/// Copy `rows x depth` of `view` into strips of `width` rows, each stored
+/// column by column and zero-padded to a whole strip.
+fn pack<T: Copy + Default>(
+    view: View<T>,
+    rows: Range<usize>,
+    depth: Range<usize>,
+    width: usize,
+    buf: &mut Vec<T>,
+) {
+    buf.clear();
+    for strip in rows.clone().step_by(width) {
+        for p in depth.clone() {
+            for row in strip..strip + width {
+                buf.push(if row < rows.end {
+                    view.at(row, p)
+                } else {
+                    T::default()
+                });
+            }
+        }
+    }
+}
+
+/// The register tile in scalar code, for targets without `simd128`.
+#[cfg(not(all(target_arch = "wasm32", target_feature = "simd128")))]
+fn scalar_tile<T: Element>(a: &[T], b: &[T]) -> [[T; NR]; MR] {
+    let mut acc = [[T::default(); NR]; MR];
+    for (a, b) in a.chunks_exact(MR).zip(b.chunks_exact(NR)) {
+        for (row, &x) in acc.iter_mut().zip(a) {
+            for (value, &y) in row.iter_mut().zip(b) {
+                *value += x * y;
+            }
+        }
+    }
+    acc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive(a: &[f64], b: &[f64], m: usize, k: usize, n: usize) -> Vec<f64> {
+        let mut out = vec![0.0; m * n];
+        for i in 0..m {
+            for p in 0..k {
+                for j in 0..n {
+                    out[i * n + j] += a[i * k + p] * b[p * n + j];
+                }
+            }
+        }
+        out
+    }
+
+    fn transpose(x: &[f64], rows: usize, cols: usize) -> Vec<f64> {
+        (0..cols)
+            .flat_map(|c| (0..rows).map(move |r| x[r * cols + c]))
+            .collect()
+    }
+
+    #[test]
+    fn test_gemm_matches_naive_across_block_edges() {
+        // Odd sizes straddle the MR/NR tiles and the KC/MC/NC blocks.
+        for &(m, k, n) in &[
+            (1, 1, 1),
+            (5, 3, 7),
+            (67, 131, 9),
+            (3, 2, 515),
+            (0, 4, 3),
+            (2, 0, 2),
+        ] {
+            let a: Vec<f64> = (0..m * k).map(|i| ((i * 7) % 11) as f64 - 5.0).collect();
+            let b: Vec<f64> = (0..k * n).map(|i| ((i * 5) % 13) as f64 - 6.0).collect();
+            let expected = naive(&a, &b, m, k, n);
+            let (at, bt) = (transpose(&a, m, k), transpose(&b, k, n));
+            let mut out = vec![f64::NAN; m * n];
+            for (trans_a, trans_b) in [(false, false), (true, false), (false, true), (true, true)] {
+                let a = Operand {
+                    data: if trans_a { &at } else { &a },
+                    trans: trans_a,
+                };
+                let b = Operand {
+                    data: if trans_b { &bt } else { &b },
+                    trans: trans_b,
+                };
+                gemm_into(a, b, m, k, n, &mut out).unwrap();
+                assert_eq!(
+                    out, expected,
+                    "{}x{}x{} trans=({}, {})",
+                    m, k, n, trans_a, trans_b
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_gemm_row_ranges_tile_the_product() {
+        let (m, k, n) = (70, 9, 6);
+        let a: Vec<f64> = (0..m * k).map(|i| (i % 7) as f64).collect();
+        let b: Vec<f64> = (0..k * n).map(|i| (i % 5) as f64 - 2.0).collect();
+        let (a, b) = (
+            Operand {
+                data: &a,
+                trans: false,
+            },
+            Operand {
+                data: &b,
+                trans: false,
+            },
+        );
+        let mut whole = vec![0.0; m * n];
+        gemm_into(a, b, m, k, n, &mut whole).unwrap();
+        let mut parts = vec![0.0; m * n];
+        for (chunk, rows) in parts
+            .chunks_mut(23 * n)
+            .zip([0..23, 23..46, 46..69, 69..70])
+        {
+            gemm_rows_into(a, b, m, k, n, rows, chunk).unwrap();
+        }
+        assert_eq!(parts, whole);
+    }
+
+    #[test]
+    fn test_gemm_f32_accumulates_in_f32() {
+        let (m, k, n) = (5, 131, 6);
+        let a: Vec<f32> = (0..m * k).map(|i| ((i * 7) % 11) as f32 - 5.0).collect();
+        let b: Vec<f32> = (0..k * n).map(|i| ((i * 5) % 13) as f32 - 6.0).collect();
+        let expected: Vec<f32> = naive(
+            &a.iter().map(|&x| x as f64).collect::<Vec<_>>(),
+            &b.iter().map(|&x| x as f64).collect::<Vec<_>>(),
+            m,
+            k,
+            n,
+        )
+        .into_iter()
+        .map(|x| x as f32)
+        .collect();
+        let mut out = vec![f32::NAN; m * n];
+        gemm_into(
+            Operand {
+                data: &a,
+                trans: false,
+            },
+            Operand {
+                data: &b,
+                trans: false,
+            },
+            m,
+            k,
+            n,
+            &mut out,
+        )
+        .unwrap();
+        assert_eq!(out, expected);
+        // 2^24 + 1 is not a float32, so the f32 accumulator drops the 1.
+        let mut one = [0f32];
+        gemm_into(
+            Operand {
+                data: &[16_777_216.0, 1.0],
+                trans: false,
+            },
+            Operand {
+                data: &[1.0, 1.0],
+                trans: false,
+            },
+            1,
+            2,
+            1,
+            &mut one,
+        )
+        .unwrap();
+        assert_eq!(one, [16_777_216.0]);
+    }
+
+    #[test]
+    fn test_gemm_validates_operands() {
+        let a = Operand {
+            data: &[1.0; 6],
+            trans: true,
+        };
+        let b = Operand {
+            data: &[1.0; 6],
+            trans: false,
+        };
+        assert!(gemm_into(a, b, 3, 2, 3, &mut [0.0; 9]).is_ok());
+        assert!(gemm_into(a, b, 2, 3, 2, &mut [0.0; 4]).is_ok());
+        assert!(gemm_into(a, b, 3, 2, 3, &mut [0.0; 8]).is_err());
+        assert!(gemm_into(
+            Operand {
+                data: &[1.0; 5],
+                trans: false
+            },
+            b,
+            3,
+            2,
+            3,
+            &mut [0.0; 9]
+        )
+        .is_err());
+    }
+}
@@ -1,270 +1,591 @@
+use js_sys::{BigInt64Array, Float32Array, Float64Array, Int32Array};
+use sylphx_numpy_core::cumulative::{self, AccumDType, Accumulated, CumulativeOp, Scalar, View};
+use sylphx_numpy_core::division::{self, Divmod};
+use sylphx_numpy_core::elementwise::{self, BinaryOp, CompareOp, UnaryOp};
+use sylphx_numpy_core::fft as fft_kernels;
+use sylphx_numpy_core::norm::{self as norm_kernels, NormOrd};
+use sylphx_numpy_core::ufunc::Ufunc;
+use sylphx_numpy_core::{
+    linalg, reduce, rounding, shape, validate, Float, Never, NumpyError, Result as CoreResult,
+};
 use wasm_bindgen::prelude::*;
 
+mod gemm;
+mod resident;
+mod simd;
+#[cfg(feature = "threads")]
+mod threads;
+
+use gemm::Operand;
+pub use resident::WasmArray;
+
 // ===== WASM Backend for @sylphx/numpy =====
-// High-performance Rust implementation with SIMD support
+// Thin wasm-bindgen wrappers over the shared `numpy-core` kernels
 
-/// Add two arrays element-wise (with broadcasting)
+/// Throw a core error as a JS `Error` whose message is the shared JSON payload,
+/// the same wire format the native addon uses.
+fn js_error(error: NumpyError) -> JsValue {
+    JsError::new(&error.to_json()).into()
+}
+
+/// Report Rust panics through `console.error` with their message instead of
+/// an opaque `unreachable` trap.
+#[wasm_bindgen(start)]
+pub fn init_panic_hook() {
+    console_error_panic_hook::set_once();
+}
+
+/// Whether this module was compiled with `simd128`, which tells the `wasm`
+/// and `wasm-nosimd` artifacts apart.
 #[wasm_bindgen]
-pub fn add_arrays(a: &[f64], b: &[f64]) -> Vec<f64> {
-    assert_eq!(a.len(), b.len(), "Arrays must have same length (broadcasting handled in JS)");
+pub fn simd_enabled() -> bool {
+    cfg!(target_feature = "simd128")
+}
+
+/// Allocate an output of `len` elements and fill it with a core kernel.
+fn with_output<T: Float>(
+    len: usize,
+    kernel: impl FnOnce(&mut [T]) -> CoreResult<()>,
+) -> Result<Vec<T>, JsValue> {
+    let mut out = vec![T::default(); len];
+    kernel(&mut out).map_err(js_error)?;
+    Ok(out)
+}
+
+fn unary<T: simd::Element>(op: UnaryOp, a: &[T]) -> Result<Vec<T>, JsValue> {
+    with_output(a.len(), |out| simd::unary_into(op, a, out))
+}
+
+fn binary<T: simd::Element>(op: BinaryOp, a: &[T], b: &[T]) -> Result<Vec<T>, JsValue> {
+    with_output(a.len(), |out| simd::binary_into(op, a, b, out))
+}
+
+fn scalar<T: simd::Element>(op: BinaryOp, a: &[T], scalar: T) -> Result<Vec<T>, JsValue> {
+    with_output(a.len(), |out| simd::scalar_into(op, a, scalar, out))
+}
+
+/// Comparisons return `0`/`1` bytes, which JS reads as a `Uint8Array`.
+fn compare<T: simd::Element>(op: CompareOp, a: &[T], b: &[T]) -> Result<Vec<u8>, JsValue> {
+    let mut out = vec![0; a.len()];
+    simd::compare_into(op, a, b, &mut out).map_err(js_error)?;
+    Ok(out)
+}
+
+fn compare_scalar<T: simd::Element>(op: CompareOp, a: &[T], scalar: T) -> Result<Vec<u8>, JsValue> {
+    let mut out = vec![0; a.len()];
+    simd::compare_scalar_into(op, a, scalar, &mut out).map_err(js_error)?;
+    Ok(out)
+}
 
-    a.iter()
-        .zip(b.iter())
-        .map(|(x, y)| x + y)
-        .collect()
+/// Add two arrays element-wise (with broadcasting)
+#[wasm_bindgen]
+pub fn add_arrays(a: &[f64], b: &[f64]) -> Result<Vec<f64>, JsValue> {
+    binary(BinaryOp::Add, a, b)
 }
 
 /// Add scalar to array
 #[wasm_bindgen]
-pub fn add_scalar(a: &[f64], scalar: f64) -> Vec<f64> {
-    a.iter().map(|x| x + scalar).collect()
+pub fn add_scalar(a: &[f64], scalar: f64) -> Result<Vec<f64>, JsValue> {
+    self::scalar(BinaryOp::Add, a, scalar)
 }
 
 /// Subtract two arrays element-wise
 #[wasm_bindgen]
-pub fn sub_arrays(a: &[f64], b: &[f64]) -> Vec<f64> {
-    assert_eq!(a.len(), b.len());
-
-    a.iter()
-        .zip(b.iter())
-        .map(|(x, y)| x - y)
-        .collect()
+pub fn sub_arrays(a: &[f64], b: &[f64]) -> Result<Vec<f64>, JsValue> {
+    binary(BinaryOp::Sub, a, b)
 }
 
 /// Subtract scalar from array
 #[wasm_bindgen]
-pub fn sub_scalar(a: &[f64], scalar: f64) -> Vec<f64> {
-    a.iter().map(|x| x - scalar).collect()
+pub fn sub_scalar(a: &[f64], scalar: f64) -> Result<Vec<f64>, JsValue> {
+    self::scalar(BinaryOp::Sub, a, scalar)
 }
 
 /// Multiply two arrays element-wise
 #[wasm_bindgen]
-pub fn mul_arrays(a: &[f64], b: &[f64]) -> Vec<f64> {
-    assert_eq!(a.len(), b.len());
-
-    a.iter()
-        .zip(b.iter())
-        .map(|(x, y)| x * y)
-        .collect()
+pub fn mul_arrays(a: &[f64], b: &[f64]) -> Result<Vec<f64>, JsValue> {
+    binary(BinaryOp::Mul, a, b)
 }
 
 /// Multiply array by scalar
 #[wasm_bindgen]
-pub fn mul_scalar(a: &[f64], scalar: f64) -> Vec<f64> {
-    a.iter().map(|x| x * scalar).collect()
+pub fn mul_scalar(a: &[f64], scalar: f64) -> Result<Vec<f64>, JsValue> {
+    self::scalar(BinaryOp::Mul, a, scalar)
 }
 
 /// Divide two arrays element-wise
 #[wasm_bindgen]
-pub fn div_arrays(a: &[f64], b: &[f64]) -> Vec<f64> {
-    assert_eq!(a.len(), b.len());
-
-    a.iter()
-        .zip(b.iter())
-        .map(|(x, y)| x / y)
-        .collect()
+pub fn div_arrays(a: &[f64], b: &[f64]) -> Result<Vec<f64>, JsValue> {
+    binary(BinaryOp::Div, a, b)
 }
 
 /// Divide array by scalar
 #[wasm_bindgen]
-pub fn div_scalar(a: &[f64], scalar: f64) -> Vec<f64> {
-    a.iter().map(|x| x / scalar).collect()
+pub fn div_scalar(a: &[f64], scalar: f64) -> Result<Vec<f64>, JsValue> {
+    self::scalar(BinaryOp::Div, a, scalar)
 }
 
 /// Power: raise array elements to exponent
 #[wasm_bindgen]
-pub fn pow_scalar(a: &[f64], exponent: f64) -> Vec<f64> {
-    a.iter().map(|x| x.powf(exponent)).collect()
+pub fn pow_scalar(a: &[f64], exponent: f64) -> Result<Vec<f64>, JsValue> {
+    self::scalar(BinaryOp::Pow, a, exponent)
 }
 
 // ===== Reductions =====
 
 /// Sum all elements
 #[wasm_bindgen]
-pub fn sum(a: &[f64]) -> f64 {
-    a.iter().sum()
+pub fn sum(a: &[f64]) -> Result<f64, JsValue> {
+    Ok(simd::sum(a))
 }
 
 /// Mean of all elements
 #[wasm_bindgen]
-pub fn mean(a: &[f64]) -> f64 {
-    if a.is_empty() {
-        return 0.0;
-    }
-    a.iter().sum::<f64>() / a.len() as f64
+pub fn mean(a: &[f64]) -> Result<f64, JsValue> {
+    Ok(simd::mean(a))
 }
 
-/// Maximum element
+/// Maximum element; NaN if any element is NaN
 #[wasm_bindgen]
-pub fn max(a: &[f64]) -> f64 {
-    a.iter()
-        .copied()
-        .fold(f64::NEG_INFINITY, f64::max)
+pub fn max(a: &[f64]) -> Result<f64, JsValue> {
+    simd::max(a).map_err(js_error)
 }
 
-/// Minimum element
+/// Minimum element; NaN if any element is NaN
 #[wasm_bindgen]
-pub fn min(a: &[f64]) -> f64 {
-    a.iter()
-        .copied()
-        .fold(f64::INFINITY, f64::min)
+pub fn min(a: &[f64]) -> Result<f64, JsValue> {
+    simd::min(a).map_err(js_error)
 }
 
 /// Standard deviation
 #[wasm_bindgen]
-pub fn std(a: &[f64]) -> f64 {
-    variance(a).sqrt()
+pub fn std(a: &[f64]) -> Result<f64, JsValue> {
+    Ok(reduce::std(a))
 }
 
 /// Variance
 #[wasm_bindgen]
-pub fn variance(a: &[f64]) -> f64 {
-    if a.is_empty() {
-        return 0.0;
-    }
-
-    let mean_val = mean(a);
-    let sum_squared_diff: f64 = a.iter()
-        .map(|x| {
-            let diff = x - mean_val;
-            diff * diff
-        })
-        .sum();
-
-    sum_squared_diff / a.len() as f64
+pub fn variance(a: &[f64]) -> Result<f64, JsValue> {
+    Ok(reduce::var(a))
 }
 
 /// Product of all elements
 #[wasm_bindgen]
-pub fn prod(a: &[f64]) -> f64 {
-    a.iter().product()
+pub fn prod(a: &[f64]) -> Result<f64, JsValue> {
+    Ok(simd::prod(a))
 }
 
-/// Index of maximum element
+/// Index of maximum element, or of the first NaN
 #[wasm_bindgen]
-pub fn argmax(a: &[f64]) -> usize {
-    let mut max_idx = 0;
-    let mut max_val = a[0];
+pub fn argmax(a: &[f64]) -> Result<usize, JsValue> {
+    reduce::argmax(a).map_err(js_error)
+}
 
-    for (i, &val) in a.iter().enumerate().skip(1) {
-        if val > max_val {
-            max_val = val;
-            max_idx = i;
-        }
-    }
-    max_idx
+/// Index of minimum element, or of the first NaN
+#[wasm_bindgen]
+pub fn argmin(a: &[f64]) -> Result<usize, JsValue> {
+    reduce::argmin(a).map_err(js_error)
 }
 
-/// Index of minimum element
+/// `np.argmax(a, axis, keepdims)` for an array of `shape`; returns int64
+/// indices along `axis`, or one flat index when `axis` is absent. Ties go to
+/// the first occurrence and the first NaN wins
 #[wasm_bindgen]
-pub fn argmin(a: &[f64]) -> usize {
-    let mut min_idx = 0;
-    let mut min_val = a[0];
+pub fn argmax_axis(
+    a: &[f64],
+    shape: &[usize],
+    axis: Option<i32>,
+    keepdims: bool,
+) -> Result<Vec<i64>, JsValue> {
+    arg_axis(reduce::argmax_axis, a, shape, axis, keepdims)
+}
 
-    for (i, &val) in a.iter().enumerate().skip(1) {
-        if val < min_val {
-            min_val = val;
-            min_idx = i;
-        }
-    }
-    min_idx
-}
-
-/// Compute vector/matrix norm
-#[wasm_bindgen]
-pub fn norm(a: &[f64], ord: f64) -> Result<f64, String> {
-    if ord == 2.0 {
-        // L2 norm (Euclidean)
-        let sum: f64 = a.iter().map(|x| x * x).sum();
-        Ok(sum.sqrt())
-    } else if ord == 1.0 {
-        // L1 norm (Manhattan)
-        Ok(a.iter().map(|x| x.abs()).sum())
-    } else if ord == f64::INFINITY {
-        // Max norm
-        a.iter()
-            .map(|x| x.abs())
-            .fold(None, |max, x| match max {
-                None => Some(x),
-                Some(m) => Some(if x > m { x } else { m }),
-            })
-            .ok_or_else(|| "Empty array".to_string())
-    } else if ord == -1.0 {
-        // Special marker for Frobenius norm (same as L2)
-        let sum: f64 = a.iter().map(|x| x * x).sum();
-        Ok(sum.sqrt())
-    } else {
-        Err(format!("Unsupported norm order: {}", ord))
-    }
+/// `np.argmin` with the arguments of `argmax_axis`
+#[wasm_bindgen]
+pub fn argmin_axis(
+    a: &[f64],
+    shape: &[usize],
+    axis: Option<i32>,
+    keepdims: bool,
+) -> Result<Vec<i64>, JsValue> {
+    arg_axis(reduce::argmin_axis, a, shape, axis, keepdims)
+}
+
+/// `np.nanargmax` with the arguments of `argmax_axis`; throws on an all-NaN
+/// lane
+#[wasm_bindgen]
+pub fn nanargmax_axis(
+    a: &[f64],
+    shape: &[usize],
+    axis: Option<i32>,
+    keepdims: bool,
+) -> Result<Vec<i64>, JsValue> {
+    arg_axis(reduce::nanargmax_axis, a, shape, axis, keepdims)
+}
+
+/// `np.nanargmin` with the arguments of `argmax_axis`
+#[wasm_bindgen]
+pub fn nanargmin_axis(
+    a: &[f64],
+    shape: &[usize],
+    axis: Option<i32>,
+    keepdims: bool,
+) -> Result<Vec<i64>, JsValue> {
+    arg_axis(reduce::nanargmin_axis, a, shape, axis, keepdims)
+}
+
+type ArgKernel<T> =
+    fn(&[T], &[usize], Option<i64>, bool, &Never) -> CoreResult<(Vec<i64>, Vec<usize>)>;
+
+fn arg_axis<T: Float>(
+    kernel: ArgKernel<T>,
+    a: &[T],
+    shape: &[usize],
+    axis: Option<i32>,
+    keepdims: bool,
+) -> Result<Vec<i64>, JsValue> {
+    kernel(a, shape, axis.map(i64::from), keepdims, &Never)
+        .map(|(indices, _)| indices)
+        .map_err(js_error)
+}
+
+/// Vector norm of the flattened input for any numeric `ord`
+#[wasm_bindgen]
+pub fn norm(a: &[f64], ord: f64) -> Result<f64, JsValue> {
+    norm_kernels::vector_norm(a, ord).map_err(js_error)
+}
+
+/// `np.linalg.norm` with `ord` as a number, `"fro"` or `"nuc"` and an optional
+/// `axis`; returns the flat C-order result
+#[wasm_bindgen]
+pub fn linalg_norm(
+    a: &[f64],
+    shape: &[usize],
+    ord: Option<String>,
+    axis: Option<Vec<i32>>,
+    keepdims: bool,
+) -> Result<Vec<f64>, JsValue> {
+    let ord = ord
+        .as_deref()
+        .map(NormOrd::parse)
+        .transpose()
+        .map_err(js_error)?;
+    let axis: Option<Vec<i64>> = axis.map(|axis| axis.into_iter().map(i64::from).collect());
+    norm_kernels::norm(a, shape, ord, axis.as_deref(), keepdims, &Never)
+        .map(|(values, _)| values)
+        .map_err(js_error)
+}
+
+/// `np.var(a, axis, ddof, keepdims, where)` for an array of `shape`; returns
+/// the flat C-order result. `where_mask` holds one `0`/`1` byte per element,
+/// already broadcast to `shape`
+#[wasm_bindgen]
+pub fn var_axis(
+    a: &[f64],
+    shape: &[usize],
+    axis: Option<Vec<i32>>,
+    ddof: f64,
+    keepdims: bool,
+    where_mask: Option<Vec<u8>>,
+) -> Result<Vec<f64>, JsValue> {
+    moments(reduce::var_axis, a, shape, axis, ddof, keepdims, where_mask)
+}
+
+/// `np.std` with the arguments of `var_axis`
+#[wasm_bindgen]
+pub fn std_axis(
+    a: &[f64],
+    shape: &[usize],
+    axis: Option<Vec<i32>>,
+    ddof: f64,
+    keepdims: bool,
+    where_mask: Option<Vec<u8>>,
+) -> Result<Vec<f64>, JsValue> {
+    moments(reduce::std_axis, a, shape, axis, ddof, keepdims, where_mask)
+}
+
+type MomentKernel<T> = fn(
+    &[T],
+    &[usize],
+    Option<&[i64]>,
+    f64,
+    bool,
+    Option<&[bool]>,
+    &Never,
+) -> CoreResult<(Vec<T>, Vec<usize>)>;
+
+fn moments<T: Float>(
+    kernel: MomentKernel<T>,
+    a: &[T],
+    shape: &[usize],
+    axis: Option<Vec<i32>>,
+    ddof: f64,
+    keepdims: bool,
+    where_mask: Option<Vec<u8>>,
+) -> Result<Vec<T>, JsValue> {
+    let axis: Option<Vec<i64>> = axis.map(|axis| axis.into_iter().map(i64::from).collect());
+    let mask: Option<Vec<bool>> = where_mask.map(|mask| mask.into_iter().map(|m| m != 0).collect());
+    kernel(
+        a,
+        shape,
+        axis.as_deref(),
+        ddof,
+        keepdims,
+        mask.as_deref(),
+        &Never,
+    )
+    .map(|(values, _)| values)
+    .map_err(js_error)
 }
 
 // ===== NaN-aware Reductions =====
 
 /// Sum ignoring NaN values
 #[wasm_bindgen]
-pub fn nansum(a: &[f64]) -> f64 {
-    a.iter().filter(|x| !x.is_nan()).sum()
+pub fn nansum(a: &[f64]) -> Result<f64, JsValue> {
+    Ok(simd::nansum(a))
 }
 
 /// Mean ignoring NaN values
 #[wasm_bindgen]
-pub fn nanmean(a: &[f64]) -> f64 {
-    let sum: f64 = a.iter().filter(|x| !x.is_nan()).sum();
-    let count = a.iter().filter(|x| !x.is_nan()).count();
-    if count > 0 {
-        sum / count as f64
-    } else {
-        f64::NAN
-    }
+pub fn nanmean(a: &[f64]) -> Result<f64, JsValue> {
+    Ok(reduce::nanmean(a))
 }
 
 /// Maximum ignoring NaN values
 #[wasm_bindgen]
-pub fn nanmax(a: &[f64]) -> f64 {
-    a.iter()
-        .copied()
-        .filter(|x| !x.is_nan())
-        .fold(f64::NEG_INFINITY, f64::max)
+pub fn nanmax(a: &[f64]) -> Result<f64, JsValue> {
+    reduce::nanmax(a).map_err(js_error)
 }
 
 /// Minimum ignoring NaN values
 #[wasm_bindgen]
-pub fn nanmin(a: &[f64]) -> f64 {
-    a.iter()
-        .copied()
-        .filter(|x| !x.is_nan())
-        .fold(f64::INFINITY, f64::min)
+pub fn nanmin(a: &[f64]) -> Result<f64, JsValue> {
+    reduce::nanmin(a).map_err(js_error)
 }
 
 /// Standard deviation ignoring NaN values
 #[wasm_bindgen]
-pub fn nanstd(a: &[f64]) -> f64 {
-    nanvar(a).sqrt()
+pub fn nanstd(a: &[f64]) -> Result<f64, JsValue> {
+    Ok(reduce::nanstd(a))
 }
 
 /// Variance ignoring NaN values
 #[wasm_bindgen]
-pub fn nanvar(a: &[f64]) -> f64 {
-    let mean_val = nanmean(a);
-    if mean_val.is_nan() {
-        return f64::NAN;
+pub fn nanvar(a: &[f64]) -> Result<f64, JsValue> {
+    Ok(reduce::nanvar(a))
+}
+
+// ===== Cumulative Operations =====
+
+/// `np.cumsum`, `np.cumprod`, `np.nancumsum` or `np.nancumprod`, named by
+/// `op`, along `axis` of a strided view of `a`, or of the flattened view when
+/// `axis` is absent. `strides` are in elements and default to C order.
+/// Returns a C-order typed array of `dtype`, which defaults to NumPy's
+/// accumulator for the input
+#[wasm_bindgen]
+pub fn cumulative(
+    op: &str,
+    a: &[f64],
+    shape: &[usize],
+    strides: Option<Vec<i32>>,
+    offset: usize,
+    axis: Option<i32>,
+    dtype: Option<String>,
+) -> Result<JsValue, JsValue> {
+    accumulate(op, a, shape, strides, offset, axis, dtype)
+        .map(typed_array)
+        .map_err(js_error)
+}
+
+/// `cumulative` over float32 data, which accumulates in float32 by default
+#[wasm_bindgen]
+pub fn cumulative_f32(
+    op: &str,
+    a: &[f32],
+    shape: &[usize],
+    strides: Option<Vec<i32>>,
+    offset: usize,
+    axis: Option<i32>,
+    dtype: Option<String>,
+) -> Result<JsValue, JsValue> {
+    accumulate(op, a, shape, strides, offset, axis, dtype)
+        .map(typed_array)
+        .map_err(js_error)
+}
+
+/// `cumulative` over int32 data, which accumulates in int64 by default
+#[wasm_bindgen]
+pub fn cumulative_i32(
+    op: &str,
+    a: &[i32],
+    shape: &[usize],
+    strides: Option<Vec<i32>>,
+    offset: usize,
+    axis: Option<i32>,
+    dtype: Option<String>,
+) -> Result<JsValue, JsValue> {
+    accumulate(op, a, shape, strides, offset, axis, dtype)
+        .map(typed_array)
+        .map_err(js_error)
+}
+
+fn accumulate<T: Scalar>(
+    op: &str,
+    a: &[T],
+    shape: &[usize],
+    strides: Option<Vec<i32>>,
+    offset: usize,
+    axis: Option<i32>,
+    dtype: Option<String>,
+) -> CoreResult<Accumulated> {
+    let op = CumulativeOp::parse(op)?;
+    let dtype = dtype.as_deref().map(AccumDType::parse).transpose()?;
+    let strides: Vec<isize> = match strides {
+        Some(strides) => strides.into_iter().map(|stride| stride as isize).collect(),
+        None => shape::c_strides(shape),
+    };
+    let view = View::new(a, shape, &strides, offset)?;
+    cumulative::cumulative_as(view, axis.map(i64::from), op, dtype, &Never).map(|(out, _)| out)
+}
+
+/// int64 results come back as a `BigInt64Array`.
+fn typed_array(out: Accumulated) -> JsValue {
+    match out {
+        Accumulated::Float64(out) => Float64Array::from(&out[..]).into(),
+        Accumulated::Float32(out) => Float32Array::from(&out[..]).into(),
+        Accumulated::Int64(out) => BigInt64Array::from(&out[..]).into(),
+        Accumulated::Int32(out) => Int32Array::from(&out[..]).into(),
     }
+}
 
-    let sum_squared_diff: f64 = a
+// ===== Ufunc Methods =====
+// `reduce`, `accumulate`, `outer` and `reduceat` for every binary ufunc,
+// named by its NumPy name (`add`, `maximum`, `floor_divide`, ...)
+
+/// `np.<name>.reduce(a, axis, keepdims, initial)` for an array of `shape`;
+/// returns the flat C-order result. An absent `axis` reduces every axis, so
+/// NumPy's default of axis 0 is passed as `[0]`
+#[wasm_bindgen]
+pub fn ufunc_reduce(
+    name: &str,
+    a: &[f64],
+    shape: &[usize],
+    axis: Option<Vec<i32>>,
+    keepdims: bool,
+    initial: Option<f64>,
+) -> Result<Vec<f64>, JsValue> {
+    ufunc_reduce_impl(name, a, shape, axis, keepdims, initial).map_err(js_error)
+}
+
+/// `np.<name>.accumulate(a, axis)` for an array of `shape`, in C order
+#[wasm_bindgen]
+pub fn ufunc_accumulate(
+    name: &str,
+    a: &[f64],
+    shape: &[usize],
+    axis: i32,
+) -> Result<Vec<f64>, JsValue> {
+    Ufunc::parse(name)
+        .and_then(|ufunc| ufunc.accumulate(a, shape, axis.into(), &Never))
+        .map_err(js_error)
+}
+
+/// `np.<name>.outer(a, b)` of the flattened inputs, of shape
+/// `a.shape + b.shape`
+#[wasm_bindgen]
+pub fn ufunc_outer(name: &str, a: &[f64], b: &[f64]) -> Result<Vec<f64>, JsValue> {
+    outer(Ufunc::parse(name).map_err(js_error)?, a, b)
+}
+
+/// `np.<name>.reduceat(a, indices, axis)` for an array of `shape`; returns the
+/// flat C-order result, with `indices.length` along `axis`
+#[wasm_bindgen]
+pub fn ufunc_reduceat(
+    name: &str,
+    a: &[f64],
+    shape: &[usize],
+    indices: &[i32],
+    axis: i32,
+) -> Result<Vec<f64>, JsValue> {
+    ufunc_reduceat_impl(name, a, shape, indices, axis).map_err(js_error)
+}
+
+/// `np.<name>.at(target, indices, values)` in place for a `target` of
+/// `shape`. `indices` holds one index array per leading axis back to back,
+/// with `lengths[k]` indices in the `k`th; arrays of length 1 broadcast, and
+/// a repeated index is applied every time
+#[wasm_bindgen]
+pub fn ufunc_at(
+    name: &str,
+    target: &mut [f64],
+    shape: &[usize],
+    indices: &[i32],
+    lengths: &[u32],
+    values: &[f64],
+) -> Result<(), JsValue> {
+    ufunc_at_impl(name, target, shape, indices, lengths, values).map_err(js_error)
+}
+
+fn ufunc_reduce_impl<T: Float>(
+    name: &str,
+    a: &[T],
+    shape: &[usize],
+    axis: Option<Vec<i32>>,
+    keepdims: bool,
+    initial: Option<f64>,
+) -> CoreResult<Vec<T>> {
+    let axis: Option<Vec<i64>> = axis.map(|axis| axis.into_iter().map(i64::from).collect());
+    Ufunc::parse(name)?
+        .reduce(a, shape, axis.as_deref(), keepdims, initial, &Never)
+        .map(|(out, _)| out)
+}
+
+fn ufunc_reduceat_impl<T: Float>(
+    name: &str,
+    a: &[T],
+    shape: &[usize],
+    indices: &[i32],
+    axis: i32,
+) -> CoreResult<Vec<T>> {
+    let indices: Vec<i64> = indices.iter().map(|&index| index.into()).collect();
+    Ufunc::parse(name)?
+        .reduceat(a, shape, &indices, axis.into(), &Never)
+        .map(|(out, _)| out)
+}
+
+fn ufunc_at_impl<T: Float>(
+    name: &str,
+    target: &mut [T],
+    shape: &[usize],
+    indices: &[i32],
+    lengths: &[u32],
+    values: &[T],
+) -> CoreResult<()> {
+    let total: u64 = lengths.iter().map(|&len| u64::from(len)).sum();
+    if total != indices.len() as u64 {
+        return Err(NumpyError::value(format!(
+            "index array lengths {:?} do not add up to the {} indices given",
+            lengths,
+            indices.len()
+        )));
+    }
+    let indices: Vec<i64> = indices.iter().map(|&index| index.into()).collect();
+    let mut rest = &indices[..];
+    let tuple: Vec<&[i64]> = lengths
         .iter()
-        .filter(|x| !x.is_nan())
-        .map(|x| {
-            let diff = x - mean_val;
-            diff * diff
+        .map(|&len| {
+            let (head, tail) = rest.split_at(len as usize);
+            rest = tail;
+            head
         })
-        .sum();
+        .collect();
+    Ufunc::parse(name)?.at(target, shape, &tuple, values, &Never)
+}
 
-    let count = a.iter().filter(|x| !x.is_nan()).count();
-    if count > 0 {
-        sum_squared_diff / count as f64
-    } else {
-        f64::NAN
-    }
+fn outer<T: Float>(ufunc: Ufunc, a: &[T], b: &[T]) -> Result<Vec<T>, JsValue> {
+    let len = validate::checked_len(a.len(), b.len()).map_err(js_error)?;
+    with_output(len, |out| ufunc.outer_into(a, b, out))
 }
 
 // ===== Linear Algebra =====
@@ -272,461 +593,837 @@ pub fn nanvar(a: &[f64]) -> f64 {
 /// Matrix multiplication: C = A @ B
 /// A is m×k, B is k×n, result is m×n
 #[wasm_bindgen]
-pub fn matmul(a: &[f64], b: &[f64], m: usize, k: usize, n: usize) -> Vec<f64> {
-    assert_eq!(a.len(), m * k, "A size mismatch");
-    assert_eq!(b.len(), k * n, "B size mismatch");
-
-    let mut result = vec![0.0; m * n];
-
-    // Matrix multiplication: C[i,j] = sum(A[i,k] * B[k,j])
-    // Optimized: iterate in cache-friendly order
-    for i in 0..m {
-        for kk in 0..k {
-            let a_val = a[i * k + kk];
-            for j in 0..n {
-                result[i * n + j] += a_val * b[kk * n + j];
-            }
-        }
-    }
-
-    result
+pub fn matmul(a: &[f64], b: &[f64], m: usize, k: usize, n: usize) -> Result<Vec<f64>, JsValue> {
+    let len = validate::checked_len(m, n).map_err(js_error)?;
+    with_output(len, |out| {
+        gemm::gemm_into(
+            Operand {
+                data: a,
+                trans: false,
+            },
+            Operand {
+                data: b,
+                trans: false,
+            },
+            m,
+            k,
+            n,
+            out,
+        )
+    })
+}
+
+/// Blocked GEMM into a caller-provided `m×n` output: C = op(A) @ op(B)
+/// A transposed operand is passed row-major with its dimensions swapped
+/// (A as k×m, B as n×k)
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn matmul_into(
+    a: &[f64],
+    b: &[f64],
+    m: usize,
+    k: usize,
+    n: usize,
+    trans_a: bool,
+    trans_b: bool,
+    out: &mut [f64],
+) -> Result<(), JsValue> {
+    gemm::gemm_into(
+        Operand {
+            data: a,
+            trans: trans_a,
+        },
+        Operand {
+            data: b,
+            trans: trans_b,
+        },
+        m,
+        k,
+        n,
+        out,
+    )
+    .map_err(js_error)
 }
 
 /// Dot product (inner product) of two 1D arrays
 #[wasm_bindgen]
-pub fn dot(a: &[f64], b: &[f64]) -> f64 {
-    assert_eq!(a.len(), b.len(), "Arrays must have same length");
-
-    a.iter()
-        .zip(b.iter())
-        .map(|(x, y)| x * y)
-        .sum()
+pub fn dot(a: &[f64], b: &[f64]) -> Result<f64, JsValue> {
+    linalg::dot(a, b).map_err(js_error)
 }
 
 // ===== Math Functions =====
 
 /// Element-wise absolute value
 #[wasm_bindgen]
-pub fn abs_array(a: &[f64]) -> Vec<f64> {
-    a.iter().map(|x| x.abs()).collect()
+pub fn abs_array(a: &[f64]) -> Result<Vec<f64>, JsValue> {
+    unary(UnaryOp::Abs, a)
 }
 
 /// Element-wise square root
 #[wasm_bindgen]
-pub fn sqrt_array(a: &[f64]) -> Vec<f64> {
-    a.iter().map(|x| x.sqrt()).collect()
+pub fn sqrt_array(a: &[f64]) -> Result<Vec<f64>, JsValue> {
+    unary(UnaryOp::Sqrt, a)
 }
 
 #[wasm_bindgen]
-pub fn cbrt_array(a: &[f64]) -> Vec<f64> {
-    a.iter().map(|x| x.cbrt()).collect()
+pub fn cbrt_array(a: &[f64]) -> Result<Vec<f64>, JsValue> {
+    unary(UnaryOp::Cbrt, a)
 }
 
 #[wasm_bindgen]
-pub fn square_array(a: &[f64]) -> Vec<f64> {
-    a.iter().map(|x| x * x).collect()
+pub fn square_array(a: &[f64]) -> Result<Vec<f64>, JsValue> {
+    unary(UnaryOp::Square, a)
 }
 
 /// Element-wise exponential (e^x)
 #[wasm_bindgen]
-pub fn exp_array(a: &[f64]) -> Vec<f64> {
-    a.iter().map(|x| x.exp()).collect()
+pub fn exp_array(a: &[f64]) -> Result<Vec<f64>, JsValue> {
+    unary(UnaryOp::Exp, a)
 }
 
 #[wasm_bindgen]
-pub fn exp2_array(a: &[f64]) -> Vec<f64> {
-    a.iter().map(|x| x.exp2()).collect()
+pub fn exp2_array(a: &[f64]) -> Result<Vec<f64>, JsValue> {
+    unary(UnaryOp::Exp2, a)
 }
 
 #[wasm_bindgen]
-pub fn expm1_array(a: &[f64]) -> Vec<f64> {
-    a.iter().map(|x| x.exp_m1()).collect()
+pub fn expm1_array(a: &[f64]) -> Result<Vec<f64>, JsValue> {
+    unary(UnaryOp::Expm1, a)
 }
 
 /// Element-wise natural logarithm
 #[wasm_bindgen]
-pub fn log_array(a: &[f64]) -> Vec<f64> {
-    a.iter().map(|x| x.ln()).collect()
+pub fn log_array(a: &[f64]) -> Result<Vec<f64>, JsValue> {
+    unary(UnaryOp::Log, a)
 }
 
 #[wasm_bindgen]
-pub fn log2_array(a: &[f64]) -> Vec<f64> {
-    a.iter().map(|x| x.log2()).collect()
+pub fn log2_array(a: &[f64]) -> Result<Vec<f64>, JsValue> {
+    unary(UnaryOp::Log2, a)
 }
 
 /// Element-wise base-10 logarithm
 #[wasm_bindgen]
-pub fn log10_array(a: &[f64]) -> Vec<f64> {
-    a.iter().map(|x| x.log10()).collect()
+pub fn log10_array(a: &[f64]) -> Result<Vec<f64>, JsValue> {
+    unary(UnaryOp::Log10, a)
 }
 
 #[wasm_bindgen]
-pub fn log1p_array(a: &[f64]) -> Vec<f64> {
-    a.iter().map(|x| x.ln_1p()).collect()
+pub fn log1p_array(a: &[f64]) -> Result<Vec<f64>, JsValue> {
+    unary(UnaryOp::Log1p, a)
 }
 
 /// Element-wise sine
 #[wasm_bindgen]
-pub fn sin_array(a: &[f64]) -> Vec<f64> {
-    a.iter().map(|x| x.sin()).collect()
+pub fn sin_array(a: &[f64]) -> Result<Vec<f64>, JsValue> {
+    unary(UnaryOp::Sin, a)
 }
 
 /// Element-wise cosine
 #[wasm_bindgen]
-pub fn cos_array(a: &[f64]) -> Vec<f64> {
-    a.iter().map(|x| x.cos()).collect()
+pub fn cos_array(a: &[f64]) -> Result<Vec<f64>, JsValue> {
+    unary(UnaryOp::Cos, a)
 }
 
 /// Element-wise tangent
 #[wasm_bindgen]
-pub fn tan_array(a: &[f64]) -> Vec<f64> {
-    a.iter().map(|x| x.tan()).collect()
+pub fn tan_array(a: &[f64]) -> Result<Vec<f64>, JsValue> {
+    unary(UnaryOp::Tan, a)
 }
 
 #[wasm_bindgen]
-pub fn sinh_array(a: &[f64]) -> Vec<f64> {
-    a.iter().map(|x| x.sinh()).collect()
+pub fn sinh_array(a: &[f64]) -> Result<Vec<f64>, JsValue> {
+    unary(UnaryOp::Sinh, a)
 }
 
 #[wasm_bindgen]
-pub fn cosh_array(a: &[f64]) -> Vec<f64> {
-    a.iter().map(|x| x.cosh()).collect()
+pub fn cosh_array(a: &[f64]) -> Result<Vec<f64>, JsValue> {
+    unary(UnaryOp::Cosh, a)
 }
 
 #[wasm_bindgen]
-pub fn tanh_array(a: &[f64]) -> Vec<f64> {
-    a.iter().map(|x| x.tanh()).collect()
+pub fn tanh_array(a: &[f64]) -> Result<Vec<f64>, JsValue> {
+    unary(UnaryOp::Tanh, a)
 }
 
 #[wasm_bindgen]
-pub fn arcsin_array(a: &[f64]) -> Vec<f64> {
-    a.iter().map(|x| x.asin()).collect()
+pub fn arcsin_array(a: &[f64]) -> Result<Vec<f64>, JsValue> {
+    unary(UnaryOp::Arcsin, a)
 }
 
 #[wasm_bindgen]
-pub fn arccos_array(a: &[f64]) -> Vec<f64> {
-    a.iter().map(|x| x.acos()).collect()
+pub fn arccos_array(a: &[f64]) -> Result<Vec<f64>, JsValue> {
+    unary(UnaryOp::Arccos, a)
 }
 
 #[wasm_bindgen]
-pub fn arctan_array(a: &[f64]) -> Vec<f64> {
-    a.iter().map(|x| x.atan()).collect()
+pub fn arctan_array(a: &[f64]) -> Result<Vec<f64>, JsValue> {
+    unary(UnaryOp::Arctan, a)
 }
 
 #[wasm_bindgen]
-pub fn asinh_array(a: &[f64]) -> Vec<f64> {
-    a.iter().map(|x| x.asinh()).collect()
+pub fn asinh_array(a: &[f64]) -> Result<Vec<f64>, JsValue> {
+    unary(UnaryOp::Arcsinh, a)
 }
 
 #[wasm_bindgen]
-pub fn acosh_array(a: &[f64]) -> Vec<f64> {
-    a.iter().map(|x| x.acosh()).collect()
+pub fn acosh_array(a: &[f64]) -> Result<Vec<f64>, JsValue> {
+    unary(UnaryOp::Arccosh, a)
 }
 
 #[wasm_bindgen]
-pub fn atanh_array(a: &[f64]) -> Vec<f64> {
-    a.iter().map(|x| x.atanh()).collect()
+pub fn atanh_array(a: &[f64]) -> Result<Vec<f64>, JsValue> {
+    unary(UnaryOp::Arctanh, a)
 }
 
 // ===== Rounding Functions =====
 
+/// `np.round`: half to even
+#[wasm_bindgen]
+pub fn round_array(a: &[f64]) -> Result<Vec<f64>, JsValue> {
+    unary(UnaryOp::Round, a)
+}
+
+/// `np.round(a, decimals)`; negative `decimals` round to tens, hundreds, ...
+#[wasm_bindgen]
+pub fn round_decimals(a: &[f64], decimals: i32) -> Result<Vec<f64>, JsValue> {
+    with_output(a.len(), |out| rounding::round_into(a, decimals, out))
+}
+
+#[wasm_bindgen]
+pub fn round_decimals_i32(a: &[i32], decimals: i32) -> Result<Vec<i32>, JsValue> {
+    let mut out = vec![0; a.len()];
+    rounding::round_into(a, decimals, &mut out).map_err(js_error)?;
+    Ok(out)
+}
+
+#[wasm_bindgen]
+pub fn rint_array(a: &[f64]) -> Result<Vec<f64>, JsValue> {
+    unary(UnaryOp::Round, a)
+}
+
+/// `np.fix`: round toward zero
 #[wasm_bindgen]
-pub fn round_array(a: &[f64]) -> Vec<f64> {
-    a.iter().map(|x| x.round()).collect()
+pub fn fix_array(a: &[f64]) -> Result<Vec<f64>, JsValue> {
+    unary(UnaryOp::Trunc, a)
 }
 
 #[wasm_bindgen]
-pub fn floor_array(a: &[f64]) -> Vec<f64> {
-    a.iter().map(|x| x.floor()).collect()
+pub fn floor_array(a: &[f64]) -> Result<Vec<f64>, JsValue> {
+    unary(UnaryOp::Floor, a)
 }
 
 #[wasm_bindgen]
-pub fn ceil_array(a: &[f64]) -> Vec<f64> {
-    a.iter().map(|x| x.ceil()).collect()
+pub fn ceil_array(a: &[f64]) -> Result<Vec<f64>, JsValue> {
+    unary(UnaryOp::Ceil, a)
 }
 
 #[wasm_bindgen]
-pub fn trunc_array(a: &[f64]) -> Vec<f64> {
-    a.iter().map(|x| x.trunc()).collect()
+pub fn trunc_array(a: &[f64]) -> Result<Vec<f64>, JsValue> {
+    unary(UnaryOp::Trunc, a)
 }
 
 // ===== Comparison Functions =====
 
 #[wasm_bindgen]
-pub fn maximum_arrays(a: &[f64], b: &[f64]) -> Vec<f64> {
-    a.iter().zip(b.iter()).map(|(x, y)| x.max(*y)).collect()
+pub fn maximum_arrays(a: &[f64], b: &[f64]) -> Result<Vec<f64>, JsValue> {
+    binary(BinaryOp::Maximum, a, b)
 }
 
 #[wasm_bindgen]
-pub fn minimum_arrays(a: &[f64], b: &[f64]) -> Vec<f64> {
-    a.iter().zip(b.iter()).map(|(x, y)| x.min(*y)).collect()
+pub fn minimum_arrays(a: &[f64], b: &[f64]) -> Result<Vec<f64>, JsValue> {
+    binary(BinaryOp::Minimum, a, b)
 }
 
+/// Elementwise maximum ignoring NaN; NaN only where both are NaN
 #[wasm_bindgen]
-pub fn clip_array(a: &[f64], min: f64, max: f64) -> Vec<f64> {
-    a.iter().map(|x| x.clamp(min, max)).collect()
+pub fn fmax_arrays(a: &[f64], b: &[f64]) -> Result<Vec<f64>, JsValue> {
+    binary(BinaryOp::Fmax, a, b)
+}
+
+/// Elementwise minimum ignoring NaN; NaN only where both are NaN
+#[wasm_bindgen]
+pub fn fmin_arrays(a: &[f64], b: &[f64]) -> Result<Vec<f64>, JsValue> {
+    binary(BinaryOp::Fmin, a, b)
+}
+
+/// `a == b`
+#[wasm_bindgen]
+pub fn equal_arrays(a: &[f64], b: &[f64]) -> Result<Vec<u8>, JsValue> {
+    compare(CompareOp::Equal, a, b)
+}
+
+#[wasm_bindgen]
+pub fn equal_scalar(a: &[f64], scalar: f64) -> Result<Vec<u8>, JsValue> {
+    compare_scalar(CompareOp::Equal, a, scalar)
+}
+
+/// `a != b`; true where either side is NaN
+#[wasm_bindgen]
+pub fn not_equal_arrays(a: &[f64], b: &[f64]) -> Result<Vec<u8>, JsValue> {
+    compare(CompareOp::NotEqual, a, b)
+}
+
+#[wasm_bindgen]
+pub fn not_equal_scalar(a: &[f64], scalar: f64) -> Result<Vec<u8>, JsValue> {
+    compare_scalar(CompareOp::NotEqual, a, scalar)
+}
+
+/// `a < b`
+#[wasm_bindgen]
+pub fn less_arrays(a: &[f64], b: &[f64]) -> Result<Vec<u8>, JsValue> {
+    compare(CompareOp::Less, a, b)
+}
+
+#[wasm_bindgen]
+pub fn less_scalar(a: &[f64], scalar: f64) -> Result<Vec<u8>, JsValue> {
+    compare_scalar(CompareOp::Less, a, scalar)
+}
+
+/// `a <= b`
+#[wasm_bindgen]
+pub fn less_equal_arrays(a: &[f64], b: &[f64]) -> Result<Vec<u8>, JsValue> {
+    compare(CompareOp::LessEqual, a, b)
+}
+
+#[wasm_bindgen]
+pub fn less_equal_scalar(a: &[f64], scalar: f64) -> Result<Vec<u8>, JsValue> {
+    compare_scalar(CompareOp::LessEqual, a, scalar)
+}
+
+/// `a > b`
+#[wasm_bindgen]
+pub fn greater_arrays(a: &[f64], b: &[f64]) -> Result<Vec<u8>, JsValue> {
+    compare(CompareOp::Greater, a, b)
+}
+
+#[wasm_bindgen]
+pub fn greater_scalar(a: &[f64], scalar: f64) -> Result<Vec<u8>, JsValue> {
+    compare_scalar(CompareOp::Greater, a, scalar)
+}
+
+/// `a >= b`
+#[wasm_bindgen]
+pub fn greater_equal_arrays(a: &[f64], b: &[f64]) -> Result<Vec<u8>, JsValue> {
+    compare(CompareOp::GreaterEqual, a, b)
+}
+
+#[wasm_bindgen]
+pub fn greater_equal_scalar(a: &[f64], scalar: f64) -> Result<Vec<u8>, JsValue> {
+    compare_scalar(CompareOp::GreaterEqual, a, scalar)
+}
+
+#[wasm_bindgen]
+pub fn clip_array(a: &[f64], min: f64, max: f64) -> Result<Vec<f64>, JsValue> {
+    with_output(a.len(), |out| elementwise::clip_into(a, min, max, out))
 }
 
 // ===== Miscellaneous Math Functions =====
 
 #[wasm_bindgen]
-pub fn sign_array(a: &[f64]) -> Vec<f64> {
-    a.iter().map(|x| {
-        if *x > 0.0 {
-            1.0
-        } else if *x < 0.0 {
-            -1.0
-        } else {
-            0.0
-        }
-    }).collect()
+pub fn sign_array(a: &[f64]) -> Result<Vec<f64>, JsValue> {
+    unary(UnaryOp::Sign, a)
 }
 
+/// `np.mod` / `np.remainder`: floored, with the sign of the divisor
 #[wasm_bindgen]
-pub fn mod_scalar(a: &[f64], b: f64) -> Vec<f64> {
-    a.iter().map(|x| x % b).collect()
+pub fn mod_scalar(a: &[f64], b: f64) -> Result<Vec<f64>, JsValue> {
+    scalar(BinaryOp::Remainder, a, b)
 }
 
 #[wasm_bindgen]
-pub fn mod_arrays(a: &[f64], b: &[f64]) -> Vec<f64> {
-    a.iter().zip(b.iter()).map(|(x, y)| x % y).collect()
+pub fn mod_arrays(a: &[f64], b: &[f64]) -> Result<Vec<f64>, JsValue> {
+    binary(BinaryOp::Remainder, a, b)
 }
 
 #[wasm_bindgen]
-pub fn fmod_scalar(a: &[f64], b: f64) -> Vec<f64> {
-    a.iter().map(|x| x % b).collect()
+pub fn mod_arrays_i32(a: &[i32], b: &[i32]) -> Result<Vec<i32>, JsValue> {
+    let mut out = vec![0; a.len()];
+    division::remainder_into(a, b, &mut out).map_err(js_error)?;
+    Ok(out)
 }
 
 #[wasm_bindgen]
-pub fn fmod_arrays(a: &[f64], b: &[f64]) -> Vec<f64> {
-    a.iter().zip(b.iter()).map(|(x, y)| x % y).collect()
+pub fn floor_divide_scalar(a: &[f64], b: f64) -> Result<Vec<f64>, JsValue> {
+    scalar(BinaryOp::FloorDivide, a, b)
 }
 
 #[wasm_bindgen]
-pub fn arctan2_arrays(y: &[f64], x: &[f64]) -> Vec<f64> {
-    y.iter().zip(x.iter()).map(|(y_val, x_val)| y_val.atan2(*x_val)).collect()
+pub fn floor_divide_arrays(a: &[f64], b: &[f64]) -> Result<Vec<f64>, JsValue> {
+    binary(BinaryOp::FloorDivide, a, b)
 }
 
 #[wasm_bindgen]
-pub fn deg2rad_array(a: &[f64]) -> Vec<f64> {
-    a.iter().map(|x| x * std::f64::consts::PI / 180.0).collect()
+pub fn floor_divide_arrays_i32(a: &[i32], b: &[i32]) -> Result<Vec<i32>, JsValue> {
+    let mut out = vec![0; a.len()];
+    division::floor_divide_into(a, b, &mut out).map_err(js_error)?;
+    Ok(out)
 }
 
+/// `np.divmod`
+/// Output: all quotients followed by all remainders (length 2n)
 #[wasm_bindgen]
-pub fn rad2deg_array(a: &[f64]) -> Vec<f64> {
-    a.iter().map(|x| x * 180.0 / std::f64::consts::PI).collect()
+pub fn divmod_arrays(a: &[f64], b: &[f64]) -> Result<Vec<f64>, JsValue> {
+    divmod_halves(a, b)
 }
 
+/// `np.divmod` for int32; same layout as `divmod_arrays`
 #[wasm_bindgen]
-pub fn hypot_arrays(a: &[f64], b: &[f64]) -> Vec<f64> {
-    a.iter().zip(b.iter()).map(|(x, y)| x.hypot(*y)).collect()
+pub fn divmod_arrays_i32(a: &[i32], b: &[i32]) -> Result<Vec<i32>, JsValue> {
+    divmod_halves(a, b)
+}
+
+fn divmod_halves<T: Divmod + Default>(a: &[T], b: &[T]) -> Result<Vec<T>, JsValue> {
+    let mut out = vec![T::default(); a.len().saturating_mul(2)];
+    let (quotient, remainder) = out.split_at_mut(a.len());
+    division::divmod_into(a, b, quotient, remainder).map_err(js_error)?;
+    Ok(out)
 }
 
 #[wasm_bindgen]
-pub fn reciprocal_array(a: &[f64]) -> Vec<f64> {
-    a.iter().map(|x| 1.0 / x).collect()
+pub fn fmod_scalar(a: &[f64], b: f64) -> Result<Vec<f64>, JsValue> {
+    scalar(BinaryOp::Fmod, a, b)
 }
 
-// ===== Linear Algebra (Advanced) =====
+#[wasm_bindgen]
+pub fn fmod_arrays(a: &[f64], b: &[f64]) -> Result<Vec<f64>, JsValue> {
+    binary(BinaryOp::Fmod, a, b)
+}
 
-/// Trace of a matrix (sum of diagonal elements)
 #[wasm_bindgen]
-pub fn trace_matrix(a: &[f64], rows: usize, cols: usize) -> f64 {
-    let n = rows.min(cols);
-    let stride = cols + 1;
+pub fn arctan2_arrays(y: &[f64], x: &[f64]) -> Result<Vec<f64>, JsValue> {
+    binary(BinaryOp::Arctan2, y, x)
+}
 
-    let mut sum = 0.0;
-    for i in 0..n {
-        sum += a[i * stride];
-    }
-    sum
+#[wasm_bindgen]
+pub fn deg2rad_array(a: &[f64]) -> Result<Vec<f64>, JsValue> {
+    unary(UnaryOp::Deg2rad, a)
 }
 
-/// Outer product of two vectors
 #[wasm_bindgen]
-pub fn outer_product(a: &[f64], b: &[f64]) -> Vec<f64> {
-    let m = a.len();
-    let n = b.len();
-    let mut result = Vec::with_capacity(m * n);
+pub fn rad2deg_array(a: &[f64]) -> Result<Vec<f64>, JsValue> {
+    unary(UnaryOp::Rad2deg, a)
+}
 
-    for i in 0..m {
-        for j in 0..n {
-            result.push(a[i] * b[j]);
-        }
-    }
-    result
+#[wasm_bindgen]
+pub fn hypot_arrays(a: &[f64], b: &[f64]) -> Result<Vec<f64>, JsValue> {
+    binary(BinaryOp::Hypot, a, b)
+}
+
+#[wasm_bindgen]
+pub fn reciprocal_array(a: &[f64]) -> Result<Vec<f64>, JsValue> {
+    unary(UnaryOp::Reciprocal, a)
+}
+
+// ===== Linear Algebra (Advanced) =====
+
+/// Trace of a matrix (sum of diagonal elements)
+#[wasm_bindgen]
+pub fn trace_matrix(a: &[f64], rows: usize, cols: usize) -> Result<f64, JsValue> {
+    linalg::trace(a, rows, cols).map_err(js_error)
+}
+
+/// Outer product of two vectors: `np.multiply.outer`
+#[wasm_bindgen]
+pub fn outer_product(a: &[f64], b: &[f64]) -> Result<Vec<f64>, JsValue> {
+    outer(Ufunc::parse("multiply").map_err(js_error)?, a, b)
 }
 
 /// Inner product of two vectors
 #[wasm_bindgen]
-pub fn inner_product(a: &[f64], b: &[f64]) -> f64 {
-    assert_eq!(a.len(), b.len());
-    a.iter().zip(b.iter()).map(|(x, y)| x * y).sum()
+pub fn inner_product(a: &[f64], b: &[f64]) -> Result<f64, JsValue> {
+    linalg::dot(a, b).map_err(js_error)
 }
 
 /// Matrix inverse for 2x2 and 3x3 matrices
 #[wasm_bindgen]
-pub fn inv_matrix(a: &[f64], n: usize) -> Result<Vec<f64>, String> {
-    if n == 2 {
-        let det = a[0] * a[3] - a[1] * a[2];
+pub fn inv_matrix(a: &[f64], n: usize) -> Result<Vec<f64>, JsValue> {
+    with_output(a.len(), |out| linalg::inv_into(a, n, out))
+}
+
+/// Determinant for 2x2 and 3x3 matrices
+#[wasm_bindgen]
+pub fn det_matrix(a: &[f64], n: usize) -> Result<f64, JsValue> {
+    linalg::det(a, n).map_err(js_error)
+}
+
+/// Matrix transpose
+#[wasm_bindgen]
+pub fn transpose_matrix(a: &[f64], rows: usize, cols: usize) -> Result<Vec<f64>, JsValue> {
+    with_output(a.len(), |out| linalg::transpose_into(a, rows, cols, out))
+}
+
+// ===== FFT Operations =====
+
+/// Fast Fourier Transform of a real input of any length
+/// Output: interleaved [real, imag] pairs (length 2n)
+#[wasm_bindgen]
+pub fn fft(input: &[f64]) -> Result<Vec<f64>, JsValue> {
+    with_output(input.len().saturating_mul(2), |out| {
+        fft_kernels::fft_into(input, None, false, out, &Never)
+    })
+}
+
+/// Inverse Fast Fourier Transform
+/// Input: interleaved [real, imag] pairs (length 2n)
+/// Output: interleaved [real, imag] pairs (length 2n)
+#[wasm_bindgen]
+pub fn ifft(input: &[f64], n: usize) -> Result<Vec<f64>, JsValue> {
+    validate::matrix_len("Input", input.len(), n, 2).map_err(js_error)?;
+    let real: Vec<f64> = input.iter().step_by(2).copied().collect();
+    let imag: Vec<f64> = input.iter().skip(1).step_by(2).copied().collect();
+    with_output(input.len(), |out| {
+        fft_kernels::fft_into(&real, Some(&imag), true, out, &Never)
+    })
+}
+
+// ===== Float32 Kernels =====
+// `_f32` twins of the exports above for `Float32Array` data. Inputs are not
+// widened on the JS side and results stay float32, as NumPy keeps float32
+// inputs float32; each element is still computed in f64 and rounded once,
+// and reductions accumulate in f64.
+
+macro_rules! f32_unary {
+    ($($name:ident => $op:ident),* $(,)?) => {$(
+        #[wasm_bindgen]
+        pub fn $name(a: &[f32]) -> Result<Vec<f32>, JsValue> {
+            unary(UnaryOp::$op, a)
+        }
+    )*};
+}
 
-        if det.abs() < 1e-10 {
-            return Err("Matrix is singular".to_string());
+macro_rules! f32_binary {
+    ($($name:ident => $op:ident),* $(,)?) => {$(
+        #[wasm_bindgen]
+        pub fn $name(a: &[f32], b: &[f32]) -> Result<Vec<f32>, JsValue> {
+            binary(BinaryOp::$op, a, b)
         }
+    )*};
+}
 
-        Ok(vec![
-            a[3] / det,
-            -a[1] / det,
-            -a[2] / det,
-            a[0] / det,
-        ])
-    } else if n == 3 {
-        let det = a[0] * a[4] * a[8] + a[1] * a[5] * a[6] + a[2] * a[3] * a[7]
-                - a[2] * a[4] * a[6] - a[1] * a[3] * a[8] - a[0] * a[5] * a[7];
-
-        if det.abs() < 1e-10 {
-            return Err("Matrix is singular".to_string());
+macro_rules! f32_scalar {
+    ($($name:ident => $op:ident),* $(,)?) => {$(
+        #[wasm_bindgen]
+        pub fn $name(a: &[f32], scalar: f32) -> Result<Vec<f32>, JsValue> {
+            self::scalar(BinaryOp::$op, a, scalar)
         }
+    )*};
+}
 
-        Ok(vec![
-            (a[4] * a[8] - a[5] * a[7]) / det,
-            (a[2] * a[7] - a[1] * a[8]) / det,
-            (a[1] * a[5] - a[2] * a[4]) / det,
-            (a[5] * a[6] - a[3] * a[8]) / det,
-            (a[0] * a[8] - a[2] * a[6]) / det,
-            (a[2] * a[3] - a[0] * a[5]) / det,
-            (a[3] * a[7] - a[4] * a[6]) / det,
-            (a[1] * a[6] - a[0] * a[7]) / det,
-            (a[0] * a[4] - a[1] * a[3]) / det,
-        ])
-    } else {
-        Err("inv only supports 2x2 and 3x3 matrices".to_string())
-    }
+macro_rules! f32_reduce {
+    ($($name:ident => $kernel:path),* $(,)?) => {$(
+        #[wasm_bindgen]
+        pub fn $name(a: &[f32]) -> Result<f32, JsValue> {
+            Ok($kernel(a))
+        }
+    )*};
+}
+
+f32_binary! {
+    add_arrays_f32 => Add,
+    sub_arrays_f32 => Sub,
+    mul_arrays_f32 => Mul,
+    div_arrays_f32 => Div,
+    maximum_arrays_f32 => Maximum,
+    minimum_arrays_f32 => Minimum,
+    fmax_arrays_f32 => Fmax,
+    fmin_arrays_f32 => Fmin,
+    mod_arrays_f32 => Remainder,
+    fmod_arrays_f32 => Fmod,
+    floor_divide_arrays_f32 => FloorDivide,
+    arctan2_arrays_f32 => Arctan2,
+    hypot_arrays_f32 => Hypot,
+}
+
+f32_scalar! {
+    add_scalar_f32 => Add,
+    sub_scalar_f32 => Sub,
+    mul_scalar_f32 => Mul,
+    div_scalar_f32 => Div,
+    pow_scalar_f32 => Pow,
+    mod_scalar_f32 => Remainder,
+    fmod_scalar_f32 => Fmod,
+    floor_divide_scalar_f32 => FloorDivide,
+}
+
+f32_unary! {
+    abs_array_f32 => Abs,
+    sqrt_array_f32 => Sqrt,
+    cbrt_array_f32 => Cbrt,
+    square_array_f32 => Square,
+    exp_array_f32 => Exp,
+    exp2_array_f32 => Exp2,
+    expm1_array_f32 => Expm1,
+    log_array_f32 => Log,
+    log2_array_f32 => Log2,
+    log10_array_f32 => Log10,
+    log1p_array_f32 => Log1p,
+    sin_array_f32 => Sin,
+    cos_array_f32 => Cos,
+    tan_array_f32 => Tan,
+    sinh_array_f32 => Sinh,
+    cosh_array_f32 => Cosh,
+    tanh_array_f32 => Tanh,
+    arcsin_array_f32 => Arcsin,
+    arccos_array_f32 => Arccos,
+    arctan_array_f32 => Arctan,
+    asinh_array_f32 => Arcsinh,
+    acosh_array_f32 => Arccosh,
+    atanh_array_f32 => Arctanh,
+    round_array_f32 => Round,
+    rint_array_f32 => Round,
+    fix_array_f32 => Trunc,
+    floor_array_f32 => Floor,
+    ceil_array_f32 => Ceil,
+    trunc_array_f32 => Trunc,
+    sign_array_f32 => Sign,
+    deg2rad_array_f32 => Deg2rad,
+    rad2deg_array_f32 => Rad2deg,
+    reciprocal_array_f32 => Reciprocal,
+}
+
+f32_reduce! {
+    sum_f32 => simd::sum,
+    mean_f32 => simd::mean,
+    prod_f32 => simd::prod,
+    std_f32 => reduce::std,
+    variance_f32 => reduce::var,
+    nansum_f32 => simd::nansum,
+    nanmean_f32 => reduce::nanmean,
+    nanstd_f32 => reduce::nanstd,
+    nanvar_f32 => reduce::nanvar,
 }
 
-/// Determinant for 2x2 and 3x3 matrices
 #[wasm_bindgen]
-pub fn det_matrix(a: &[f64], n: usize) -> Result<f64, String> {
-    if n == 2 {
-        Ok(a[0] * a[3] - a[1] * a[2])
-    } else if n == 3 {
-        Ok(a[0] * a[4] * a[8] + a[1] * a[5] * a[6] + a[2] * a[3] * a[7]
-         - a[2] * a[4] * a[6] - a[1] * a[3] * a[8] - a[0] * a[5] * a[7])
-    } else {
-        Err("det only supports 2x2 and 3x3 matrices".to_string())
-    }
+pub fn var_axis_f32(
+    a: &[f32],
+    shape: &[usize],
+    axis: Option<Vec<i32>>,
+    ddof: f64,
+    keepdims: bool,
+    where_mask: Option<Vec<u8>>,
+) -> Result<Vec<f32>, JsValue> {
+    moments(reduce::var_axis, a, shape, axis, ddof, keepdims, where_mask)
 }
 
-/// Matrix transpose
 #[wasm_bindgen]
-pub fn transpose_matrix(a: &[f64], rows: usize, cols: usize) -> Vec<f64> {
-    let mut result = vec![0.0; rows * cols];
+pub fn std_axis_f32(
+    a: &[f32],
+    shape: &[usize],
+    axis: Option<Vec<i32>>,
+    ddof: f64,
+    keepdims: bool,
+    where_mask: Option<Vec<u8>>,
+) -> Result<Vec<f32>, JsValue> {
+    moments(reduce::std_axis, a, shape, axis, ddof, keepdims, where_mask)
+}
 
-    for i in 0..rows {
-        for j in 0..cols {
-            result[j * rows + i] = a[i * cols + j];
-        }
-    }
+#[wasm_bindgen]
+pub fn max_f32(a: &[f32]) -> Result<f32, JsValue> {
+    simd::max(a).map_err(js_error)
+}
 
-    result
+#[wasm_bindgen]
+pub fn min_f32(a: &[f32]) -> Result<f32, JsValue> {
+    simd::min(a).map_err(js_error)
 }
 
-// ===== FFT Operations =====
+#[wasm_bindgen]
+pub fn nanmax_f32(a: &[f32]) -> Result<f32, JsValue> {
+    reduce::nanmax(a).map_err(js_error)
+}
 
-/// Fast Fourier Transform (Cooley-Tukey algorithm)
-/// Input: real-valued array of length n (must be power of 2)
-/// Output: interleaved [real, imag] pairs (length 2n)
 #[wasm_bindgen]
-pub fn fft(input: &[f64]) -> Vec<f64> {
-    let n = input.len();
-    assert!(n > 0 && (n & (n - 1)) == 0, "FFT requires power of 2 length");
+pub fn nanmin_f32(a: &[f32]) -> Result<f32, JsValue> {
+    reduce::nanmin(a).map_err(js_error)
+}
 
-    let mut real: Vec<f64> = input.to_vec();
-    let mut imag: Vec<f64> = vec![0.0; n];
+#[wasm_bindgen]
+pub fn clip_array_f32(a: &[f32], min: f32, max: f32) -> Result<Vec<f32>, JsValue> {
+    with_output(a.len(), |out| elementwise::clip_into(a, min, max, out))
+}
 
-    fft_recursive(&mut real, &mut imag);
+#[wasm_bindgen]
+pub fn argmax_f32(a: &[f32]) -> Result<usize, JsValue> {
+    reduce::argmax(a).map_err(js_error)
+}
 
-    // Interleave real and imaginary parts
-    let mut result = Vec::with_capacity(n * 2);
-    for i in 0..n {
-        result.push(real[i]);
-        result.push(imag[i]);
-    }
+#[wasm_bindgen]
+pub fn argmin_f32(a: &[f32]) -> Result<usize, JsValue> {
+    reduce::argmin(a).map_err(js_error)
+}
 
-    result
+#[wasm_bindgen]
+pub fn argmax_axis_f32(
+    a: &[f32],
+    shape: &[usize],
+    axis: Option<i32>,
+    keepdims: bool,
+) -> Result<Vec<i64>, JsValue> {
+    arg_axis(reduce::argmax_axis, a, shape, axis, keepdims)
 }
 
-/// Inverse Fast Fourier Transform
-/// Input: interleaved [real, imag] pairs (length 2n)
-/// Output: interleaved [real, imag] pairs (length 2n)
 #[wasm_bindgen]
-pub fn ifft(input: &[f64], n: usize) -> Vec<f64> {
-    assert_eq!(input.len(), n * 2, "Input must have length 2n");
-    assert!(n > 0 && (n & (n - 1)) == 0, "IFFT requires power of 2 length");
+pub fn argmin_axis_f32(
+    a: &[f32],
+    shape: &[usize],
+    axis: Option<i32>,
+    keepdims: bool,
+) -> Result<Vec<i64>, JsValue> {
+    arg_axis(reduce::argmin_axis, a, shape, axis, keepdims)
+}
 
-    // Extract real and imaginary parts
-    let mut real: Vec<f64> = (0..n).map(|i| input[i * 2]).collect();
-    let mut imag: Vec<f64> = (0..n).map(|i| input[i * 2 + 1]).collect();
+#[wasm_bindgen]
+pub fn nanargmax_axis_f32(
+    a: &[f32],
+    shape: &[usize],
+    axis: Option<i32>,
+    keepdims: bool,
+) -> Result<Vec<i64>, JsValue> {
+    arg_axis(reduce::nanargmax_axis, a, shape, axis, keepdims)
+}
 
-    // Conjugate
-    for i in 0..n {
-        imag[i] = -imag[i];
-    }
+#[wasm_bindgen]
+pub fn nanargmin_axis_f32(
+    a: &[f32],
+    shape: &[usize],
+    axis: Option<i32>,
+    keepdims: bool,
+) -> Result<Vec<i64>, JsValue> {
+    arg_axis(reduce::nanargmin_axis, a, shape, axis, keepdims)
+}
 
-    // FFT
-    fft_recursive(&mut real, &mut imag);
+#[wasm_bindgen]
+pub fn ufunc_reduce_f32(
+    name: &str,
+    a: &[f32],
+    shape: &[usize],
+    axis: Option<Vec<i32>>,
+    keepdims: bool,
+    initial: Option<f64>,
+) -> Result<Vec<f32>, JsValue> {
+    ufunc_reduce_impl(name, a, shape, axis, keepdims, initial).map_err(js_error)
+}
 
-    // Conjugate and scale
-    let scale = 1.0 / n as f64;
-    let mut result = Vec::with_capacity(n * 2);
-    for i in 0..n {
-        result.push(real[i] * scale);
-        result.push(-imag[i] * scale);
-    }
+#[wasm_bindgen]
+pub fn ufunc_accumulate_f32(
+    name: &str,
+    a: &[f32],
+    shape: &[usize],
+    axis: i32,
+) -> Result<Vec<f32>, JsValue> {
+    Ufunc::parse(name)
+        .and_then(|ufunc| ufunc.accumulate(a, shape, axis.into(), &Never))
+        .map_err(js_error)
+}
 
-    result
+#[wasm_bindgen]
+pub fn ufunc_outer_f32(name: &str, a: &[f32], b: &[f32]) -> Result<Vec<f32>, JsValue> {
+    outer(Ufunc::parse(name).map_err(js_error)?, a, b)
 }
 
-/// Recursive FFT implementation (Cooley-Tukey)
-fn fft_recursive(real: &mut [f64], imag: &mut [f64]) {
-    let n = real.len();
-    if n <= 1 {
-        return;
-    }
+#[wasm_bindgen]
+pub fn ufunc_at_f32(
+    name: &str,
+    target: &mut [f32],
+    shape: &[usize],
+    indices: &[i32],
+    lengths: &[u32],
+    values: &[f32],
+) -> Result<(), JsValue> {
+    ufunc_at_impl(name, target, shape, indices, lengths, values).map_err(js_error)
+}
 
-    // Divide: separate even and odd indices
-    let half_n = n / 2;
-    let mut even_real = vec![0.0; half_n];
-    let mut even_imag = vec![0.0; half_n];
-    let mut odd_real = vec![0.0; half_n];
-    let mut odd_imag = vec![0.0; half_n];
-
-    for i in 0..half_n {
-        even_real[i] = real[i * 2];
-        even_imag[i] = imag[i * 2];
-        odd_real[i] = real[i * 2 + 1];
-        odd_imag[i] = imag[i * 2 + 1];
-    }
+#[wasm_bindgen]
+pub fn ufunc_reduceat_f32(
+    name: &str,
+    a: &[f32],
+    shape: &[usize],
+    indices: &[i32],
+    axis: i32,
+) -> Result<Vec<f32>, JsValue> {
+    ufunc_reduceat_impl(name, a, shape, indices, axis).map_err(js_error)
+}
 
-    // Conquer: recursively compute FFT of even and odd parts
-    fft_recursive(&mut even_real, &mut even_imag);
-    fft_recursive(&mut odd_real, &mut odd_imag);
+/// float32 `matmul`; the GEMM tile accumulates in f32 like `sgemm`
+#[wasm_bindgen]
+pub fn matmul_f32(a: &[f32], b: &[f32], m: usize, k: usize, n: usize) -> Result<Vec<f32>, JsValue> {
+    let len = validate::checked_len(m, n).map_err(js_error)?;
+    with_output(len, |out| {
+        gemm::gemm_into(
+            Operand {
+                data: a,
+                trans: false,
+            },
+            Operand {
+                data: b,
+                trans: false,
+            },
+            m,
+            k,
+            n,
+            out,
+        )
+    })
+}
+
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn matmul_into_f32(
+    a: &[f32],
+    b: &[f32],
+    m: usize,
+    k: usize,
+    n: usize,
+    trans_a: bool,
+    trans_b: bool,
+    out: &mut [f32],
+) -> Result<(), JsValue> {
+    gemm::gemm_into(
+        Operand {
+            data: a,
+            trans: trans_a,
+        },
+        Operand {
+            data: b,
+            trans: trans_b,
+        },
+        m,
+        k,
+        n,
+        out,
+    )
+    .map_err(js_error)
+}
+
+/// float32 `dot`, accumulated in f64 and rounded once like the reductions
+#[wasm_bindgen]
+pub fn dot_f32(a: &[f32], b: &[f32]) -> Result<f32, JsValue> {
+    validate::same_len(a.len(), b.len()).map_err(js_error)?;
+    let dot: f64 = a
+        .iter()
+        .zip(b)
+        .map(|(&x, &y)| f64::from(x) * f64::from(y))
+        .sum();
+    Ok(dot as f32)
+}
 
-    // Combine: butterfly operations with twiddle factors
-    for k in 0..half_n {
-        let angle = -2.0 * std::f64::consts::PI * k as f64 / n as f64;
-        let (sin, cos) = angle.sin_cos();
+/// float32 `fft`: the transform runs in f64 and the complex64 output is
+/// rounded once, interleaved like `fft`
+#[wasm_bindgen]
+pub fn fft_f32(input: &[f32]) -> Result<Vec<f32>, JsValue> {
+    let input: Vec<f64> = input.iter().map(|&x| f64::from(x)).collect();
+    Ok(narrow(fft(&input)?))
+}
 
-        // Complex multiplication: twiddle * odd[k]
-        let t_real = cos * odd_real[k] - sin * odd_imag[k];
-        let t_imag = cos * odd_imag[k] + sin * odd_real[k];
+#[wasm_bindgen]
+pub fn ifft_f32(input: &[f32], n: usize) -> Result<Vec<f32>, JsValue> {
+    let input: Vec<f64> = input.iter().map(|&x| f64::from(x)).collect();
+    Ok(narrow(ifft(&input, n)?))
+}
 
-        // Butterfly operation
-        real[k] = even_real[k] + t_real;
-        imag[k] = even_imag[k] + t_imag;
-        real[k + half_n] = even_real[k] - t_real;
-        imag[k + half_n] = even_imag[k] - t_imag;
-    }
+fn narrow(values: Vec<f64>) -> Vec<f32> {
+    values.into_iter().map(|x| x as f32).collect()
 }
 
 #[cfg(test)]
@@ -737,40 +1434,40 @@ mod tests {
     fn test_add_arrays() {
         let a = vec![1.0, 2.0, 3.0];
         let b = vec![4.0, 5.0, 6.0];
-        let result = add_arrays(&a, &b);
+        let result = add_arrays(&a, &b).unwrap();
         assert_eq!(result, vec![5.0, 7.0, 9.0]);
     }
 
     #[test]
     fn test_add_scalar() {
         let a = vec![1.0, 2.0, 3.0];
-        let result = add_scalar(&a, 10.0);
+        let result = add_scalar(&a, 10.0).unwrap();
         assert_eq!(result, vec![11.0, 12.0, 13.0]);
     }
 
     #[test]
     fn test_sum() {
         let a = vec![1.0, 2.0, 3.0, 4.0];
-        assert_eq!(sum(&a), 10.0);
+        assert_eq!(sum(&a).unwrap(), 10.0);
     }
 
     #[test]
     fn test_mean() {
         let a = vec![2.0, 4.0, 6.0, 8.0];
-        assert_eq!(mean(&a), 5.0);
+        assert_eq!(mean(&a).unwrap(), 5.0);
     }
 
     #[test]
     fn test_variance() {
         let a = vec![2.0, 4.0, 6.0, 8.0];
-        assert_eq!(variance(&a), 5.0);
+        assert_eq!(variance(&a).unwrap(), 5.0);
     }
 
     #[test]
     fn test_matmul_2x2() {
         let a = vec![1.0, 2.0, 3.0, 4.0]; // [[1, 2], [3, 4]]
         let b = vec![5.0, 6.0, 7.0, 8.0]; // [[5, 6], [7, 8]]
-        let result = matmul(&a, &b, 2, 2, 2);
+        let result = matmul(&a, &b, 2, 2, 2).unwrap();
         // Expected: [[19, 22], [43, 50]]
         assert_eq!(result, vec![19.0, 22.0, 43.0, 50.0]);
     }
@@ -779,7 +1476,7 @@ mod tests {
     fn test_dot_product() {
         let a = vec![1.0, 2.0, 3.0];
         let b = vec![4.0, 5.0, 6.0];
-        let result = dot(&a, &b);
+        let result = dot(&a, &b).unwrap();
         assert_eq!(result, 32.0); // 1*4 + 2*5 + 3*6 = 32
     }
 
@@ -787,7 +1484,7 @@ mod tests {
     fn test_fft_basic() {
         // Test with simple input: [1, 0, 0, 0]
         let input = vec![1.0, 0.0, 0.0, 0.0];
-        let result = fft(&input);
+        let result = fft(&input).unwrap();
 
         // FFT of [1, 0, 0, 0] should be [1, 1, 1, 1] (all real, no imaginary)
         assert_eq!(result.len(), 8); // 4 complex numbers = 8 values
@@ -809,13 +1506,18 @@ mod tests {
     fn test_ifft_roundtrip() {
         // Test FFT -> IFFT roundtrip
         let input = vec![1.0, 2.0, 3.0, 4.0];
-        let fft_result = fft(&input);
-        let ifft_result = ifft(&fft_result, 4);
+        let fft_result = fft(&input).unwrap();
+        let ifft_result = ifft(&fft_result, 4).unwrap();
 
         // Check real parts match original
         for i in 0..4 {
-            assert!((ifft_result[i * 2] - input[i]).abs() < 1e-10,
-                    "Mismatch at index {}: {} vs {}", i, ifft_result[i * 2], input[i]);
+            assert!(
+                (ifft_result[i * 2] - input[i]).abs() < 1e-10,
+                "Mismatch at index {}: {} vs {}",
+                i,
+                ifft_result[i * 2],
+                input[i]
+            );
         }
 
         // Imaginary parts should be ~0
@@ -823,4 +1525,406 @@ mod tests {
             assert!(ifft_result[i * 2 + 1].abs() < 1e-10);
         }
     }
+
+    #[test]
+    fn test_argmax_argmin_first_occurrence() {
+        assert_eq!(argmax(&[1.0, 3.0, 3.0, 2.0]).unwrap(), 1);
+        assert_eq!(argmin(&[2.0, 1.0, 1.0]).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_empty_arg_extreme_is_value_error() {
+        let error = reduce::argmax::<f64>(&[]).unwrap_err();
+        assert_eq!(error.message, "attempt to get argmax of an empty sequence");
+    }
+
+    #[test]
+    fn test_length_validation() {
+        let error =
+            elementwise::binary_into(BinaryOp::Add, &[1.0, 2.0, 3.0], &[1.0, 2.0], &mut [0.0; 3])
+                .unwrap_err();
+        assert_eq!(error.expected_shape, Some(vec![3]));
+        assert_eq!(error.actual_shape, Some(vec![2]));
+        assert!(validate::matrix_len("A", 6, 2, 3).is_ok());
+        assert!(validate::matrix_len("A", 5, 2, 3).is_err());
+        assert!(validate::matrix_len("A", 0, usize::MAX, 2).is_err());
+    }
+
+    #[test]
+    fn test_fft_any_length() {
+        let result = fft(&[1.0, 2.0, 3.0]).unwrap();
+        assert!((result[0] - 6.0).abs() < 1e-12);
+        assert!((result[2] + 1.5).abs() < 1e-12);
+        assert!((result[3] - 0.75f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_mod_is_floored_unlike_fmod() {
+        assert_eq!(
+            mod_arrays(&[-7.0, 7.0], &[3.0, -3.0]).unwrap(),
+            vec![2.0, -2.0]
+        );
+        assert_eq!(
+            fmod_arrays(&[-7.0, 7.0], &[3.0, -3.0]).unwrap(),
+            vec![-1.0, 1.0]
+        );
+        assert_eq!(mod_scalar(&[-1.0], 3.0).unwrap(), vec![2.0]);
+        assert_eq!(mod_arrays_i32(&[-7, 5], &[3, 0]).unwrap(), vec![2, 0]);
+    }
+
+    #[test]
+    fn test_floor_divide_and_divmod() {
+        assert_eq!(
+            floor_divide_scalar(&[-7.0, 7.0], 2.0).unwrap(),
+            vec![-4.0, 3.0]
+        );
+        assert_eq!(
+            floor_divide_arrays(&[1.0], &[0.0]).unwrap(),
+            vec![f64::INFINITY]
+        );
+        assert_eq!(
+            floor_divide_arrays_i32(&[i32::MIN], &[-1]).unwrap(),
+            vec![i32::MIN]
+        );
+        assert_eq!(
+            divmod_arrays(&[-10.0, 11.0], &[3.0, 3.0]).unwrap(),
+            vec![-4.0, 3.0, 2.0, 2.0]
+        );
+        assert_eq!(
+            divmod_arrays_i32(&[-10, 11], &[3, -3]).unwrap(),
+            vec![-4, -4, 2, -1]
+        );
+    }
+
+    #[test]
+    fn test_round_half_to_even() {
+        assert_eq!(
+            round_array(&[0.5, 1.5, 2.5, -2.5]).unwrap(),
+            vec![0.0, 2.0, 2.0, -2.0]
+        );
+        assert_eq!(rint_array(&[3.5]).unwrap(), vec![4.0]);
+        assert_eq!(
+            round_decimals(&[0.125, 1250.0], 2).unwrap(),
+            vec![0.12, 1250.0]
+        );
+        assert_eq!(
+            round_decimals(&[1250.0, 1350.0], -2).unwrap(),
+            vec![1200.0, 1400.0]
+        );
+        assert_eq!(
+            round_decimals_i32(&[25, -35, 7], -1).unwrap(),
+            vec![20, -40, 10]
+        );
+        assert_eq!(fix_array(&[-2.7, 2.7]).unwrap(), vec![-2.0, 2.0]);
+    }
+
+    #[test]
+    fn test_norm_orders_and_axis() {
+        assert_eq!(norm(&[3.0, -4.0, 0.0], 0.0).unwrap(), 2.0);
+        assert_eq!(norm(&[3.0, -4.0], f64::NEG_INFINITY).unwrap(), 3.0);
+        assert!((norm(&[1.0, 2.0], 3.0).unwrap() - 9f64.cbrt()).abs() < 1e-15);
+        let a = [1.0, -2.0, 3.0, 4.0, 0.0, -3.0];
+        assert_eq!(
+            linalg_norm(&a, &[2, 3], Some("1".into()), Some(vec![0]), false).unwrap(),
+            vec![5.0, 2.0, 6.0]
+        );
+        assert_eq!(
+            linalg_norm(&a, &[2, 3], Some("-inf".into()), None, false).unwrap(),
+            vec![6.0]
+        );
+        assert_eq!(
+            linalg_norm(&a, &[2, 3], None, Some(vec![-1]), true).unwrap()[1],
+            5.0
+        );
+        assert!(NormOrd::parse("fro").is_ok() && NormOrd::parse("max").is_err());
+    }
+
+    #[test]
+    fn test_var_std_axis_ddof_where() {
+        // [[1, 2, 3], [4, 6, 11]]
+        let a = [1.0, 2.0, 3.0, 4.0, 6.0, 11.0];
+        assert_eq!(
+            var_axis(&a, &[2, 3], Some(vec![1]), 1.0, false, None).unwrap(),
+            vec![1.0, 13.0]
+        );
+        assert_eq!(
+            std_axis(&a, &[2, 3], Some(vec![0]), 0.0, true, None).unwrap(),
+            vec![1.5, 2.0, 4.0]
+        );
+        assert_eq!(
+            var_axis(&a, &[2, 3], None, 0.0, false, Some(vec![1, 1, 0, 0, 1, 1])).unwrap(),
+            vec![15.5]
+        );
+        assert_eq!(
+            var_axis_f32(
+                &[1.0, 2.0, 3.0, 4.0],
+                &[2, 2],
+                Some(vec![-1]),
+                0.0,
+                false,
+                None
+            )
+            .unwrap(),
+            vec![0.25f32, 0.25]
+        );
+    }
+
+    #[test]
+    fn test_arg_axis_returns_int64_indices() {
+        let a = [1.0, 5.0, f64::NAN, 5.0, 2.0, 0.0];
+        assert_eq!(argmax(&a).unwrap(), 2);
+        assert_eq!(
+            argmax_axis(&a, &[2, 3], Some(1), false).unwrap(),
+            vec![2, 0]
+        );
+        assert_eq!(
+            nanargmax_axis(&a, &[2, 3], Some(-1), true).unwrap(),
+            vec![1, 0]
+        );
+        assert_eq!(argmin_axis(&a, &[2, 3], None, false).unwrap(), vec![2]);
+        assert_eq!(
+            nanargmin_axis_f32(&[3.0, f32::NAN, 1.0, 1.0], &[4], None, false).unwrap(),
+            vec![2]
+        );
+        let error =
+            reduce::nanargmax_axis(&[f64::NAN, 1.0], &[2, 1], Some(1), false, &Never).unwrap_err();
+        assert_eq!(error.message, "All-NaN slice encountered");
+    }
+
+    #[test]
+    fn test_cumulative_over_strided_views() {
+        // Column sums of [[1, 2], [3, 4]] read through its transpose.
+        let a = [1.0, 2.0, 3.0, 4.0];
+        let out = accumulate("cumsum", &a, &[2, 2], Some(vec![1, 2]), 0, Some(1), None).unwrap();
+        assert_eq!(out, Accumulated::Float64(vec![1.0, 4.0, 2.0, 6.0]));
+        let out = accumulate(
+            "nancumprod",
+            &[2.0f32, f32::NAN, 3.0],
+            &[3],
+            None,
+            0,
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(out, Accumulated::Float32(vec![2.0, 2.0, 6.0]));
+        let out = accumulate(
+            "cumsum",
+            &[i32::MAX, i32::MAX],
+            &[2],
+            None,
+            0,
+            Some(0),
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            out,
+            Accumulated::Int64(vec![i32::MAX as i64, 2 * i32::MAX as i64])
+        );
+        let out = accumulate(
+            "cumprod",
+            &[3, 4],
+            &[2],
+            None,
+            0,
+            None,
+            Some("float64".into()),
+        )
+        .unwrap();
+        assert_eq!(out, Accumulated::Float64(vec![3.0, 12.0]));
+        assert!(accumulate("cumsum", &a, &[4], Some(vec![2]), 0, None, None).is_err());
+        assert!(accumulate("cummax", &a, &[4], None, 0, None, None).is_err());
+    }
+
+    #[test]
+    fn test_maximum_matches_native_semantics() {
+        let result = maximum_arrays(&[1.0, f64::NAN], &[2.0, 0.0]).unwrap();
+        assert_eq!(result[0], 2.0);
+        assert!(result[1].is_nan());
+    }
+
+    #[test]
+    fn test_max_min_propagate_nan_and_fmax_fmin_skip_it() {
+        let a = [1.0, f64::NAN, 3.0, f64::NAN];
+        let b = [2.0, 0.0, f64::NAN, f64::NAN];
+        assert!(max(&a).unwrap().is_nan() && min_f32(&[1.0, f32::NAN]).unwrap().is_nan());
+        assert_eq!(min(&b[..2]).unwrap(), 0.0);
+        let fmax = fmax_arrays(&a, &b).unwrap();
+        assert_eq!(fmax[..3], [2.0, 0.0, 3.0]);
+        assert!(fmax[3].is_nan());
+        assert_eq!(
+            fmin_arrays_f32(&[1.0, f32::NAN], &[f32::NAN, 2.0]).unwrap(),
+            vec![1.0, 2.0]
+        );
+        let error = simd::max::<f64>(&[]).unwrap_err();
+        assert_eq!(
+            error.message,
+            "zero-size array to reduction operation maximum which has no identity"
+        );
+    }
+
+    #[test]
+    fn test_vector_kernels_cover_lane_tails() {
+        // Lengths 0..9 leave every possible partial f64x2/f32x4 tail.
+        for len in 0..9 {
+            let a: Vec<f64> = (0..len)
+                .map(|i| if i == 3 { f64::NAN } else { i as f64 - 2.5 })
+                .collect();
+            let b: Vec<f64> = (0..len).map(|i| 1.0 - i as f64).collect();
+            let sum: f64 = b.iter().sum();
+            assert_eq!(add_arrays(&a, &b).unwrap().len(), len);
+            assert_eq!(
+                greater_arrays(&a, &b).unwrap(),
+                a.iter()
+                    .zip(&b)
+                    .map(|(x, y)| u8::from(x > y))
+                    .collect::<Vec<_>>()
+            );
+            assert_eq!(
+                not_equal_scalar(&a, -0.5).unwrap(),
+                a.iter().map(|&x| u8::from(x != -0.5)).collect::<Vec<_>>()
+            );
+            assert_eq!(self::sum(&b).unwrap(), sum);
+            assert_eq!(
+                nansum(&a).unwrap(),
+                a.iter().filter(|x| !x.is_nan()).sum::<f64>()
+            );
+            if len > 0 {
+                assert_eq!(max(&b).unwrap(), b[0]);
+                assert_eq!(max(&a).unwrap().is_nan(), len > 3);
+            }
+            assert_eq!(
+                round_array(&a)
+                    .unwrap()
+                    .iter()
+                    .filter(|x| x.is_nan())
+                    .count(),
+                usize::from(len > 3)
+            );
+        }
+        assert!(simd::compare_into(CompareOp::Less, &[1.0], &[1.0, 2.0], &mut [0]).is_err());
+    }
+
+    #[test]
+    fn test_float32_exports_stay_float32() {
+        let a = [0.1f32, 2.0, f32::NAN, -3.5, 1e-3];
+        let b = [0.2f32, 0.5, 1.0, 2.0, 3.0];
+        let add = add_arrays_f32(&a, &b).unwrap();
+        assert_eq!(add[0], 0.1f32 + 0.2f32);
+        assert!(add[2].is_nan());
+        assert_eq!(
+            div_scalar_f32(&[1.0, 2.0], 3.0).unwrap(),
+            vec![1.0f32 / 3.0, 2.0 / 3.0]
+        );
+        assert_eq!(sqrt_array_f32(&[2.0]).unwrap(), vec![2f32.sqrt()]);
+        assert_eq!(round_array_f32(&[2.5, -0.5]).unwrap(), vec![2.0, -0.0]);
+        assert_eq!(
+            nansum_f32(&a).unwrap(),
+            (0.1f64 as f32 as f64 + 2.0 - 3.5 + 1e-3f32 as f64) as f32
+        );
+        assert_eq!(max_f32(&b).unwrap(), 3.0);
+        assert_eq!(argmin_f32(&a).unwrap(), 2);
+        assert_eq!(
+            matmul_f32(&[1.0, 2.0, 3.0, 4.0], &[5.0, 6.0, 7.0, 8.0], 2, 2, 2).unwrap(),
+            vec![19.0, 22.0, 43.0, 50.0]
+        );
+        assert_eq!(dot_f32(&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]).unwrap(), 32.0);
+        // Summed in f32, the 1 would be lost against 1e8.
+        assert_eq!(dot_f32(&[1e8, 1.0, -1e8], &[1.0; 3]).unwrap(), 1.0);
+        let spectrum = fft_f32(&[1.0, 2.0, 3.0, 4.0]).unwrap();
+        assert_eq!(&spectrum[..4], &[10.0, 0.0, -2.0, 2.0]);
+        assert_eq!(
+            ifft_f32(&spectrum, 4)
+                .unwrap()
+                .iter()
+                .step_by(2)
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![1.0, 2.0, 3.0, 4.0]
+        );
+    }
+
+    #[test]
+    fn test_ufunc_methods_by_name() {
+        // [[1, 2, 3],
+        //  [4, 5, 6]]
+        let a = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        assert_eq!(
+            ufunc_reduce("add", &a, &[2, 3], Some(vec![0]), false, None).unwrap(),
+            vec![5.0, 7.0, 9.0]
+        );
+        assert_eq!(
+            ufunc_reduce_impl("maximum", &a, &[2, 3], None, false, Some(7.0)).unwrap(),
+            vec![7.0]
+        );
+        assert!(ufunc_reduce_impl::<f64>("minimum", &[], &[0], None, false, None).is_err());
+        assert_eq!(
+            ufunc_accumulate("multiply", &a, &[2, 3], 1).unwrap(),
+            vec![1.0, 2.0, 6.0, 4.0, 20.0, 120.0]
+        );
+        assert_eq!(
+            ufunc_outer("subtract", &[10.0, 20.0], &[1.0, 2.0]).unwrap(),
+            vec![9.0, 8.0, 19.0, 18.0]
+        );
+        assert_eq!(
+            outer_product(&[1.0, 2.0], &[3.0, 4.0]).unwrap(),
+            vec![3.0, 4.0, 6.0, 8.0]
+        );
+        assert_eq!(
+            ufunc_reduceat("add", &a, &[2, 3], &[0, 2], 1).unwrap(),
+            vec![3.0, 3.0, 9.0, 6.0]
+        );
+        assert!(ufunc_reduceat_impl("add", &a, &[2, 3], &[3], 1).is_err());
+
+        let a32 = [1.5f32, 2.5, 3.5];
+        assert_eq!(
+            ufunc_reduce_f32("add", &a32, &[3], None, false, None).unwrap(),
+            vec![7.5f32]
+        );
+        assert_eq!(
+            ufunc_accumulate_f32("maximum", &a32, &[3], 0).unwrap(),
+            a32.to_vec()
+        );
+    }
+
+    #[test]
+    fn test_ufunc_at_splits_the_index_tuple() {
+        let mut counts = [0.0; 3];
+        ufunc_at("add", &mut counts, &[3], &[0, 2, 2], &[3], &[1.0]).unwrap();
+        assert_eq!(counts, [1.0, 0.0, 2.0]);
+
+        // [[1, 2], [3, 4]] with the tuple ([0, 1, 1], [1, 0, 0])
+        let mut a = [1.0f32, 2.0, 3.0, 4.0];
+        ufunc_at_f32(
+            "multiply",
+            &mut a,
+            &[2, 2],
+            &[0, 1, 1, 1, 0, 0],
+            &[3, 3],
+            &[5.0, 2.0, 10.0],
+        )
+        .unwrap();
+        assert_eq!(a, [1.0, 10.0, 60.0, 4.0]);
+
+        // A length-1 index array broadcasts against the other: ([1], [0, 1, 1])
+        let mut a = [1.0, 2.0, 3.0, 4.0];
+        ufunc_at("add", &mut a, &[2, 2], &[1, 0, 1, 1], &[1, 3], &[1.0]).unwrap();
+        assert_eq!(a, [1.0, 2.0, 4.0, 6.0]);
+
+        let error = ufunc_at_impl("minimum", &mut counts, &[3], &[3], &[1], &[0.0]).unwrap_err();
+        assert_eq!(
+            error.message,
+            "index 3 is out of bounds for axis 0 with size 3"
+        );
+        assert!(ufunc_at_impl("add", &mut counts, &[3], &[0, 1, 2], &[2, 2], &[1.0]).is_err());
+        let error = ufunc_at_impl("add", &mut a, &[2, 2], &[0, 1, 1], &[2, 2], &[1.0]);
+        assert_eq!(
+            error.unwrap_err().message,
+            "index array lengths [2, 2] do not add up to the 3 indices given"
+        );
+        // Lengths 2 and 3 neither match nor broadcast
+        let error = ufunc_at_impl("add", &mut a, &[2, 2], &[0, 1, 0, 1, 1], &[2, 3], &[1.0]);
+        assert!(error.is_err());
+    }
 }
@@ -0,0 +1,225 @@
+//! Arrays that stay in WASM linear memory between calls.
+//!
+//! Plain exports take `&[f64]` and return `Vec<f64>`, so wasm-bindgen copies
+//! every input in and every result out. A `WasmArray` is copied in once;
+//! kernels then run between handles and return new handles, and JS reads
+//! the data through a `Float64Array` view or copies it out at the end.
+//!
+//! Each handle owns its buffer until `free()`. The generated class also
+//! registers a `FinalizationRegistry` when built with weak refs, and
+//! `ResidentArray` in `@sylphx/numpy` adds one on top for older glue.
+
+use js_sys::Float64Array;
+use sylphx_numpy_core::elementwise::{BinaryOp, UnaryOp};
+use sylphx_numpy_core::validate;
+use wasm_bindgen::prelude::*;
+
+use crate::gemm::{self, Operand};
+use crate::{binary, js_error, scalar, simd, unary, with_output};
+
+#[wasm_bindgen]
+pub struct WasmArray {
+    data: Vec<f64>,
+}
+
+impl WasmArray {
+    fn wrap(data: Vec<f64>) -> Self {
+        Self { data }
+    }
+}
+
+#[wasm_bindgen]
+impl WasmArray {
+    /// Zero-filled array of `len` elements
+    #[wasm_bindgen(constructor)]
+    pub fn new(len: usize) -> WasmArray {
+        Self::wrap(vec![0.0; len])
+    }
+
+    /// Copy `values` into WASM memory once
+    #[wasm_bindgen(js_name = fromFloat64Array)]
+    pub fn from_float64_array(values: &[f64]) -> WasmArray {
+        Self::wrap(values.to_vec())
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Zero-copy view onto the array's linear memory. Any allocation in the
+    /// module can grow memory and detach it, so take a fresh view after
+    /// each kernel call instead of holding one.
+    pub fn view(&self) -> Float64Array {
+        // SAFETY: JS gets the view back before this module allocates again,
+        // and the caller is told above not to keep it across calls.
+        unsafe { Float64Array::view(&self.data) }
+    }
+
+    /// Copy the data out into a JS-owned `Float64Array`
+    #[wasm_bindgen(js_name = toFloat64Array)]
+    pub fn to_float64_array(&self) -> Vec<f64> {
+        self.data.clone()
+    }
+
+    /// Overwrite the data in place from an equal-length array
+    pub fn set(&mut self, values: &[f64]) -> Result<(), JsValue> {
+        validate::output_len(values.len(), self.data.len()).map_err(js_error)?;
+        self.data.copy_from_slice(values);
+        Ok(())
+    }
+
+    pub fn sum(&self) -> f64 {
+        simd::sum(&self.data)
+    }
+
+    pub fn mean(&self) -> f64 {
+        simd::mean(&self.data)
+    }
+
+    pub fn max(&self) -> Result<f64, JsValue> {
+        simd::max(&self.data).map_err(js_error)
+    }
+
+    pub fn min(&self) -> Result<f64, JsValue> {
+        simd::min(&self.data).map_err(js_error)
+    }
+
+    /// `self @ other` for `m×k` and `k×n` row-major matrices
+    pub fn matmul(
+        &self,
+        other: &WasmArray,
+        m: usize,
+        k: usize,
+        n: usize,
+    ) -> Result<WasmArray, JsValue> {
+        let len = validate::checked_len(m, n).map_err(js_error)?;
+        with_output(len, |out| {
+            gemm::gemm_into(
+                Operand {
+                    data: &self.data,
+                    trans: false,
+                },
+                Operand {
+                    data: &other.data,
+                    trans: false,
+                },
+                m,
+                k,
+                n,
+                out,
+            )
+        })
+        .map(Self::wrap)
+    }
+
+    /// `out = op(self) @ op(other)` without allocating, like `matmul_into`
+    #[wasm_bindgen(js_name = matmulInto)]
+    #[allow(clippy::too_many_arguments)]
+    pub fn matmul_into(
+        &self,
+        other: &WasmArray,
+        m: usize,
+        k: usize,
+        n: usize,
+        trans_a: bool,
+        trans_b: bool,
+        out: &mut WasmArray,
+    ) -> Result<(), JsValue> {
+        let (a, b) = (
+            Operand {
+                data: &self.data,
+                trans: trans_a,
+            },
+            Operand {
+                data: &other.data,
+                trans: trans_b,
+            },
+        );
+        gemm::gemm_into(a, b, m, k, n, &mut out.data).map_err(js_error)
+    }
+}
+
+#[cfg(feature = "threads")]
+#[wasm_bindgen]
+impl WasmArray {
+    /// Byte address of the data in linear memory, for the worker pool's
+    /// chunk kernels
+    #[wasm_bindgen(getter)]
+    pub fn ptr(&self) -> usize {
+        self.data.as_ptr() as usize
+    }
+}
+
+/// Methods returning a new handle for each elementwise op.
+macro_rules! resident_ops {
+    ($(binary $bname:ident => $bop:ident;)* $(scalar $sname:ident, $sjs:ident => $sop:ident;)* $(unary $uname:ident => $uop:ident;)*) => {
+        #[wasm_bindgen]
+        impl WasmArray {
+            $(
+                pub fn $bname(&self, other: &WasmArray) -> Result<WasmArray, JsValue> {
+                    binary(BinaryOp::$bop, &self.data, &other.data).map(Self::wrap)
+                }
+            )*
+            $(
+                #[wasm_bindgen(js_name = $sjs)]
+                pub fn $sname(&self, value: f64) -> Result<WasmArray, JsValue> {
+                    scalar(BinaryOp::$sop, &self.data, value).map(Self::wrap)
+                }
+            )*
+            $(
+                pub fn $uname(&self) -> Result<WasmArray, JsValue> {
+                    unary(UnaryOp::$uop, &self.data).map(Self::wrap)
+                }
+            )*
+        }
+    };
+}
+
+resident_ops! {
+    binary add => Add;
+    binary sub => Sub;
+    binary mul => Mul;
+    binary div => Div;
+    binary pow => Pow;
+    binary maximum => Maximum;
+    binary minimum => Minimum;
+    binary fmax => Fmax;
+    binary fmin => Fmin;
+    scalar add_scalar, addScalar => Add;
+    scalar sub_scalar, subScalar => Sub;
+    scalar mul_scalar, mulScalar => Mul;
+    scalar div_scalar, divScalar => Div;
+    unary neg => Neg;
+    unary abs => Abs;
+    unary sqrt => Sqrt;
+    unary exp => Exp;
+    unary log => Log;
+    unary sin => Sin;
+    unary cos => Cos;
+    unary tanh => Tanh;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kernels_chain_between_handles() {
+        let a = WasmArray::from_float64_array(&[1.0, 2.0, 3.0, 4.0]);
+        let b = WasmArray::from_float64_array(&[4.0, 3.0, 2.0, 1.0]);
+        let c = a.mul(&b).unwrap().add_scalar(1.0).unwrap();
+        assert_eq!(c.to_float64_array(), vec![5.0, 7.0, 7.0, 5.0]);
+        assert_eq!(c.sum(), 24.0);
+
+        let product = a.matmul(&b, 2, 2, 2).unwrap();
+        assert_eq!(product.to_float64_array(), vec![8.0, 5.0, 20.0, 13.0]);
+        let mut out = WasmArray::new(4);
+        a.matmul_into(&b, 2, 2, 2, true, false, &mut out).unwrap();
+        assert_eq!(out.to_float64_array(), vec![10.0, 6.0, 16.0, 10.0]);
+
+        out.set(&[0.0, 1.0, 0.0, 1.0]).unwrap();
+        assert_eq!(out.sqrt().unwrap().max().unwrap(), 1.0);
+        assert_eq!(out.length(), 4);
+    }
+}
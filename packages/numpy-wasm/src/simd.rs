@@ -0,0 +1,591 @@
+//! Explicit `simd128` arithmetic, comparison and reduction kernels.
+//!
+//! Each entry point validates like its `numpy-core` counterpart, then runs
+//! in `v128` lanes (`f64x2` for `f64`, `f32x4` for `f32`) when the module is
+//! built with `simd128`, and falls back to the core scalar loop otherwise:
+//! in the `wasm-nosimd` artifact, in the native test build, and for ops with
+//! no vector form such as `pow` or `exp`.
+//!
+//! Vector results match the scalar loops bit for bit, except that sums and
+//! products accumulate in lanes and so round in a different order. Both
+//! accumulate in `f64`, so `f32` lanes are widened first.
+
+use sylphx_numpy_core::elementwise::{self, BinaryOp, CompareOp, UnaryOp};
+use sylphx_numpy_core::{reduce, validate, Float, Result};
+
+/// Element types with vector kernels: `f64` and `f32`.
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+pub trait Element: Float + lanes::Lane {}
+
+/// Element types with vector kernels: `f64` and `f32`.
+#[cfg(not(all(target_arch = "wasm32", target_feature = "simd128")))]
+pub trait Element: Float {}
+
+impl Element for f64 {}
+impl Element for f32 {}
+
+/// `out[i] = op(left[i], right[i])`.
+pub fn binary_into<T: Element>(op: BinaryOp, left: &[T], right: &[T], out: &mut [T]) -> Result<()> {
+    validate::same_len(left.len(), right.len())?;
+    validate::output_len(left.len(), out.len())?;
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    if lanes::binary(op, left, right, out) {
+        return Ok(());
+    }
+    elementwise::binary_into(op, left, right, out)
+}
+
+/// `out[i] = op(input[i], scalar)`.
+pub fn scalar_into<T: Element>(op: BinaryOp, input: &[T], scalar: T, out: &mut [T]) -> Result<()> {
+    validate::output_len(input.len(), out.len())?;
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    if lanes::scalar(op, input, scalar, out) {
+        return Ok(());
+    }
+    elementwise::scalar_into(op, input, scalar, out)
+}
+
+/// `out[i] = op(input[i])`.
+pub fn unary_into<T: Element>(op: UnaryOp, input: &[T], out: &mut [T]) -> Result<()> {
+    validate::output_len(input.len(), out.len())?;
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    if lanes::unary(op, input, out) {
+        return Ok(());
+    }
+    elementwise::unary_into(op, input, out)
+}
+
+/// `out[i] = op(left[i], right[i]) as u8`.
+pub fn compare_into<T: Element>(
+    op: CompareOp,
+    left: &[T],
+    right: &[T],
+    out: &mut [u8],
+) -> Result<()> {
+    validate::same_len(left.len(), right.len())?;
+    validate::output_len(left.len(), out.len())?;
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        lanes::compare(op, left, right, out);
+        Ok(())
+    }
+    #[cfg(not(all(target_arch = "wasm32", target_feature = "simd128")))]
+    elementwise::compare_into(op, left, right, out)
+}
+
+/// `out[i] = op(input[i], scalar) as u8`.
+pub fn compare_scalar_into<T: Element>(
+    op: CompareOp,
+    input: &[T],
+    scalar: T,
+    out: &mut [u8],
+) -> Result<()> {
+    validate::output_len(input.len(), out.len())?;
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        lanes::compare_scalar(op, input, scalar, out);
+        Ok(())
+    }
+    #[cfg(not(all(target_arch = "wasm32", target_feature = "simd128")))]
+    elementwise::compare_scalar_into(op, input, scalar, out)
+}
+
+pub fn sum<T: Element>(a: &[T]) -> T {
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    return T::from_f64(lanes::sum(a));
+    #[cfg(not(all(target_arch = "wasm32", target_feature = "simd128")))]
+    reduce::sum(a)
+}
+
+pub fn mean<T: Element>(a: &[T]) -> T {
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    if !a.is_empty() {
+        return T::from_f64(lanes::sum(a) / a.len() as f64);
+    }
+    reduce::mean(a)
+}
+
+pub fn prod<T: Element>(a: &[T]) -> T {
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    return T::from_f64(lanes::prod(a));
+    #[cfg(not(all(target_arch = "wasm32", target_feature = "simd128")))]
+    reduce::prod(a)
+}
+
+pub fn nansum<T: Element>(a: &[T]) -> T {
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    return T::from_f64(lanes::nansum(a));
+    #[cfg(not(all(target_arch = "wasm32", target_feature = "simd128")))]
+    reduce::nansum(a)
+}
+
+/// Propagates NaN and fails on empty input, like `reduce::max`.
+pub fn max<T: Element>(a: &[T]) -> Result<T> {
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    if !a.is_empty() {
+        return Ok(lanes::max(a));
+    }
+    reduce::max(a)
+}
+
+/// Propagates NaN and fails on empty input, like `reduce::min`.
+pub fn min<T: Element>(a: &[T]) -> Result<T> {
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    if !a.is_empty() {
+        return Ok(lanes::min(a));
+    }
+    reduce::min(a)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+mod lanes {
+    use core::arch::wasm32::*;
+
+    use sylphx_numpy_core::elementwise::{BinaryOp, CompareOp, UnaryOp};
+    use sylphx_numpy_core::Float;
+
+    /// A float type packed `LANES` to a `v128`.
+    pub trait Lane: Float {
+        const LANES: usize;
+        const ZERO: Self;
+        const ONE: Self;
+        const INFINITY: Self;
+        const NEG_INFINITY: Self;
+
+        fn splat(x: Self) -> v128;
+        fn add(a: v128, b: v128) -> v128;
+        fn sub(a: v128, b: v128) -> v128;
+        fn mul(a: v128, b: v128) -> v128;
+        fn div(a: v128, b: v128) -> v128;
+        /// `a < b ? b : a`, the scalar `maximum` without the NaN check.
+        fn pmax(a: v128, b: v128) -> v128;
+        /// `b < a ? b : a`, the scalar `minimum` without the NaN check.
+        fn pmin(a: v128, b: v128) -> v128;
+        fn eq_mask(a: v128, b: v128) -> v128;
+        fn ne_mask(a: v128, b: v128) -> v128;
+        fn lt_mask(a: v128, b: v128) -> v128;
+        fn le_mask(a: v128, b: v128) -> v128;
+        fn gt_mask(a: v128, b: v128) -> v128;
+        fn ge_mask(a: v128, b: v128) -> v128;
+        fn neg(a: v128) -> v128;
+        fn abs(a: v128) -> v128;
+        fn sqrt(a: v128) -> v128;
+        fn floor(a: v128) -> v128;
+        fn ceil(a: v128) -> v128;
+        fn trunc(a: v128) -> v128;
+        /// Half to even, as `math::rint`.
+        fn nearest(a: v128) -> v128;
+        /// One bit per lane of a comparison mask, lane 0 lowest.
+        fn bitmask(mask: v128) -> u8;
+        /// Combine the lanes of `v` pairwise with the vector op `f`.
+        fn fold(v: v128, f: impl Fn(v128, v128) -> v128) -> Self;
+        /// Pass the lanes of `v` to `f` as `f64x2` vectors.
+        fn widen(v: v128, f: impl FnMut(v128));
+    }
+
+    impl Lane for f64 {
+        const LANES: usize = 2;
+        const ZERO: Self = 0.0;
+        const ONE: Self = 1.0;
+        const INFINITY: Self = f64::INFINITY;
+        const NEG_INFINITY: Self = f64::NEG_INFINITY;
+
+        fn splat(x: f64) -> v128 {
+            f64x2_splat(x)
+        }
+        fn add(a: v128, b: v128) -> v128 {
+            f64x2_add(a, b)
+        }
+        fn sub(a: v128, b: v128) -> v128 {
+            f64x2_sub(a, b)
+        }
+        fn mul(a: v128, b: v128) -> v128 {
+            f64x2_mul(a, b)
+        }
+        fn div(a: v128, b: v128) -> v128 {
+            f64x2_div(a, b)
+        }
+        fn pmax(a: v128, b: v128) -> v128 {
+            f64x2_pmax(a, b)
+        }
+        fn pmin(a: v128, b: v128) -> v128 {
+            f64x2_pmin(a, b)
+        }
+        fn eq_mask(a: v128, b: v128) -> v128 {
+            f64x2_eq(a, b)
+        }
+        fn ne_mask(a: v128, b: v128) -> v128 {
+            f64x2_ne(a, b)
+        }
+        fn lt_mask(a: v128, b: v128) -> v128 {
+            f64x2_lt(a, b)
+        }
+        fn le_mask(a: v128, b: v128) -> v128 {
+            f64x2_le(a, b)
+        }
+        fn gt_mask(a: v128, b: v128) -> v128 {
+            f64x2_gt(a, b)
+        }
+        fn ge_mask(a: v128, b: v128) -> v128 {
+            f64x2_ge(a, b)
+        }
+        fn neg(a: v128) -> v128 {
+            f64x2_neg(a)
+        }
+        fn abs(a: v128) -> v128 {
+            f64x2_abs(a)
+        }
+        fn sqrt(a: v128) -> v128 {
+            f64x2_sqrt(a)
+        }
+        fn floor(a: v128) -> v128 {
+            f64x2_floor(a)
+        }
+        fn ceil(a: v128) -> v128 {
+            f64x2_ceil(a)
+        }
+        fn trunc(a: v128) -> v128 {
+            f64x2_trunc(a)
+        }
+        fn nearest(a: v128) -> v128 {
+            f64x2_nearest(a)
+        }
+        fn bitmask(mask: v128) -> u8 {
+            i64x2_bitmask(mask)
+        }
+        fn fold(v: v128, f: impl Fn(v128, v128) -> v128) -> f64 {
+            f64x2_extract_lane::<0>(f(v, i64x2_shuffle::<1, 0>(v, v)))
+        }
+        fn widen(v: v128, mut f: impl FnMut(v128)) {
+            f(v)
+        }
+    }
+
+    impl Lane for f32 {
+        const LANES: usize = 4;
+        const ZERO: Self = 0.0;
+        const ONE: Self = 1.0;
+        const INFINITY: Self = f32::INFINITY;
+        const NEG_INFINITY: Self = f32::NEG_INFINITY;
+
+        fn splat(x: f32) -> v128 {
+            f32x4_splat(x)
+        }
+        fn add(a: v128, b: v128) -> v128 {
+            f32x4_add(a, b)
+        }
+        fn sub(a: v128, b: v128) -> v128 {
+            f32x4_sub(a, b)
+        }
+        fn mul(a: v128, b: v128) -> v128 {
+            f32x4_mul(a, b)
+        }
+        fn div(a: v128, b: v128) -> v128 {
+            f32x4_div(a, b)
+        }
+        fn pmax(a: v128, b: v128) -> v128 {
+            f32x4_pmax(a, b)
+        }
+        fn pmin(a: v128, b: v128) -> v128 {
+            f32x4_pmin(a, b)
+        }
+        fn eq_mask(a: v128, b: v128) -> v128 {
+            f32x4_eq(a, b)
+        }
+        fn ne_mask(a: v128, b: v128) -> v128 {
+            f32x4_ne(a, b)
+        }
+        fn lt_mask(a: v128, b: v128) -> v128 {
+            f32x4_lt(a, b)
+        }
+        fn le_mask(a: v128, b: v128) -> v128 {
+            f32x4_le(a, b)
+        }
+        fn gt_mask(a: v128, b: v128) -> v128 {
+            f32x4_gt(a, b)
+        }
+        fn ge_mask(a: v128, b: v128) -> v128 {
+            f32x4_ge(a, b)
+        }
+        fn neg(a: v128) -> v128 {
+            f32x4_neg(a)
+        }
+        fn abs(a: v128) -> v128 {
+            f32x4_abs(a)
+        }
+        fn sqrt(a: v128) -> v128 {
+            f32x4_sqrt(a)
+        }
+        fn floor(a: v128) -> v128 {
+            f32x4_floor(a)
+        }
+        fn ceil(a: v128) -> v128 {
+            f32x4_ceil(a)
+        }
+        fn trunc(a: v128) -> v128 {
+            f32x4_trunc(a)
+        }
+        fn nearest(a: v128) -> v128 {
+            f32x4_nearest(a)
+        }
+        fn bitmask(mask: v128) -> u8 {
+            i32x4_bitmask(mask)
+        }
+        fn fold(v: v128, f: impl Fn(v128, v128) -> v128) -> f32 {
+            let v = f(v, i32x4_shuffle::<2, 3, 0, 1>(v, v));
+            f32x4_extract_lane::<0>(f(v, i32x4_shuffle::<1, 0, 3, 2>(v, v)))
+        }
+        fn widen(v: v128, mut f: impl FnMut(v128)) {
+            f(f64x2_promote_low_f32x4(v));
+            f(f64x2_promote_low_f32x4(i32x4_shuffle::<2, 3, 0, 1>(v, v)));
+        }
+    }
+
+    /// The vector at `x[start..]`, padded with `fill` past the end.
+    fn load<T: Lane>(x: &[T], start: usize, fill: T) -> v128 {
+        let x = &x[start..];
+        if x.len() >= T::LANES {
+            // SAFETY: at least one whole vector is in bounds; `v128_load`
+            // has no alignment requirement.
+            return unsafe { v128_load(x.as_ptr() as *const v128) };
+        }
+        let mut buf = [fill; 4];
+        buf[..x.len()].copy_from_slice(x);
+        // SAFETY: `buf` holds at least 16 bytes.
+        unsafe { v128_load(buf.as_ptr() as *const v128) }
+    }
+
+    /// Write the lanes of `v` that fit into `out`.
+    fn store<T: Lane>(v: v128, out: &mut [T]) {
+        if out.len() >= T::LANES {
+            // SAFETY: as in `load`.
+            return unsafe { v128_store(out.as_mut_ptr() as *mut v128, v) };
+        }
+        let mut buf = [T::ZERO; 4];
+        // SAFETY: `buf` holds at least 16 bytes.
+        unsafe { v128_store(buf.as_mut_ptr() as *mut v128, v) };
+        let len = out.len();
+        out.copy_from_slice(&buf[..len]);
+    }
+
+    fn map1<T: Lane>(input: &[T], out: &mut [T], f: impl Fn(v128) -> v128) {
+        for start in (0..input.len()).step_by(T::LANES) {
+            store(f(load(input, start, T::ZERO)), &mut out[start..]);
+        }
+    }
+
+    fn map2<T: Lane>(left: &[T], right: &[T], out: &mut [T], f: impl Fn(v128, v128) -> v128) {
+        for start in (0..left.len()).step_by(T::LANES) {
+            store(
+                f(load(left, start, T::ZERO), load(right, start, T::ZERO)),
+                &mut out[start..],
+            );
+        }
+    }
+
+    /// `f(left, right)` lanes that are NaN where either operand is.
+    fn nan_or<T: Lane>(left: v128, right: v128, f: impl Fn(v128, v128) -> v128) -> v128 {
+        let nan = v128_or(T::ne_mask(left, left), T::ne_mask(right, right));
+        v128_bitselect(T::add(left, right), f(left, right), nan)
+    }
+
+    /// Expand `$apply` with `$f` bound to the vector form of `$op`, or
+    /// return `false` from the caller when it has none.
+    macro_rules! with_binary {
+        ($op:expr, $t:ty, $f:ident => $apply:expr) => {
+            match $op {
+                BinaryOp::Add => {
+                    let $f = <$t>::add;
+                    $apply
+                }
+                BinaryOp::Sub => {
+                    let $f = <$t>::sub;
+                    $apply
+                }
+                BinaryOp::Mul => {
+                    let $f = <$t>::mul;
+                    $apply
+                }
+                BinaryOp::Div => {
+                    let $f = <$t>::div;
+                    $apply
+                }
+                BinaryOp::Maximum => {
+                    let $f = |x: v128, y: v128| nan_or::<$t>(x, y, <$t>::pmax);
+                    $apply
+                }
+                BinaryOp::Minimum => {
+                    let $f = |x: v128, y: v128| nan_or::<$t>(x, y, <$t>::pmin);
+                    $apply
+                }
+                // `pmax(x, y)` is `y` only when `x < y`, so it already takes
+                // `x` over a NaN `y`; take `y` over a NaN `x`.
+                BinaryOp::Fmax => {
+                    let $f =
+                        |x: v128, y: v128| v128_bitselect(y, <$t>::pmax(x, y), <$t>::ne_mask(x, x));
+                    $apply
+                }
+                BinaryOp::Fmin => {
+                    let $f =
+                        |x: v128, y: v128| v128_bitselect(y, <$t>::pmin(x, y), <$t>::ne_mask(x, x));
+                    $apply
+                }
+                _ => return false,
+            }
+        };
+    }
+
+    macro_rules! with_compare {
+        ($op:expr, $t:ty, $f:ident => $apply:expr) => {
+            match $op {
+                CompareOp::Equal => {
+                    let $f = <$t>::eq_mask;
+                    $apply
+                }
+                CompareOp::NotEqual => {
+                    let $f = <$t>::ne_mask;
+                    $apply
+                }
+                CompareOp::Less => {
+                    let $f = <$t>::lt_mask;
+                    $apply
+                }
+                CompareOp::LessEqual => {
+                    let $f = <$t>::le_mask;
+                    $apply
+                }
+                CompareOp::Greater => {
+                    let $f = <$t>::gt_mask;
+                    $apply
+                }
+                CompareOp::GreaterEqual => {
+                    let $f = <$t>::ge_mask;
+                    $apply
+                }
+            }
+        };
+    }
+
+    pub fn binary<T: Lane>(op: BinaryOp, left: &[T], right: &[T], out: &mut [T]) -> bool {
+        with_binary!(op, T, f => map2(left, right, out, f));
+        true
+    }
+
+    pub fn scalar<T: Lane>(op: BinaryOp, input: &[T], scalar: T, out: &mut [T]) -> bool {
+        let scalar = T::splat(scalar);
+        with_binary!(op, T, f => map1(input, out, |x| f(x, scalar)));
+        true
+    }
+
+    pub fn unary<T: Lane>(op: UnaryOp, input: &[T], out: &mut [T]) -> bool {
+        match op {
+            UnaryOp::Neg => map1(input, out, T::neg),
+            UnaryOp::Abs => map1(input, out, T::abs),
+            UnaryOp::Sqrt => map1(input, out, T::sqrt),
+            UnaryOp::Square => map1(input, out, |x| T::mul(x, x)),
+            UnaryOp::Reciprocal => map1(input, out, |x| T::div(T::splat(T::ONE), x)),
+            UnaryOp::Round => map1(input, out, T::nearest),
+            UnaryOp::Floor => map1(input, out, T::floor),
+            UnaryOp::Ceil => map1(input, out, T::ceil),
+            UnaryOp::Trunc => map1(input, out, T::trunc),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Spread a lane mask into one `0`/`1` byte per lane.
+    fn store_mask<T: Lane>(mask: v128, out: &mut [u8]) {
+        let bits = T::bitmask(mask);
+        for (lane, slot) in out.iter_mut().take(T::LANES).enumerate() {
+            *slot = (bits >> lane) & 1;
+        }
+    }
+
+    pub fn compare<T: Lane>(op: CompareOp, left: &[T], right: &[T], out: &mut [u8]) {
+        with_compare!(op, T, f => {
+            for start in (0..left.len()).step_by(T::LANES) {
+                store_mask::<T>(f(load(left, start, T::ZERO), load(right, start, T::ZERO)), &mut out[start..]);
+            }
+        });
+    }
+
+    pub fn compare_scalar<T: Lane>(op: CompareOp, input: &[T], scalar: T, out: &mut [u8]) {
+        let scalar = T::splat(scalar);
+        with_compare!(op, T, f => {
+            for start in (0..input.len()).step_by(T::LANES) {
+                store_mask::<T>(f(load(input, start, T::ZERO), scalar), &mut out[start..]);
+            }
+        });
+    }
+
+    /// Fold `a` with `step` into two accumulators started at `identity`,
+    /// which also pads the tail, then merge them and their lanes with `merge`.
+    fn fold<T: Lane>(
+        a: &[T],
+        identity: T,
+        step: impl Fn(v128, v128) -> v128,
+        merge: impl Fn(v128, v128) -> v128,
+    ) -> T {
+        let mut acc = [T::splat(identity); 2];
+        let mut start = 0;
+        while start + 2 * T::LANES <= a.len() {
+            acc[0] = step(acc[0], load(a, start, identity));
+            acc[1] = step(acc[1], load(a, start + T::LANES, identity));
+            start += 2 * T::LANES;
+        }
+        while start < a.len() {
+            acc[0] = step(acc[0], load(a, start, identity));
+            start += T::LANES;
+        }
+        T::fold(merge(acc[0], acc[1]), merge)
+    }
+
+    /// `fold` in `f64x2` accumulators, widening `f32` lanes first, as the
+    /// scalar reductions accumulate in `f64`.
+    fn fold_f64<T: Lane>(
+        a: &[T],
+        identity: T,
+        step: impl Fn(v128, v128) -> v128,
+        merge: impl Fn(v128, v128) -> v128,
+    ) -> f64 {
+        let mut acc = [f64x2_splat(identity.to_f64()); 2];
+        let mut turn = 0;
+        for start in (0..a.len()).step_by(T::LANES) {
+            T::widen(load(a, start, identity), |v| {
+                acc[turn] = step(acc[turn], v);
+                turn ^= 1;
+            });
+        }
+        f64::fold(merge(acc[0], acc[1]), merge)
+    }
+
+    pub fn sum<T: Lane>(a: &[T]) -> f64 {
+        fold_f64(a, T::ZERO, |a, b| f64x2_add(a, b), |a, b| f64x2_add(a, b))
+    }
+
+    pub fn prod<T: Lane>(a: &[T]) -> f64 {
+        fold_f64(a, T::ONE, |a, b| f64x2_mul(a, b), |a, b| f64x2_mul(a, b))
+    }
+
+    pub fn nansum<T: Lane>(a: &[T]) -> f64 {
+        let zero = f64x2_splat(0.0);
+        fold_f64(
+            a,
+            T::ZERO,
+            |acc, x| f64x2_add(acc, v128_bitselect(zero, x, f64x2_ne(x, x))),
+            |a, b| f64x2_add(a, b),
+        )
+    }
+
+    /// A NaN lane stays NaN through every later step and merge, so any NaN
+    /// input makes the result NaN.
+    pub fn max<T: Lane>(a: &[T]) -> T {
+        let step = |acc, x| nan_or::<T>(acc, x, T::pmax);
+        fold(a, T::NEG_INFINITY, step, step)
+    }
+
+    pub fn min<T: Lane>(a: &[T]) -> T {
+        let step = |acc, x| nan_or::<T>(acc, x, T::pmin);
+        fold(a, T::INFINITY, step, step)
+    }
+}
@@ -0,0 +1,253 @@
+//! Chunk kernels for the threaded build (`--features threads`).
+//!
+//! Every worker instantiates this module on the same shared memory. The main
+//! thread keeps operands in `WasmArray`s, hands their addresses
+//! (`WasmArray.ptr`) to the workers and splits the index space into disjoint
+//! chunks, so each worker reads the shared inputs and writes only its own
+//! output range. Reductions return one partial per chunk for JS to combine.
+
+use std::slice;
+
+use sylphx_numpy_core::elementwise::{BinaryOp, UnaryOp};
+use sylphx_numpy_core::{validate, NumpyError};
+use wasm_bindgen::prelude::*;
+
+use crate::gemm::{self, Operand};
+use crate::{js_error, simd};
+
+/// Elements `start..end` of the `f64` array at byte address `ptr`.
+///
+/// The pool only passes addresses of live `WasmArray`s with `end` within
+/// their length, and keeps them alive until every chunk has finished.
+fn chunk<'a>(ptr: usize, start: usize, end: usize) -> &'a [f64] {
+    // SAFETY: see above; inputs are only read while the chunk runs.
+    unsafe { slice::from_raw_parts((ptr as *const f64).add(start), end.saturating_sub(start)) }
+}
+
+fn chunk_mut<'a>(ptr: usize, start: usize, end: usize) -> &'a mut [f64] {
+    // SAFETY: as `chunk`, and no two chunks of one call overlap, so this is
+    // the only live reference to the output range.
+    unsafe { slice::from_raw_parts_mut((ptr as *mut f64).add(start), end.saturating_sub(start)) }
+}
+
+fn binary_op(name: &str) -> Result<BinaryOp, JsValue> {
+    Ok(match name {
+        "add" => BinaryOp::Add,
+        "sub" => BinaryOp::Sub,
+        "mul" => BinaryOp::Mul,
+        "div" => BinaryOp::Div,
+        "pow" => BinaryOp::Pow,
+        "maximum" => BinaryOp::Maximum,
+        "minimum" => BinaryOp::Minimum,
+        "fmax" => BinaryOp::Fmax,
+        "fmin" => BinaryOp::Fmin,
+        _ => {
+            return Err(js_error(NumpyError::value(format!(
+                "Unknown binary op '{}'",
+                name
+            ))))
+        }
+    })
+}
+
+fn unary_op(name: &str) -> Result<UnaryOp, JsValue> {
+    Ok(match name {
+        "neg" => UnaryOp::Neg,
+        "abs" => UnaryOp::Abs,
+        "sqrt" => UnaryOp::Sqrt,
+        "exp" => UnaryOp::Exp,
+        "log" => UnaryOp::Log,
+        "sin" => UnaryOp::Sin,
+        "cos" => UnaryOp::Cos,
+        "tanh" => UnaryOp::Tanh,
+        _ => {
+            return Err(js_error(NumpyError::value(format!(
+                "Unknown unary op '{}'",
+                name
+            ))))
+        }
+    })
+}
+
+/// The shared linear memory, for workers to instantiate the module on
+#[wasm_bindgen]
+pub fn shared_memory() -> JsValue {
+    wasm_bindgen::memory()
+}
+
+/// Check elementwise operand lengths on the main thread before dispatching,
+/// since chunk kernels trust the lengths they are given
+#[wasm_bindgen]
+pub fn par_check_same_len(left: usize, right: usize) -> Result<(), JsValue> {
+    validate::same_len(left, right).map_err(js_error)
+}
+
+/// Check that a reduction with no identity, such as `maximum`, has elements
+/// to reduce before dispatching, since every chunk of a non-empty array is
+/// non-empty
+#[wasm_bindgen]
+pub fn par_check_nonempty(len: usize, reduction: &str) -> Result<(), JsValue> {
+    if len == 0 {
+        return Err(js_error(NumpyError::no_identity(reduction)));
+    }
+    Ok(())
+}
+
+/// Check GEMM operand lengths on the main thread before dispatching
+#[wasm_bindgen]
+pub fn par_check_gemm(
+    a_len: usize,
+    b_len: usize,
+    m: usize,
+    k: usize,
+    n: usize,
+    trans_a: bool,
+    trans_b: bool,
+) -> Result<(), JsValue> {
+    let (a_rows, a_cols) = if trans_a { (k, m) } else { (m, k) };
+    let (b_rows, b_cols) = if trans_b { (n, k) } else { (k, n) };
+    validate::matrix_len("A", a_len, a_rows, a_cols).map_err(js_error)?;
+    validate::matrix_len("B", b_len, b_rows, b_cols).map_err(js_error)
+}
+
+/// `out[start..end] = op(a[start..end], b[start..end])`
+#[wasm_bindgen]
+pub fn par_binary_chunk(
+    op: &str,
+    a: usize,
+    b: usize,
+    out: usize,
+    start: usize,
+    end: usize,
+) -> Result<(), JsValue> {
+    let op = binary_op(op)?;
+    simd::binary_into(
+        op,
+        chunk(a, start, end),
+        chunk(b, start, end),
+        chunk_mut(out, start, end),
+    )
+    .map_err(js_error)
+}
+
+/// `out[start..end] = op(a[start..end], value)`
+#[wasm_bindgen]
+pub fn par_scalar_chunk(
+    op: &str,
+    a: usize,
+    value: f64,
+    out: usize,
+    start: usize,
+    end: usize,
+) -> Result<(), JsValue> {
+    let op = binary_op(op)?;
+    simd::scalar_into(op, chunk(a, start, end), value, chunk_mut(out, start, end)).map_err(js_error)
+}
+
+/// `out[start..end] = op(a[start..end])`
+#[wasm_bindgen]
+pub fn par_unary_chunk(
+    op: &str,
+    a: usize,
+    out: usize,
+    start: usize,
+    end: usize,
+) -> Result<(), JsValue> {
+    let op = unary_op(op)?;
+    simd::unary_into(op, chunk(a, start, end), chunk_mut(out, start, end)).map_err(js_error)
+}
+
+/// Partial sum of `a[start..end]`
+#[wasm_bindgen]
+pub fn par_sum_chunk(a: usize, start: usize, end: usize) -> f64 {
+    simd::sum(chunk(a, start, end))
+}
+
+/// Partial max of `a[start..end]`; NaN if the chunk holds one
+#[wasm_bindgen]
+pub fn par_max_chunk(a: usize, start: usize, end: usize) -> Result<f64, JsValue> {
+    simd::max(chunk(a, start, end)).map_err(js_error)
+}
+
+/// Partial min of `a[start..end]`; NaN if the chunk holds one
+#[wasm_bindgen]
+pub fn par_min_chunk(a: usize, start: usize, end: usize) -> Result<f64, JsValue> {
+    simd::min(chunk(a, start, end)).map_err(js_error)
+}
+
+/// Output rows `row0..row1` of `op(A) @ op(B)`, written into the full `m×n`
+/// output at `out`
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn par_gemm_rows(
+    a: usize,
+    b: usize,
+    out: usize,
+    m: usize,
+    k: usize,
+    n: usize,
+    trans_a: bool,
+    trans_b: bool,
+    row0: usize,
+    row1: usize,
+) -> Result<(), JsValue> {
+    let row1 = row1.min(m);
+    let a = Operand {
+        data: chunk(a, 0, m * k),
+        trans: trans_a,
+    };
+    let b = Operand {
+        data: chunk(b, 0, k * n),
+        trans: trans_b,
+    };
+    gemm::gemm_rows_into(
+        a,
+        b,
+        m,
+        k,
+        n,
+        row0..row1,
+        chunk_mut(out, row0 * n, row1 * n),
+    )
+    .map_err(js_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn addr(values: &[f64]) -> usize {
+        values.as_ptr() as usize
+    }
+
+    #[test]
+    fn test_chunks_cover_the_whole_array() {
+        let a: Vec<f64> = (0..10).map(f64::from).collect();
+        let b = vec![2.0; 10];
+        let mut out = vec![0.0; 10];
+        let out_addr = out.as_mut_ptr() as usize;
+        for (start, end) in [(0, 4), (4, 8), (8, 10)] {
+            par_binary_chunk("mul", addr(&a), addr(&b), out_addr, start, end).unwrap();
+        }
+        assert_eq!(out, a.iter().map(|x| x * 2.0).collect::<Vec<_>>());
+
+        let partials: f64 = [(0, 3), (3, 10)]
+            .iter()
+            .map(|&(s, e)| par_sum_chunk(addr(&a), s, e))
+            .sum();
+        assert_eq!(partials, 45.0);
+        assert_eq!(par_max_chunk(addr(&a), 2, 5).unwrap(), 4.0);
+        assert_eq!(par_min_chunk(addr(&a), 2, 5).unwrap(), 2.0);
+    }
+
+    #[test]
+    fn test_gemm_rows_split_across_chunks() {
+        let a = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let b = [1.0, 0.0, 0.0, 1.0];
+        let mut out = vec![0.0; 6];
+        let out_addr = out.as_mut_ptr() as usize;
+        par_gemm_rows(addr(&a), addr(&b), out_addr, 3, 2, 2, false, false, 0, 2).unwrap();
+        par_gemm_rows(addr(&a), addr(&b), out_addr, 3, 2, 2, false, false, 2, 3).unwrap();
+        assert_eq!(out, a.to_vec());
+    }
+}
@@ -2,3 +2,6 @@
 
 export { getBackend, initWASM, initNativeBLAS, getBackendInfo } from './manager'
 export type { Backend, BackendInit } from './types'
+export { ResidentArray } from './wasm-array'
+export { WasmThreadPool, threadsAvailable } from './wasm-threads'
+export type { ThreadPoolOptions } from './wasm-threads'
@@ -3,6 +3,31 @@
 
 import type { DType, NDArrayData } from '../core/types'
 
+/** Arg-reductions that pick one element per lane */
+export type ArgReduction = 'argmax' | 'argmin' | 'nanargmax' | 'nanargmin'
+
+/** Running reductions along one axis */
+export type CumulativeOp = 'cumsum' | 'cumprod' | 'nancumsum' | 'nancumprod'
+
+/** Binary ufuncs under their NumPy names, each with reduce, accumulate, outer, reduceat and at */
+export type UfuncName =
+  | 'add'
+  | 'subtract'
+  | 'multiply'
+  | 'divide'
+  | 'true_divide'
+  | 'power'
+  | 'maximum'
+  | 'minimum'
+  | 'fmax'
+  | 'fmin'
+  | 'fmod'
+  | 'remainder'
+  | 'mod'
+  | 'floor_divide'
+  | 'arctan2'
+  | 'hypot'
+
 /**
  * Backend interface - implemented by both WASM and TS
  */
@@ -32,6 +57,8 @@ export interface Backend {
   prod(a: NDArrayData): number
   argmax(a: NDArrayData): number
   argmin(a: NDArrayData): number
+  // Indices along `axis`, or the flat index as a 0-d result when `axis` is null
+  argAxis(op: ArgReduction, a: NDArrayData, axis: number | null, keepdims: boolean): NDArrayData
 
   // NaN-aware reductions
   nansum(a: NDArrayData): number
@@ -41,6 +68,33 @@ export interface Backend {
   nanstd(a: NDArrayData): number
   nanvar(a: NDArrayData): number
 
+  // Cumulative operations, in the input dtype; a null axis runs over the flattened array
+  cumulative(op: CumulativeOp, a: NDArrayData, axis: number | null): NDArrayData
+
+  // Ufunc methods over C-contiguous data, in the input dtype; a null axis reduces every axis
+  ufuncReduce(
+    name: UfuncName,
+    a: NDArrayData,
+    axis: readonly number[] | null,
+    keepdims: boolean,
+    initial?: number,
+  ): NDArrayData
+  ufuncAccumulate(name: UfuncName, a: NDArrayData, axis: number): NDArrayData
+  ufuncOuter(name: UfuncName, a: NDArrayData, b: NDArrayData): NDArrayData
+  ufuncReduceat(
+    name: UfuncName,
+    a: NDArrayData,
+    indices: readonly number[],
+    axis: number,
+  ): NDArrayData
+  // In place and unbuffered, so a repeated index is applied every time it appears
+  ufuncAt(
+    name: UfuncName,
+    target: NDArrayData,
+    indices: readonly (readonly number[])[],
+    values: NDArrayData | number,
+  ): void
+
   // Linear algebra operations
   matmul(a: NDArrayData, b: NDArrayData): NDArrayData
   matmulInto?(a: NDArrayData, b: NDArrayData, out: NDArrayData): NDArrayData
@@ -1,9 +1,71 @@
 // ===== TypeScript Backend =====
 // Pure TS implementation (fallback and reference)
 
+import { IndexError, ValueError } from '../core/errors'
 import type { DType, NDArrayData } from '../core/types'
-import { broadcastShapes, broadcastTo, computeStrides, createTypedArray } from '../core/utils'
-import type { Backend } from './types'
+import {
+  axisLanes,
+  broadcastShapes,
+  broadcastTo,
+  computeSize,
+  computeStrides,
+  createTypedArray,
+  dimOffsets,
+  normalizeAxis,
+  reducedShape,
+} from '../core/utils'
+import type { ArgReduction, Backend, CumulativeOp, UfuncName } from './types'
+
+type UfuncKernel = {
+  // Canonical NumPy name, for error messages
+  name: string
+  op: (x: number, y: number) => number
+  // What reduce returns for an empty lane
+  identity?: number
+  // Associative and commutative, so it may reduce several axes at once
+  reorderable: boolean
+}
+
+const UFUNCS: Record<UfuncName, UfuncKernel> = {
+  add: { name: 'add', op: (x, y) => x + y, identity: 0, reorderable: true },
+  subtract: { name: 'subtract', op: (x, y) => x - y, reorderable: false },
+  multiply: { name: 'multiply', op: (x, y) => x * y, identity: 1, reorderable: true },
+  divide: { name: 'divide', op: (x, y) => x / y, reorderable: false },
+  true_divide: { name: 'divide', op: (x, y) => x / y, reorderable: false },
+  power: { name: 'power', op: (x, y) => x ** y, reorderable: false },
+  maximum: { name: 'maximum', op: Math.max, reorderable: true },
+  minimum: { name: 'minimum', op: Math.min, reorderable: true },
+  fmax: {
+    name: 'fmax',
+    op: (x, y) => (Number.isNaN(y) ? x : Number.isNaN(x) ? y : Math.max(x, y)),
+    reorderable: true,
+  },
+  fmin: {
+    name: 'fmin',
+    op: (x, y) => (Number.isNaN(y) ? x : Number.isNaN(x) ? y : Math.min(x, y)),
+    reorderable: true,
+  },
+  fmod: { name: 'fmod', op: (x, y) => x % y, reorderable: false },
+  remainder: { name: 'remainder', op: floorMod, reorderable: false },
+  mod: { name: 'remainder', op: floorMod, reorderable: false },
+  floor_divide: { name: 'floor_divide', op: (x, y) => Math.floor(x / y), reorderable: false },
+  arctan2: { name: 'arctan2', op: Math.atan2, reorderable: false },
+  hypot: { name: 'hypot', op: Math.hypot, identity: 0, reorderable: true },
+}
+
+// Python's %, which takes the sign of the divisor
+function floorMod(x: number, y: number): number {
+  const r = x % y
+  return r !== 0 && r < 0 !== y < 0 ? r + y : r
+}
+
+function ufuncKernel(name: UfuncName): UfuncKernel {
+  const ufunc = UFUNCS[name]
+  if (!ufunc) {
+    throw new ValueError(`Unknown ufunc '${name}'`)
+  }
+  return ufunc
+}
 
 /**
  * Pure TypeScript backend
@@ -133,29 +195,52 @@ export class TypeScriptBackend implements Backend {
   }
 
   argmax(a: NDArrayData): number {
-    let maxIdx = 0
-    let maxVal = a.buffer[0]
-
-    for (let i = 1; i < a.buffer.length; i++) {
-      if (a.buffer[i] > maxVal) {
-        maxVal = a.buffer[i]
-        maxIdx = i
-      }
-    }
-    return maxIdx
+    // Same NaN and empty-input handling as the axis form
+    return this.argAxis('argmax', a, null, false).buffer[0]
   }
 
   argmin(a: NDArrayData): number {
-    let minIdx = 0
-    let minVal = a.buffer[0]
-
-    for (let i = 1; i < a.buffer.length; i++) {
-      if (a.buffer[i] < minVal) {
-        minVal = a.buffer[i]
-        minIdx = i
+    return this.argAxis('argmin', a, null, false).buffer[0]
+  }
+
+  argAxis(op: ArgReduction, a: NDArrayData, axis: number | null, keepdims: boolean): NDArrayData {
+    const ax = axis === null ? 0 : normalizeAxis(axis, a.shape.length)
+    const { outer, length, inner } = axisLanes(axis === null ? [a.buffer.length] : a.shape, ax)
+    if (length === 0 && outer * inner > 0) {
+      throw new ValueError(`attempt to get ${op.replace('nan', '')} of an empty sequence`)
+    }
+
+    // Ties keep the first index; a NaN wins unless the op skips NaN
+    const skipNaN = op.startsWith('nan')
+    const better = op.endsWith('max')
+      ? (x: number, y: number) => x > y
+      : (x: number, y: number) => x < y
+    const out = new Int32Array(outer * inner)
+    for (let o = 0; o < outer; o++) {
+      for (let i = 0; i < inner; i++) {
+        let best = -1
+        let bestVal = Number.NaN
+        for (let k = 0; k < length; k++) {
+          const val = Number(a.buffer[(o * length + k) * inner + i])
+          if (Number.isNaN(val)) {
+            if (skipNaN) continue
+            best = k
+            break
+          }
+          if (best < 0 || better(val, bestVal)) {
+            best = k
+            bestVal = val
+          }
+        }
+        if (best < 0) {
+          throw new ValueError('All-NaN slice encountered')
+        }
+        out[o * inner + i] = best
       }
     }
-    return minIdx
+
+    const shape = reducedShape(a.shape, axis === null ? null : ax, keepdims)
+    return { buffer: out, shape, strides: computeStrides(shape), dtype: 'int32' }
   }
 
   norm(a: NDArrayData, ord: number | 'fro'): number {
@@ -226,6 +311,9 @@ export class TypeScriptBackend implements Backend {
   }
 
   nanmax(a: NDArrayData): number {
+    if (a.buffer.length === 0) {
+      throw new ValueError('zero-size array to reduction operation fmax which has no identity')
+    }
     let maxVal = Number.NEGATIVE_INFINITY
     let hasValue = false
     for (let i = 0; i < a.buffer.length; i++) {
@@ -241,6 +329,9 @@ export class TypeScriptBackend implements Backend {
   }
 
   nanmin(a: NDArrayData): number {
+    if (a.buffer.length === 0) {
+      throw new ValueError('zero-size array to reduction operation fmin which has no identity')
+    }
     let minVal = Number.POSITIVE_INFINITY
     let hasValue = false
     for (let i = 0; i < a.buffer.length; i++) {
@@ -278,6 +369,219 @@ export class TypeScriptBackend implements Backend {
     return count > 0 ? sumSquares / count : Number.NaN
   }
 
+  // ===== Cumulative Operations =====
+
+  cumulative(op: CumulativeOp, a: NDArrayData, axis: number | null): NDArrayData {
+    const shape = axis === null ? [a.buffer.length] : [...a.shape]
+    const ax = axis === null ? 0 : normalizeAxis(axis, shape.length)
+    const { outer, length, inner } = axisLanes(shape, ax)
+    const skipNaN = op.startsWith('nan')
+    const isSum = op.endsWith('sum')
+    const buffer = createTypedArray(a.buffer.length, a.dtype)
+
+    for (let o = 0; o < outer; o++) {
+      for (let i = 0; i < inner; i++) {
+        let acc = isSum ? 0 : 1
+        for (let k = 0; k < length; k++) {
+          const idx = (o * length + k) * inner + i
+          const val = Number(a.buffer[idx])
+          if (!(skipNaN && Number.isNaN(val))) {
+            acc = isSum ? acc + val : acc * val
+          }
+          // Read back so each step rounds or wraps in the output dtype
+          buffer[idx] = acc
+          acc = Number(buffer[idx])
+        }
+      }
+    }
+
+    return { buffer, shape, strides: computeStrides(shape), dtype: a.dtype }
+  }
+
+  // ===== Ufunc Methods =====
+
+  ufuncReduce(
+    name: UfuncName,
+    a: NDArrayData,
+    axis: readonly number[] | null,
+    keepdims: boolean,
+    initial?: number,
+  ): NDArrayData {
+    const ufunc = ufuncKernel(name)
+    const ndim = a.shape.length
+    const axes: number[] = []
+    for (const ax of axis ?? a.shape.map((_, d) => d)) {
+      const resolved = normalizeAxis(ax, ndim)
+      if (axes.includes(resolved)) {
+        throw new ValueError("duplicate value in 'axis'")
+      }
+      axes.push(resolved)
+    }
+    if (axes.length > 1 && !ufunc.reorderable) {
+      throw new ValueError(
+        `reduction operation '${ufunc.name}' is not reorderable, so at most one axis may be specified`,
+      )
+    }
+
+    const kept = a.shape.map((_, d) => d).filter((d) => !axes.includes(d))
+    const outer = dimOffsets(a.shape, kept)
+    const inner = dimOffsets(a.shape, axes)
+    const buffer = createTypedArray(outer.length, a.dtype)
+    outer.forEach((base, o) => {
+      // Fold from initial, else the first element; only an empty lane is the identity
+      let acc = initial ?? (inner.length > 0 ? Number(a.buffer[base + inner[0]]) : ufunc.identity)
+      if (acc === undefined) {
+        throw new ValueError(
+          `zero-size array to reduction operation ${ufunc.name} which has no identity`,
+        )
+      }
+      for (let k = initial === undefined ? 1 : 0; k < inner.length; k++) {
+        acc = ufunc.op(acc, Number(a.buffer[base + inner[k]]))
+      }
+      buffer[o] = acc
+    })
+
+    const shape = reducedShape(a.shape, axes, keepdims)
+    return { buffer, shape, strides: computeStrides(shape), dtype: a.dtype }
+  }
+
+  ufuncAccumulate(name: UfuncName, a: NDArrayData, axis: number): NDArrayData {
+    const ufunc = ufuncKernel(name)
+    const { outer, length, inner } = axisLanes(a.shape, normalizeAxis(axis, a.shape.length))
+    const buffer = createTypedArray(a.buffer.length, a.dtype)
+
+    for (let o = 0; o < outer; o++) {
+      for (let i = 0; i < inner; i++) {
+        let acc = 0
+        for (let k = 0; k < length; k++) {
+          const idx = (o * length + k) * inner + i
+          const val = Number(a.buffer[idx])
+          // Read back so each step rounds in the output dtype, as NumPy stores it
+          buffer[idx] = k === 0 ? val : ufunc.op(acc, val)
+          acc = Number(buffer[idx])
+        }
+      }
+    }
+
+    return { buffer, shape: [...a.shape], strides: computeStrides(a.shape), dtype: a.dtype }
+  }
+
+  ufuncOuter(name: UfuncName, a: NDArrayData, b: NDArrayData): NDArrayData {
+    const { op } = ufuncKernel(name)
+    const buffer = createTypedArray(a.buffer.length * b.buffer.length, a.dtype)
+    for (let i = 0; i < a.buffer.length; i++) {
+      for (let j = 0; j < b.buffer.length; j++) {
+        buffer[i * b.buffer.length + j] = op(Number(a.buffer[i]), Number(b.buffer[j]))
+      }
+    }
+
+    const shape = [...a.shape, ...b.shape]
+    return { buffer, shape, strides: computeStrides(shape), dtype: a.dtype }
+  }
+
+  ufuncReduceat(
+    name: UfuncName,
+    a: NDArrayData,
+    indices: readonly number[],
+    axis: number,
+  ): NDArrayData {
+    const ufunc = ufuncKernel(name)
+    const ax = normalizeAxis(axis, a.shape.length)
+    const { outer, length, inner } = axisLanes(a.shape, ax)
+    for (const index of indices) {
+      if (!Number.isInteger(index) || index < 0 || index >= length) {
+        throw new IndexError(
+          `index ${index} out-of-bounds in ${ufunc.name}.reduceat [0, ${length})`,
+        )
+      }
+    }
+
+    const shape = a.shape.map((dim, d) => (d === ax ? indices.length : dim))
+    const buffer = createTypedArray(computeSize(shape), a.dtype)
+    for (let o = 0; o < outer; o++) {
+      for (let i = 0; i < inner; i++) {
+        indices.forEach((start, m) => {
+          // A slice runs to the next index, or is the lone element where that is not larger
+          const next = indices[m + 1]
+          const end = next === undefined ? length : next > start ? next : start + 1
+          let acc = Number(a.buffer[(o * length + start) * inner + i])
+          for (let k = start + 1; k < end; k++) {
+            acc = ufunc.op(acc, Number(a.buffer[(o * length + k) * inner + i]))
+          }
+          buffer[(o * indices.length + m) * inner + i] = acc
+        })
+      }
+    }
+
+    return { buffer, shape, strides: computeStrides(shape), dtype: a.dtype }
+  }
+
+  ufuncAt(
+    name: UfuncName,
+    target: NDArrayData,
+    indices: readonly (readonly number[])[],
+    values: NDArrayData | number,
+  ): void {
+    const { op } = ufuncKernel(name)
+    const { shape } = target
+    if (indices.length > shape.length) {
+      throw new IndexError(
+        `too many indices for array: array is ${shape.length}-dimensional, but ${indices.length} were indexed`,
+      )
+    }
+    // Index arrays of length 1 broadcast against the others
+    const count = indices.find((index) => index.length !== 1)?.length ?? 1
+    if (indices.some((index) => index.length !== 1 && index.length !== count)) {
+      const shapes = indices.map((index) => `(${index.length},) `).join('')
+      throw new IndexError(
+        `shape mismatch: indexing arrays could not be broadcast together with shapes ${shapes}`,
+      )
+    }
+
+    // Check every index before target changes
+    const strides = computeStrides(shape)
+    const bases = new Array<number>(count).fill(0)
+    indices.forEach((index, axis) => {
+      for (let i = 0; i < count; i++) {
+        const raw = index[index.length === 1 ? 0 : i]
+        const resolved = raw < 0 ? raw + shape[axis] : raw
+        if (!Number.isInteger(raw) || resolved < 0 || resolved >= shape[axis]) {
+          throw new IndexError(
+            `index ${raw} is out of bounds for axis ${axis} with size ${shape[axis]}`,
+          )
+        }
+        bases[i] += resolved * strides[axis]
+      }
+    })
+
+    // Cast to the target dtype first, as NumPy does
+    const given = typeof values === 'number' ? [values] : values.buffer
+    const vals = createTypedArray(given.length, target.dtype)
+    vals.set(given)
+
+    // One value, one subarray, or one subarray per index
+    const inner = computeSize(shape.slice(indices.length))
+    let rowStep = 0
+    let colStep = 1
+    if (vals.length === 1) {
+      colStep = 0
+    } else if (vals.length === count * inner && vals.length !== inner) {
+      rowStep = inner
+    } else if (vals.length !== inner) {
+      throw new ValueError('array is not broadcastable to correct shape', {
+        expectedShape: [count, ...shape.slice(indices.length)],
+        actualShape: [vals.length],
+      })
+    }
+
+    bases.forEach((base, i) => {
+      for (let j = 0; j < inner; j++) {
+        const value = Number(vals[i * rowStep + j * colStep])
+        target.buffer[base + j] = op(Number(target.buffer[base + j]), value)
+      }
+    })
+  }
+
   // ===== Linear Algebra Operations =====
 
   matmul(a: NDArrayData, b: NDArrayData): NDArrayData {
@@ -0,0 +1,84 @@
+import { describe, expect, test } from 'bun:test'
+import { ValueError } from '../core/errors'
+import { WASMBackend } from './wasm'
+import { type RawWasmArray, ResidentArray } from './wasm-array'
+
+// Stand-in for the wasm-bindgen class, counting frees.
+function fakeRaw(values: number[], freed: { count: number }): RawWasmArray {
+  const data = Float64Array.from(values)
+  const raw = {
+    length: data.length,
+    view: () => data,
+    toFloat64Array: () => data.slice(),
+    set: (next: Float64Array) => {
+      if (next.length !== data.length) {
+        throw new Error('{"name":"ValueError","message":"length mismatch"}')
+      }
+      data.set(next)
+    },
+    free: () => {
+      freed.count += 1
+    },
+    sum: () => data.reduce((total, x) => total + x, 0),
+    add: (other: RawWasmArray) =>
+      fakeRaw(Array.from(data, (x, i) => x + other.view()[i]), freed),
+    addScalar: (value: number) => fakeRaw(Array.from(data, (x) => x + value), freed),
+  }
+  return raw as unknown as RawWasmArray
+}
+
+describe('ResidentArray', () => {
+  test('chains kernels into new handles', () => {
+    const freed = { count: 0 }
+    const a = new ResidentArray(fakeRaw([1, 2, 3], freed))
+    const b = a.add(a).add(1)
+
+    expect(Array.from(b.toFloat64Array())).toEqual([3, 5, 7])
+    expect(b.sum()).toBe(15)
+    expect(b.length).toBe(3)
+  })
+
+  test('free is idempotent and later use throws', () => {
+    const freed = { count: 0 }
+    const a = new ResidentArray(fakeRaw([1, 2], freed))
+
+    a.free()
+    a.free()
+    expect(freed.count).toBe(1)
+    expect(() => a.view()).toThrow('ResidentArray has been freed')
+  })
+
+  test('kernel errors surface as NumPy error classes', () => {
+    const a = new ResidentArray(fakeRaw([1, 2], { count: 0 }))
+
+    expect(() => a.set(new Float64Array(3))).toThrow(ValueError)
+  })
+})
+
+describe('ResidentArray over the real numpy-wasm WasmArray', () => {
+  test('allocates, runs kernels in place, reads back and frees', async () => {
+    const backend = new WASMBackend()
+    await backend.init()
+
+    const a = backend.residentArray(new Float64Array([1, 2, 3, 4]))
+    const eye = backend.residentArray(new Float64Array([1, 0, 0, 1]))
+    const out = backend.residentArray(new Float64Array(4))
+    expect(a.length).toBe(4)
+
+    a.matmulInto(eye, out, 2, 2, 2, true)
+    expect(Array.from(out.toFloat64Array())).toEqual([1, 3, 2, 4])
+
+    out.set(new Float64Array([5, 6, 7, 8]))
+    expect(Array.from(out.view())).toEqual([5, 6, 7, 8])
+
+    const b = a.add(out).mul(2)
+    expect(Array.from(b.toFloat64Array())).toEqual([12, 16, 20, 24])
+    expect(b.sum()).toBe(72)
+    expect(() => a.set(new Float64Array(3))).toThrow(ValueError)
+
+    const raw = b.raw
+    for (const handle of [a, eye, out, b]) handle.free()
+    expect(() => b.view()).toThrow('ResidentArray has been freed')
+    expect(() => raw.sum()).toThrow()
+  })
+})
@@ -0,0 +1,153 @@
+// ===== Resident WASM Arrays =====
+// Handles to float64 arrays that live in WASM linear memory, so chained
+// kernels skip the per-call copy in and out of plain exports.
+
+import { callNative } from '../core/errors'
+
+/** The wasm-bindgen `WasmArray` class exported by numpy-wasm. */
+export interface RawWasmArray {
+  readonly length: number
+  view(): Float64Array
+  toFloat64Array(): Float64Array
+  set(values: Float64Array): void
+  free(): void
+  sum(): number
+  mean(): number
+  max(): number
+  min(): number
+  add(other: RawWasmArray): RawWasmArray
+  sub(other: RawWasmArray): RawWasmArray
+  mul(other: RawWasmArray): RawWasmArray
+  div(other: RawWasmArray): RawWasmArray
+  addScalar(value: number): RawWasmArray
+  mulScalar(value: number): RawWasmArray
+  matmul(other: RawWasmArray, m: number, k: number, n: number): RawWasmArray
+  matmulInto(
+    other: RawWasmArray,
+    m: number,
+    k: number,
+    n: number,
+    transA: boolean,
+    transB: boolean,
+    out: RawWasmArray,
+  ): void
+}
+
+export interface RawWasmArrayClass {
+  fromFloat64Array(values: Float64Array): RawWasmArray
+}
+
+// Frees the WASM buffer when a handle is collected without free(). The raw
+// handle is the held value, so it outlives its wrapper until the callback.
+const registry =
+  typeof FinalizationRegistry === 'undefined'
+    ? null
+    : new FinalizationRegistry<RawWasmArray>((raw) => raw.free())
+
+/**
+ * A float64 array resident in WASM memory. Call `free()` when done; handles
+ * that are dropped without it are freed by a FinalizationRegistry on GC.
+ */
+export class ResidentArray {
+  private handle: RawWasmArray | null
+
+  constructor(raw: RawWasmArray) {
+    this.handle = raw
+    registry?.register(this, raw, this)
+  }
+
+  static from(cls: RawWasmArrayClass, values: Float64Array): ResidentArray {
+    return new ResidentArray(cls.fromFloat64Array(values))
+  }
+
+  /** The underlying handle; throws once freed. */
+  get raw(): RawWasmArray {
+    if (this.handle === null) {
+      throw new Error('ResidentArray has been freed')
+    }
+    return this.handle
+  }
+
+  get length(): number {
+    return this.raw.length
+  }
+
+  /** Zero-copy view; take a fresh one after any kernel call, as WASM memory may grow. */
+  view(): Float64Array {
+    return this.raw.view()
+  }
+
+  toFloat64Array(): Float64Array {
+    return this.raw.toFloat64Array()
+  }
+
+  set(values: Float64Array): void {
+    callNative(() => this.raw.set(values))
+  }
+
+  /** Release the WASM buffer now. Safe to call more than once. */
+  free(): void {
+    if (this.handle === null) return
+    registry?.unregister(this)
+    const raw = this.handle
+    this.handle = null
+    raw.free()
+  }
+
+  sum(): number {
+    return this.raw.sum()
+  }
+
+  mean(): number {
+    return this.raw.mean()
+  }
+
+  max(): number {
+    return callNative(() => this.raw.max())
+  }
+
+  min(): number {
+    return callNative(() => this.raw.min())
+  }
+
+  add(other: ResidentArray | number): ResidentArray {
+    return this.wrap((raw) =>
+      typeof other === 'number' ? raw.addScalar(other) : raw.add(other.raw),
+    )
+  }
+
+  sub(other: ResidentArray): ResidentArray {
+    return this.wrap((raw) => raw.sub(other.raw))
+  }
+
+  mul(other: ResidentArray | number): ResidentArray {
+    return this.wrap((raw) =>
+      typeof other === 'number' ? raw.mulScalar(other) : raw.mul(other.raw),
+    )
+  }
+
+  div(other: ResidentArray): ResidentArray {
+    return this.wrap((raw) => raw.div(other.raw))
+  }
+
+  matmul(other: ResidentArray, m: number, k: number, n: number): ResidentArray {
+    return this.wrap((raw) => raw.matmul(other.raw, m, k, n))
+  }
+
+  /** `out = op(this) @ op(other)` into an existing `m x n` handle. */
+  matmulInto(
+    other: ResidentArray,
+    out: ResidentArray,
+    m: number,
+    k: number,
+    n: number,
+    transA = false,
+    transB = false,
+  ): void {
+    callNative(() => this.raw.matmulInto(other.raw, m, k, n, transA, transB, out.raw))
+  }
+
+  private wrap(kernel: (raw: RawWasmArray) => RawWasmArray): ResidentArray {
+    return new ResidentArray(callNative(() => kernel(this.raw)))
+  }
+}
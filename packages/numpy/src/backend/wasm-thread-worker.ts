@@ -0,0 +1,55 @@
+// ===== WASM Thread Pool Worker =====
+// Runs in a Web Worker or a Node worker_thread. Instantiates the threaded
+// numpy-wasm build on the pool's shared memory, then runs chunk kernels on
+// request and posts back their results.
+
+import type { PoolMessage, PoolReply, ThreadedGlue } from './wasm-threads'
+
+type Port = {
+  post(message: PoolReply): void
+  listen(handler: (message: PoolMessage) => void): void
+}
+
+async function connect(): Promise<Port> {
+  if (typeof process !== 'undefined' && process.versions?.node) {
+    const { parentPort } = await import('node:worker_threads')
+    if (!parentPort) throw new Error('wasm-thread-worker must run as a worker')
+    return {
+      post: (message) => parentPort.postMessage(message),
+      listen: (handler) => parentPort.on('message', handler),
+    }
+  }
+  const scope = globalThis as unknown as {
+    postMessage(message: unknown): void
+    addEventListener(type: 'message', listener: (event: { data: PoolMessage }) => void): void
+  }
+  return {
+    post: (message) => scope.postMessage(message),
+    listen: (handler) => scope.addEventListener('message', (event) => handler(event.data)),
+  }
+}
+
+const port = await connect()
+let glue: ThreadedGlue | null = null
+
+port.listen(async (message) => {
+  if (message.type === 'init') {
+    try {
+      glue = (await import(message.glue)) as ThreadedGlue
+      glue.initSync({ module: message.module, memory: message.memory })
+      port.post({ type: 'ready' })
+    } catch (error) {
+      port.post({ type: 'ready', error: String(error) })
+    }
+    return
+  }
+
+  try {
+    if (!glue) throw new Error('wasm-thread-worker received a task before init')
+    const kernel = glue[message.kernel] as (...args: unknown[]) => unknown
+    port.post({ type: 'result', id: message.id, value: kernel(...message.args) })
+  } catch (error) {
+    const text = error instanceof Error ? error.message : String(error)
+    port.post({ type: 'result', id: message.id, error: text })
+  }
+})
@@ -0,0 +1,44 @@
+import { afterAll, describe, expect, test } from 'bun:test'
+import { existsSync } from 'node:fs'
+import { ValueError } from '../core/errors'
+import { threadsAvailable, WasmThreadPool } from './wasm-threads'
+
+// Built by `scripts/build-wasm.sh --threads`, which needs nightly Rust, so
+// local runs without it skip. CI builds it, and there a missing artifact
+// fails the test because `create` resolves to null.
+const built = existsSync(new URL('../../wasm-threads/tsnum_wasm_bg.wasm', import.meta.url))
+const required = built || process.env.CI !== undefined
+
+describe('WasmThreadPool', () => {
+  let pool: WasmThreadPool | null = null
+
+  afterAll(() => pool?.terminate())
+
+  test('Node has shared memory for worker_threads', () => {
+    expect(threadsAvailable()).toBe(true)
+  })
+
+  test.skipIf(!required)('parallel chunks match the single-threaded results', async () => {
+    pool = await WasmThreadPool.create({ size: 3 })
+    expect(pool).not.toBeNull()
+    if (!pool) return
+
+    const n = 100_000
+    const a = Float64Array.from({ length: n }, (_, i) => i)
+    const b = Float64Array.from({ length: n }, (_, i) => n - i)
+
+    expect(Array.from(await pool.binary('add', a, b)).every((x) => x === n)).toBe(true)
+    expect((await pool.scalar('mul', a, 2))[n - 1]).toBe(2 * (n - 1))
+    expect((await pool.unary('sqrt', a))[49]).toBe(7)
+    expect(await pool.sum(a)).toBe((n * (n - 1)) / 2)
+    expect(await pool.max(a)).toBe(n - 1)
+    expect(await pool.min(b)).toBe(1)
+
+    const m = 300
+    const x = Float64Array.from({ length: m * 4 }, (_, i) => i % 5)
+    const eye = Float64Array.from({ length: 16 }, (_, i) => (i % 5 === 0 ? 1 : 0))
+    expect(Array.from(await pool.matmul(x, eye, m, 4, 4))).toEqual(Array.from(x))
+
+    await expect(pool.binary('add', a, new Float64Array(3))).rejects.toThrow(ValueError)
+  })
+})
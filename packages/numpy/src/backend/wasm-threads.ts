@@ -0,0 +1,331 @@
+// ===== Threaded WASM Backend =====
+// Opt-in worker pool over the shared-memory build of numpy-wasm
+// (`scripts/build-wasm.sh --threads`). Operands are copied once into shared
+// linear memory; each worker runs a disjoint chunk of the same kernel on it,
+// and the main thread combines reduction partials. Without
+// SharedArrayBuffer (or cross-origin isolation in browsers) `create`
+// resolves to null and callers stay on the single-threaded WASMBackend.
+
+import { callNative, fromNativeError } from '../core/errors'
+
+/** Fewest elements worth sending to another thread. */
+const MIN_CHUNK = 1 << 14
+
+/** A `WasmArray` from the threaded build, which also exposes its address. */
+export interface ThreadedArray {
+  readonly ptr: number
+  readonly length: number
+  toFloat64Array(): Float64Array
+  free(): void
+}
+
+export type ChunkKernel =
+  | 'par_binary_chunk'
+  | 'par_scalar_chunk'
+  | 'par_unary_chunk'
+  | 'par_sum_chunk'
+  | 'par_max_chunk'
+  | 'par_min_chunk'
+  | 'par_gemm_rows'
+
+/** The wasm-bindgen `web` glue of the threaded build. */
+export type ThreadedGlue = {
+  default(options: { module_or_path: WebAssembly.Module }): Promise<unknown>
+  initSync(options: { module: WebAssembly.Module; memory: WebAssembly.Memory }): unknown
+  shared_memory(): WebAssembly.Memory
+  par_check_same_len(left: number, right: number): void
+  par_check_nonempty(len: number, reduction: string): void
+  par_check_gemm(
+    aLen: number,
+    bLen: number,
+    m: number,
+    k: number,
+    n: number,
+    transA: boolean,
+    transB: boolean,
+  ): void
+  WasmArray: {
+    new (len: number): ThreadedArray
+    fromFloat64Array(values: Float64Array): ThreadedArray
+  }
+} & Record<ChunkKernel, (...args: never[]) => unknown>
+
+export type PoolMessage =
+  | { type: 'init'; glue: string; module: WebAssembly.Module; memory: WebAssembly.Memory }
+  | { type: 'task'; id: number; kernel: ChunkKernel; args: unknown[] }
+
+export type PoolReply =
+  | { type: 'ready'; error?: string }
+  | { type: 'result'; id: number; value?: unknown; error?: string }
+
+export type BinaryKernel =
+  | 'add'
+  | 'sub'
+  | 'mul'
+  | 'div'
+  | 'pow'
+  | 'maximum'
+  | 'minimum'
+  | 'fmax'
+  | 'fmin'
+export type UnaryKernel = 'neg' | 'abs' | 'sqrt' | 'exp' | 'log' | 'sin' | 'cos' | 'tanh'
+
+export interface ThreadPoolOptions {
+  /** Worker count. Defaults to the hardware concurrency, capped at 8. */
+  size?: number
+}
+
+interface PoolWorker {
+  post(message: PoolMessage): void
+  listen(handler: (reply: PoolReply) => void): void
+  terminate(): void
+}
+
+const isNode = typeof process !== 'undefined' && Boolean(process.versions?.node)
+
+/** Shared memory is usable: Node, or a cross-origin isolated browser page. */
+export function threadsAvailable(): boolean {
+  if (typeof SharedArrayBuffer === 'undefined') return false
+  return (globalThis as { crossOriginIsolated?: boolean }).crossOriginIsolated !== false
+}
+
+async function spawn(url: URL): Promise<PoolWorker> {
+  if (isNode) {
+    const { Worker } = await import('node:worker_threads')
+    const worker = new Worker(url)
+    return {
+      post: (message) => worker.postMessage(message),
+      listen: (handler) => worker.on('message', handler),
+      terminate: () => void worker.terminate(),
+    }
+  }
+  const WebWorker = (
+    globalThis as unknown as {
+      Worker: new (
+        url: URL,
+        options: { type: 'module' },
+      ) => {
+        postMessage(message: unknown): void
+        addEventListener(type: 'message', listener: (event: { data: PoolReply }) => void): void
+        terminate(): void
+      }
+    }
+  ).Worker
+  const worker = new WebWorker(url, { type: 'module' })
+  return {
+    post: (message) => worker.postMessage(message),
+    listen: (handler) => worker.addEventListener('message', (event) => handler(event.data)),
+    terminate: () => worker.terminate(),
+  }
+}
+
+async function compile(url: URL): Promise<WebAssembly.Module> {
+  if (isNode) {
+    const { readFile } = await import('node:fs/promises')
+    return WebAssembly.compile(await readFile(url))
+  }
+  return WebAssembly.compileStreaming(fetch(url))
+}
+
+/**
+ * A pool of workers sharing one instance's linear memory. Each method
+ * copies its inputs in once, runs the kernel in parallel chunks and
+ * copies the result out.
+ */
+export class WasmThreadPool {
+  private readonly pending = new Map<
+    number,
+    { resolve: (value: unknown) => void; reject: (error: unknown) => void }
+  >()
+  private nextId = 0
+
+  private constructor(
+    private readonly glue: ThreadedGlue,
+    private readonly workers: PoolWorker[],
+  ) {
+    for (const worker of workers) {
+      worker.listen((reply) => {
+        if (reply.type !== 'result') return
+        const task = this.pending.get(reply.id)
+        if (!task) return
+        this.pending.delete(reply.id)
+        // Kernel errors carry the JSON payload; `run` maps it to a NumPy error.
+        if (reply.error === undefined) task.resolve(reply.value)
+        else task.reject(new Error(reply.error))
+      })
+    }
+  }
+
+  /** Start a pool, or resolve to null when shared memory or the threaded build is missing. */
+  static async create(options: ThreadPoolOptions = {}): Promise<WasmThreadPool | null> {
+    if (!threadsAvailable()) return null
+    const glueUrl = new URL('../../wasm-threads/tsnum_wasm.js', import.meta.url)
+    const wasmUrl = new URL('../../wasm-threads/tsnum_wasm_bg.wasm', import.meta.url)
+    let glue: ThreadedGlue
+    let module: WebAssembly.Module
+    try {
+      module = await compile(wasmUrl)
+      glue = (await import(glueUrl.href)) as ThreadedGlue
+      await glue.default({ module_or_path: module })
+    } catch {
+      return null
+    }
+
+    const hardware = (globalThis as { navigator?: { hardwareConcurrency?: number } }).navigator
+      ?.hardwareConcurrency
+    const size = Math.max(1, options.size ?? Math.min(hardware ?? 4, 8))
+    const extension = import.meta.url.endsWith('.ts') ? 'ts' : 'js'
+    const workerUrl = new URL(`./wasm-thread-worker.${extension}`, import.meta.url)
+    const memory = glue.shared_memory()
+
+    const workers = await Promise.all(
+      Array.from({ length: size }, async () => {
+        const worker = await spawn(workerUrl)
+        await new Promise<void>((resolve, reject) => {
+          worker.listen((reply) => {
+            if (reply.type !== 'ready') return
+            if (reply.error === undefined) resolve()
+            else reject(new Error(reply.error))
+          })
+          worker.post({ type: 'init', glue: glueUrl.href, module, memory })
+        })
+        return worker
+      }),
+    )
+    return new WasmThreadPool(glue, workers)
+  }
+
+  get size(): number {
+    return this.workers.length
+  }
+
+  /** Stop every worker. Pending calls never settle afterwards. */
+  terminate(): void {
+    for (const worker of this.workers) worker.terminate()
+    this.workers.length = 0
+  }
+
+  async binary(op: BinaryKernel, a: Float64Array, b: Float64Array): Promise<Float64Array> {
+    callNative(() => this.glue.par_check_same_len(a.length, b.length))
+    return this.elementwise([a, b], a.length, ([x, y], out, start, end) => [
+      'par_binary_chunk',
+      [op, x, y, out, start, end],
+    ])
+  }
+
+  async scalar(op: BinaryKernel, a: Float64Array, value: number): Promise<Float64Array> {
+    return this.elementwise([a], a.length, ([x], out, start, end) => [
+      'par_scalar_chunk',
+      [op, x, value, out, start, end],
+    ])
+  }
+
+  async unary(op: UnaryKernel, a: Float64Array): Promise<Float64Array> {
+    return this.elementwise([a], a.length, ([x], out, start, end) => [
+      'par_unary_chunk',
+      [op, x, out, start, end],
+    ])
+  }
+
+  async sum(a: Float64Array): Promise<number> {
+    const partials = await this.reduce('par_sum_chunk', a)
+    return partials.reduce((total, x) => total + x, 0)
+  }
+
+  /** NaN if any element is NaN; throws for an empty array, like `reduce::max`. */
+  async max(a: Float64Array): Promise<number> {
+    callNative(() => this.glue.par_check_nonempty(a.length, 'maximum'))
+    return Math.max(...(await this.reduce('par_max_chunk', a)))
+  }
+
+  async min(a: Float64Array): Promise<number> {
+    callNative(() => this.glue.par_check_nonempty(a.length, 'minimum'))
+    return Math.min(...(await this.reduce('par_min_chunk', a)))
+  }
+
+  /** `op(A) @ op(B)` split across workers by output rows. */
+  async matmul(
+    a: Float64Array,
+    b: Float64Array,
+    m: number,
+    k: number,
+    n: number,
+    transA = false,
+    transB = false,
+  ): Promise<Float64Array> {
+    callNative(() => this.glue.par_check_gemm(a.length, b.length, m, k, n, transA, transB))
+    // Rows per worker, at least one MIN_CHUNK of multiply-adds each.
+    const rows = Math.max(1, Math.ceil(MIN_CHUNK / Math.max(1, k * n)))
+    return this.withArrays([a, b], m * n, async ([x, y], out) => {
+      await Promise.all(
+        this.ranges(m, rows).map(([row0, row1], i) =>
+          this.run(i, 'par_gemm_rows', [x, y, out, m, k, n, transA, transB, row0, row1]),
+        ),
+      )
+    })
+  }
+
+  private async elementwise(
+    inputs: Float64Array[],
+    len: number,
+    task: (ptrs: number[], out: number, start: number, end: number) => [ChunkKernel, unknown[]],
+  ): Promise<Float64Array> {
+    return this.withArrays(inputs, len, async (ptrs, out) => {
+      await Promise.all(
+        this.ranges(len, MIN_CHUNK).map(([start, end], i) =>
+          this.run(i, ...task(ptrs, out, start, end)),
+        ),
+      )
+    })
+  }
+
+  private async reduce(kernel: ChunkKernel, a: Float64Array): Promise<number[]> {
+    const partials: number[] = []
+    await this.withArrays([a], 0, async ([x]) => {
+      const ranges: Array<[number, number]> =
+        a.length === 0 ? [[0, 0]] : this.ranges(a.length, MIN_CHUNK)
+      const values = await Promise.all(ranges.map(([s, e], i) => this.run(i, kernel, [x, s, e])))
+      partials.push(...(values as number[]))
+    })
+    return partials
+  }
+
+  /** Copy inputs into shared memory, run `body`, copy the output out and free everything. */
+  private async withArrays(
+    inputs: Float64Array[],
+    outLen: number,
+    body: (ptrs: number[], out: number) => Promise<void>,
+  ): Promise<Float64Array> {
+    const arrays = inputs.map((values) => this.glue.WasmArray.fromFloat64Array(values))
+    const out = new this.glue.WasmArray(outLen)
+    try {
+      await body(
+        arrays.map((array) => array.ptr),
+        out.ptr,
+      )
+      return out.toFloat64Array()
+    } finally {
+      for (const array of arrays) array.free()
+      out.free()
+    }
+  }
+
+  /** Split `0..len` into at most one range per worker, each at least `min` long. */
+  private ranges(len: number, min: number): Array<[number, number]> {
+    const count = Math.max(1, Math.min(this.workers.length, Math.ceil(len / min)))
+    const step = Math.ceil(len / count)
+    const ranges: Array<[number, number]> = []
+    for (let start = 0; start < len; start += step) {
+      ranges.push([start, Math.min(len, start + step)])
+    }
+    return ranges
+  }
+
+  private run(worker: number, kernel: ChunkKernel, args: unknown[]): Promise<unknown> {
+    const id = this.nextId++
+    return new Promise((resolve, reject) => {
+      this.pending.set(id, { resolve, reject: (error) => reject(fromNativeError(error)) })
+      this.workers[worker % this.workers.length].post({ type: 'task', id, kernel, args })
+    })
+  }
+}
@@ -2,7 +2,7 @@ import { afterEach, beforeEach, describe, expect, test } from 'bun:test'
 import { array } from '../creation'
 import { add, div, mean, mul, std, sub, sum, variance } from '../ops'
 import { backendManager, getBackend, initWASM } from './manager'
-import { WASMBackend } from './wasm'
+import { WASMBackend, simd128Supported } from './wasm'
 
 describe('WASM Backend', () => {
   beforeEach(() => {
@@ -23,6 +23,25 @@ describe('WASM Backend', () => {
     expect(backend.name).toBe('wasm')
   })
 
+  test('WASMBackend picks the simd128 build by feature detection', async () => {
+    const backend = new WASMBackend()
+    await backend.init()
+
+    // Every runtime the tests run on (Bun, Node 16.4+) has simd128, and the
+    // module reports how it was compiled.
+    expect(simd128Supported()).toBe(true)
+    expect(backend.simd).toBe(true)
+  })
+
+  test('the wasm-nosimd fallback is built and runs the same kernels', async () => {
+    const scalar = await import(new URL('../../wasm-nosimd/tsnum_wasm.js', import.meta.url).href)
+
+    expect(scalar.simd_enabled()).toBe(false)
+    const a = new Float64Array([1, 2, 3])
+    expect(Array.from(scalar.add_arrays(a, new Float64Array([4, 5, 6])))).toEqual([5, 7, 9])
+    expect(scalar.sum(a)).toBe(6)
+  })
+
   test('WASM backend arithmetic operations', async () => {
     const backend = new WASMBackend()
     await backend.init()
@@ -161,4 +180,34 @@ describe('WASM Backend', () => {
     expect(result2.dtype).toBe('float32')
     expect(Array.from(result2.buffer)).toEqual([3, 5, 7])
   })
+
+  test('WASM backend keeps float32 data float32', async () => {
+    const backend = new WASMBackend()
+    await backend.init()
+
+    const a = array([0.1, 2, -3.5], { dtype: 'float32' }).getData()
+    const b = array([0.2, 0.5, 4], { dtype: 'float32' }).getData()
+
+    const sum = backend.add(a, b)
+    expect(sum.buffer).toBeInstanceOf(Float32Array)
+    expect(sum.buffer[0]).toBe(Math.fround(Math.fround(0.1) + Math.fround(0.2)))
+
+    const root = backend.sqrt(backend.abs(a))
+    expect(root.dtype).toBe('float32')
+    expect(root.buffer[1]).toBe(Math.fround(Math.SQRT2))
+
+    expect(backend.sum(a)).toBeCloseTo(-1.4, 6)
+    expect(backend.argmin(a)).toBe(2)
+    expect(backend.nanmax(a)).toBe(2)
+
+    const low = backend.minimum(a, b)
+    expect(low.buffer).toBeInstanceOf(Float32Array)
+    expect(Array.from(low.buffer)).toEqual([Math.fround(0.1), 0.5, -3.5])
+    expect(backend.clip(a, -1, 1).buffer).toBeInstanceOf(Float32Array)
+
+    const m = array([[1, 2], [3, 4]], { dtype: 'float32' }).getData()
+    const product = backend.matmul(m, m)
+    expect(product.buffer).toBeInstanceOf(Float32Array)
+    expect(Array.from(product.buffer)).toEqual([7, 10, 15, 22])
+  })
 })
@@ -1,13 +1,43 @@
 // ===== WASM Backend =====
 // High-performance WASM implementation
 
+import { callNative } from '../core/errors'
 import type { DType, NDArrayData, TypedArray } from '../core/types'
-import { broadcastShapes, broadcastTo, createTypedArray } from '../core/utils'
-import type { Backend } from './types'
+import {
+  broadcastShapes,
+  broadcastTo,
+  computeStrides,
+  createTypedArray,
+  normalizeAxis,
+  reducedShape,
+} from '../core/utils'
+import type { ArgReduction, Backend, CumulativeOp, UfuncName } from './types'
+import { type RawWasmArrayClass, ResidentArray } from './wasm-array'
 
 // Dynamic import of WASM module
 type WASMModule = typeof import('../../wasm/tsnum_wasm.js')
 
+/** Exports with an `_f32` twin, so `tsc` rejects a kernel name the build lacks. */
+type F32Kernel = {
+  [K in keyof WASMModule]: K extends `${infer Base}_f32` ? Base : never
+}[keyof WASMModule]
+
+// Smallest module using a simd128 instruction (`i8x16.splat` and
+// `i8x16.popcnt` on a constant): it validates only where simd128 is supported.
+const SIMD_PROBE = new Uint8Array([
+  0, 97, 115, 109, 1, 0, 0, 0, 1, 5, 1, 96, 0, 1, 123, 3, 2, 1, 0, 10, 10, 1, 8, 0, 65, 0, 253, 15,
+  253, 98, 11,
+])
+
+/** Whether this runtime can run the simd128 build of numpy-wasm. */
+export function simd128Supported(): boolean {
+  try {
+    return WebAssembly.validate(SIMD_PROBE)
+  } catch {
+    return false
+  }
+}
+
 /**
  * WASM backend using Rust implementation
  * Provides near-native performance with automatic fallback
@@ -15,24 +45,50 @@ type WASMModule = typeof import('../../wasm/tsnum_wasm.js')
 export class WASMBackend implements Backend {
   readonly name = 'wasm' as const
   private _isReady = false
+  private _simd = false
   private wasmModule: WASMModule | null = null
 
   get isReady(): boolean {
     return this._isReady
   }
 
+  /** True when the loaded module was compiled with simd128, not the scalar fallback. */
+  get simd(): boolean {
+    return this._simd
+  }
+
   async init(): Promise<void> {
     try {
-      // Import the WASM module (Node.js target auto-initializes)
-      const module = await import('../../wasm/tsnum_wasm.js')
-
-      this.wasmModule = module as unknown as WASMModule
+      // Import the WASM module (Node.js target auto-initializes). Runtimes
+      // without simd128 reject the default build at compile time, so they
+      // get the `wasm-nosimd` artifact instead.
+      const module = (
+        simd128Supported()
+          ? await import('../../wasm/tsnum_wasm.js')
+          : await import(new URL('../../wasm-nosimd/tsnum_wasm.js', import.meta.url).href)
+      ) as WASMModule
+
+      this.wasmModule = module
+      this._simd = module.simd_enabled()
       this._isReady = true
     } catch (error) {
       throw new Error(`Failed to initialize WASM: ${error}`)
     }
   }
 
+  /**
+   * Copy `values` into WASM memory once and return a handle that kernels can
+   * chain on without copying. Free it with `free()` when done.
+   */
+  residentArray(values: Float64Array): ResidentArray {
+    this.ensureReady()
+    const { WasmArray } = this.module as unknown as { WasmArray?: RawWasmArrayClass }
+    if (!WasmArray) {
+      throw new Error('This WASM build has no resident arrays; rebuild numpy-wasm')
+    }
+    return ResidentArray.from(WasmArray, values)
+  }
+
   // ===== Arithmetic Operations =====
 
   add(a: NDArrayData, b: NDArrayData | number): NDArrayData {
@@ -40,54 +96,89 @@ export class WASMBackend implements Backend {
 
     if (typeof b === 'number') {
       // Scalar operation
+      const f32 = this.f32Array('add_scalar', [a], b)
+      if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
       const buffer = this.toFloat64Array(a.buffer)
       const result = this.module.add_scalar(buffer, b)
       return this.toNDArrayData(result, a.shape, a.dtype)
     }
 
     // Array operation with broadcasting
-    return this.elementwiseOp(a, b, (bufA, bufB) => this.module.add_arrays(bufA, bufB))
+    return this.elementwiseOp(
+      a,
+      b,
+      (bufA, bufB) => this.module.add_arrays(bufA, bufB),
+      'add_arrays',
+    )
   }
 
   sub(a: NDArrayData, b: NDArrayData | number): NDArrayData {
     this.ensureReady()
 
     if (typeof b === 'number') {
+      const f32 = this.f32Array('sub_scalar', [a], b)
+      if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
       const buffer = this.toFloat64Array(a.buffer)
       const result = this.module.sub_scalar(buffer, b)
       return this.toNDArrayData(result, a.shape, a.dtype)
     }
 
-    return this.elementwiseOp(a, b, (bufA, bufB) => this.module.sub_arrays(bufA, bufB))
+    return this.elementwiseOp(
+      a,
+      b,
+      (bufA, bufB) => this.module.sub_arrays(bufA, bufB),
+      'sub_arrays',
+    )
   }
 
   mul(a: NDArrayData, b: NDArrayData | number): NDArrayData {
     this.ensureReady()
 
     if (typeof b === 'number') {
+      const f32 = this.f32Array('mul_scalar', [a], b)
+      if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
       const buffer = this.toFloat64Array(a.buffer)
       const result = this.module.mul_scalar(buffer, b)
       return this.toNDArrayData(result, a.shape, a.dtype)
     }
 
-    return this.elementwiseOp(a, b, (bufA, bufB) => this.module.mul_arrays(bufA, bufB))
+    return this.elementwiseOp(
+      a,
+      b,
+      (bufA, bufB) => this.module.mul_arrays(bufA, bufB),
+      'mul_arrays',
+    )
   }
 
   div(a: NDArrayData, b: NDArrayData | number): NDArrayData {
     this.ensureReady()
 
     if (typeof b === 'number') {
+      const f32 = this.f32Array('div_scalar', [a], b)
+      if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
       const buffer = this.toFloat64Array(a.buffer)
       const result = this.module.div_scalar(buffer, b)
       return this.toNDArrayData(result, a.shape, a.dtype)
     }
 
-    return this.elementwiseOp(a, b, (bufA, bufB) => this.module.div_arrays(bufA, bufB))
+    return this.elementwiseOp(
+      a,
+      b,
+      (bufA, bufB) => this.module.div_arrays(bufA, bufB),
+      'div_arrays',
+    )
   }
 
   pow(a: NDArrayData, exponent: number): NDArrayData {
     this.ensureReady()
 
+    const f32 = this.f32Array('pow_scalar', [a], exponent)
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
     const result = this.module.pow_scalar(buffer, exponent)
     return this.toNDArrayData(result, a.shape, a.dtype)
@@ -97,110 +188,304 @@ export class WASMBackend implements Backend {
 
   sum(a: NDArrayData): number {
     this.ensureReady()
+    const f32 = this.f32Number('sum', [a])
+    if (f32 !== null) return f32
     const buffer = this.toFloat64Array(a.buffer)
     return this.module.sum(buffer)
   }
 
   mean(a: NDArrayData): number {
     this.ensureReady()
+    const f32 = this.f32Number('mean', [a])
+    if (f32 !== null) return f32
     const buffer = this.toFloat64Array(a.buffer)
     return this.module.mean(buffer)
   }
 
   max(a: NDArrayData): number {
     this.ensureReady()
+    const f32 = this.f32Number('max', [a])
+    if (f32 !== null) return f32
     const buffer = this.toFloat64Array(a.buffer)
-    return this.module.max(buffer)
+    return callNative(() => this.module.max(buffer))
   }
 
   min(a: NDArrayData): number {
     this.ensureReady()
+    const f32 = this.f32Number('min', [a])
+    if (f32 !== null) return f32
     const buffer = this.toFloat64Array(a.buffer)
-    return this.module.min(buffer)
+    return callNative(() => this.module.min(buffer))
   }
 
   std(a: NDArrayData): number {
     this.ensureReady()
+    const f32 = this.f32Number('std', [a])
+    if (f32 !== null) return f32
     const buffer = this.toFloat64Array(a.buffer)
     return this.module.std(buffer)
   }
 
   variance(a: NDArrayData): number {
     this.ensureReady()
+    const f32 = this.f32Number('variance', [a])
+    if (f32 !== null) return f32
     const buffer = this.toFloat64Array(a.buffer)
     return this.module.variance(buffer)
   }
 
   prod(a: NDArrayData): number {
     this.ensureReady()
+    const f32 = this.f32Number('prod', [a])
+    if (f32 !== null) return f32
     const buffer = this.toFloat64Array(a.buffer)
     return this.module.prod(buffer)
   }
 
   argmax(a: NDArrayData): number {
     this.ensureReady()
+    const f32 = this.f32Number('argmax', [a])
+    if (f32 !== null) return f32
     const buffer = this.toFloat64Array(a.buffer)
-    return this.module.argmax(buffer)
+    return callNative(() => this.module.argmax(buffer))
   }
 
   argmin(a: NDArrayData): number {
     this.ensureReady()
+    const f32 = this.f32Number('argmin', [a])
+    if (f32 !== null) return f32
     const buffer = this.toFloat64Array(a.buffer)
-    return this.module.argmin(buffer)
+    return callNative(() => this.module.argmin(buffer))
+  }
+
+  argAxis(op: ArgReduction, a: NDArrayData, axis: number | null, keepdims: boolean): NDArrayData {
+    this.ensureReady()
+    const name = `${op}_axis` as const
+    const shape = new Uint32Array(a.shape)
+    const f32 = this.f32Kernel(name, [a])
+    const indices = callNative(() =>
+      f32
+        ? (f32(a.buffer, shape, axis ?? undefined, keepdims) as BigInt64Array)
+        : this.module[name](this.toFloat64Array(a.buffer), shape, axis ?? undefined, keepdims),
+    )
+
+    const ax = axis === null ? null : normalizeAxis(axis, a.shape.length)
+    const resultShape = reducedShape(a.shape, ax, keepdims)
+    return {
+      buffer: Int32Array.from(indices, Number),
+      shape: resultShape,
+      strides: computeStrides(resultShape),
+      dtype: 'int32',
+    }
   }
 
   norm(a: NDArrayData, ord: number | 'fro'): number {
     this.ensureReady()
     const buffer = this.toFloat64Array(a.buffer)
 
-    // Map 'fro' to -1 for WASM (special marker)
-    const ordValue = ord === 'fro' ? -1 : ord
+    // Frobenius of the flattened input is its 2-norm
+    const ordValue = ord === 'fro' ? 2 : ord
 
-    try {
-      return this.module.norm(buffer, ordValue)
-    } catch (error) {
-      throw new Error(error instanceof Error ? error.message : 'Norm calculation failed')
-    }
+    return callNative(() => this.module.norm(buffer, ordValue))
   }
 
   // ===== NaN-aware Reductions =====
 
   nansum(a: NDArrayData): number {
     this.ensureReady()
+    const f32 = this.f32Number('nansum', [a])
+    if (f32 !== null) return f32
     const buffer = this.toFloat64Array(a.buffer)
     return this.module.nansum(buffer)
   }
 
   nanmean(a: NDArrayData): number {
     this.ensureReady()
+    const f32 = this.f32Number('nanmean', [a])
+    if (f32 !== null) return f32
     const buffer = this.toFloat64Array(a.buffer)
     return this.module.nanmean(buffer)
   }
 
   nanmax(a: NDArrayData): number {
     this.ensureReady()
+    const f32 = this.f32Number('nanmax', [a])
+    if (f32 !== null) return f32
     const buffer = this.toFloat64Array(a.buffer)
-    return this.module.nanmax(buffer)
+    return callNative(() => this.module.nanmax(buffer))
   }
 
   nanmin(a: NDArrayData): number {
     this.ensureReady()
+    const f32 = this.f32Number('nanmin', [a])
+    if (f32 !== null) return f32
     const buffer = this.toFloat64Array(a.buffer)
-    return this.module.nanmin(buffer)
+    return callNative(() => this.module.nanmin(buffer))
   }
 
   nanstd(a: NDArrayData): number {
     this.ensureReady()
+    const f32 = this.f32Number('nanstd', [a])
+    if (f32 !== null) return f32
     const buffer = this.toFloat64Array(a.buffer)
     return this.module.nanstd(buffer)
   }
 
   nanvar(a: NDArrayData): number {
     this.ensureReady()
+    const f32 = this.f32Number('nanvar', [a])
+    if (f32 !== null) return f32
     const buffer = this.toFloat64Array(a.buffer)
     return this.module.nanvar(buffer)
   }
 
+  // ===== Cumulative Operations =====
+
+  cumulative(op: CumulativeOp, a: NDArrayData, axis: number | null): NDArrayData {
+    this.ensureReady()
+    const { buffer } = a
+    const shape = new Uint32Array(a.shape)
+    const ax = axis ?? undefined
+    const resultShape = axis === null ? [buffer.length] : a.shape
+
+    // float32 accumulates in float32 and int32 is asked to stay int32 rather
+    // than widen to int64; everything else runs in float64
+    if (buffer instanceof Float32Array) {
+      const out = callNative(
+        () => this.module.cumulative_f32(op, buffer, shape, undefined, 0, ax) as Float32Array,
+      )
+      return this.toNDArrayData(out, resultShape, 'float32')
+    }
+    if (buffer instanceof Int32Array) {
+      const out = callNative(
+        () =>
+          this.module.cumulative_i32(op, buffer, shape, undefined, 0, ax, 'int32') as Int32Array,
+      )
+      return {
+        buffer: out,
+        shape: [...resultShape],
+        strides: computeStrides(resultShape),
+        dtype: 'int32',
+      }
+    }
+    const out = callNative(
+      () =>
+        this.module.cumulative(
+          op,
+          this.toFloat64Array(buffer),
+          shape,
+          undefined,
+          0,
+          ax,
+          'float64',
+        ) as Float64Array,
+    )
+    return this.toNDArrayData(out, resultShape, a.dtype)
+  }
+
+  // ===== Ufunc Methods =====
+
+  ufuncReduce(
+    name: UfuncName,
+    a: NDArrayData,
+    axis: readonly number[] | null,
+    keepdims: boolean,
+    initial?: number,
+  ): NDArrayData {
+    this.ensureReady()
+    const shape = new Uint32Array(a.shape)
+    const axes = axis === null ? undefined : Int32Array.from(axis)
+    const f32 = this.f32Kernel('ufunc_reduce', [a])
+    const out = callNative(() =>
+      f32
+        ? (f32(name, a.buffer, shape, axes, keepdims, initial) as Float32Array)
+        : this.module.ufunc_reduce(
+            name,
+            this.toFloat64Array(a.buffer),
+            shape,
+            axes,
+            keepdims,
+            initial,
+          ),
+    )
+
+    const reduced = axis?.map((ax) => normalizeAxis(ax, a.shape.length)) ?? null
+    return this.toNDArrayData(out, reducedShape(a.shape, reduced, keepdims), a.dtype)
+  }
+
+  ufuncAccumulate(name: UfuncName, a: NDArrayData, axis: number): NDArrayData {
+    this.ensureReady()
+    const shape = new Uint32Array(a.shape)
+    const f32 = this.f32Kernel('ufunc_accumulate', [a])
+    const out = callNative(() =>
+      f32
+        ? (f32(name, a.buffer, shape, axis) as Float32Array)
+        : this.module.ufunc_accumulate(name, this.toFloat64Array(a.buffer), shape, axis),
+    )
+    return this.toNDArrayData(out, a.shape, a.dtype)
+  }
+
+  ufuncOuter(name: UfuncName, a: NDArrayData, b: NDArrayData): NDArrayData {
+    this.ensureReady()
+    const f32 = this.f32Kernel('ufunc_outer', [a, b])
+    const out = callNative(() => {
+      if (f32) return f32(name, a.buffer, b.buffer) as Float32Array
+      const bufA = this.toFloat64Array(a.buffer)
+      const bufB = this.toFloat64Array(b.buffer)
+      return this.module.ufunc_outer(name, bufA, bufB)
+    })
+    return this.toNDArrayData(out, [...a.shape, ...b.shape], a.dtype)
+  }
+
+  ufuncReduceat(
+    name: UfuncName,
+    a: NDArrayData,
+    indices: readonly number[],
+    axis: number,
+  ): NDArrayData {
+    this.ensureReady()
+    const shape = new Uint32Array(a.shape)
+    const starts = Int32Array.from(indices)
+    const f32 = this.f32Kernel('ufunc_reduceat', [a])
+    const out = callNative(() =>
+      f32
+        ? (f32(name, a.buffer, shape, starts, axis) as Float32Array)
+        : this.module.ufunc_reduceat(name, this.toFloat64Array(a.buffer), shape, starts, axis),
+    )
+
+    const ax = normalizeAxis(axis, a.shape.length)
+    const resultShape = a.shape.map((dim, d) => (d === ax ? indices.length : dim))
+    return this.toNDArrayData(out, resultShape, a.dtype)
+  }
+
+  ufuncAt(
+    name: UfuncName,
+    target: NDArrayData,
+    indices: readonly (readonly number[])[],
+    values: NDArrayData | number,
+  ): void {
+    this.ensureReady()
+    const shape = new Uint32Array(target.shape)
+    // The index arrays back to back, with each one's length so length 1 can broadcast
+    const flat = Int32Array.from(indices.flat())
+    const lengths = Uint32Array.from(indices, (index) => index.length)
+    const { buffer } = target
+    const vals = typeof values === 'number' ? [values] : values.buffer
+
+    // wasm-bindgen copies a mutable slice back into the array passed in
+    if (buffer instanceof Float32Array) {
+      const f32Values = Float32Array.from(vals)
+      callNative(() => this.module.ufunc_at_f32(name, buffer, shape, flat, lengths, f32Values))
+      return
+    }
+    const work = this.toFloat64Array(buffer)
+    const f64Values = Float64Array.from(vals)
+    callNative(() => this.module.ufunc_at(name, work, shape, flat, lengths, f64Values))
+    if (work !== buffer) {
+      buffer.set(work)
+    }
+  }
+
   // ===== Linear Algebra Operations =====
 
   matmul(a: NDArrayData, b: NDArrayData): NDArrayData {
@@ -218,11 +503,14 @@ export class WASMBackend implements Backend {
       throw new Error(`Shape mismatch: (${m}, ${k}) and (${b.shape[0]}, ${n})`)
     }
 
+    const f32 = this.f32Array('matmul', [a, b], m, k, n)
+    if (f32) return this.toNDArrayData(f32, [m, n], 'float32')
+
     const bufA = this.toFloat64Array(a.buffer)
     const bufB = this.toFloat64Array(b.buffer)
 
     // Call WASM matmul (we'll add this to WASM module)
-    const result = this.module.matmul(bufA, bufB, m, k, n)
+    const result = callNative(() => this.module.matmul(bufA, bufB, m, k, n))
 
     return this.toNDArrayData(result, [m, n], a.dtype)
   }
@@ -236,11 +524,14 @@ export class WASMBackend implements Backend {
         throw new Error('Arrays must have same length for dot product')
       }
 
+      const f32 = this.f32Number('dot', [a, b])
+      if (f32 !== null) return f32
+
       const bufA = this.toFloat64Array(a.buffer)
       const bufB = this.toFloat64Array(b.buffer)
 
       // Call WASM dot
-      return this.module.dot(bufA, bufB)
+      return callNative(() => this.module.dot(bufA, bufB))
     }
 
     throw new Error('dot backend method only supports 1D arrays')
@@ -258,12 +549,16 @@ export class WASMBackend implements Backend {
       throw new Error('FFT requires array length to be power of 2')
     }
 
+    // float32 input gives a float32 (complex64) spectrum, as in NumPy
+    const f32 = this.f32Array('fft', [a])
+    if (f32) return this.toNDArrayData(f32, [n, 2], 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
 
     // Call WASM fft (returns interleaved [real, imag])
-    const result = this.module.fft(buffer)
+    const result = callNative(() => this.module.fft(buffer))
 
-    // Always return float64 for FFT
+    // Other dtypes return float64
     return this.toNDArrayData(result, [n, 2], 'float64')
   }
 
@@ -281,12 +576,15 @@ export class WASMBackend implements Backend {
       throw new Error('IFFT requires array length to be power of 2')
     }
 
+    const f32 = this.f32Array('ifft', [a], n)
+    if (f32) return this.toNDArrayData(f32, [n, 2], 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
 
     // Call WASM ifft (returns interleaved [real, imag])
-    const result = this.module.ifft(buffer, n)
+    const result = callNative(() => this.module.ifft(buffer, n))
 
-    // Always return float64 for IFFT
+    // Other dtypes return float64
     return this.toNDArrayData(result, [n, 2], 'float64')
   }
 
@@ -295,6 +593,9 @@ export class WASMBackend implements Backend {
   abs(a: NDArrayData): NDArrayData {
     this.ensureReady()
 
+    const f32 = this.f32Array('abs_array', [a])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
     const result = this.module.abs_array(buffer)
 
@@ -304,6 +605,9 @@ export class WASMBackend implements Backend {
   sqrt(a: NDArrayData): NDArrayData {
     this.ensureReady()
 
+    const f32 = this.f32Array('sqrt_array', [a])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
     const result = this.module.sqrt_array(buffer)
 
@@ -313,6 +617,9 @@ export class WASMBackend implements Backend {
   cbrt(a: NDArrayData): NDArrayData {
     this.ensureReady()
 
+    const f32 = this.f32Array('cbrt_array', [a])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
     const result = this.module.cbrt_array(buffer)
 
@@ -322,6 +629,9 @@ export class WASMBackend implements Backend {
   square(a: NDArrayData): NDArrayData {
     this.ensureReady()
 
+    const f32 = this.f32Array('square_array', [a])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
     const result = this.module.square_array(buffer)
 
@@ -331,6 +641,9 @@ export class WASMBackend implements Backend {
   exp(a: NDArrayData): NDArrayData {
     this.ensureReady()
 
+    const f32 = this.f32Array('exp_array', [a])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
     const result = this.module.exp_array(buffer)
 
@@ -340,6 +653,9 @@ export class WASMBackend implements Backend {
   exp2(a: NDArrayData): NDArrayData {
     this.ensureReady()
 
+    const f32 = this.f32Array('exp2_array', [a])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
     const result = this.module.exp2_array(buffer)
 
@@ -349,6 +665,9 @@ export class WASMBackend implements Backend {
   expm1(a: NDArrayData): NDArrayData {
     this.ensureReady()
 
+    const f32 = this.f32Array('expm1_array', [a])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
     const result = this.module.expm1_array(buffer)
 
@@ -358,6 +677,9 @@ export class WASMBackend implements Backend {
   log(a: NDArrayData): NDArrayData {
     this.ensureReady()
 
+    const f32 = this.f32Array('log_array', [a])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
     const result = this.module.log_array(buffer)
 
@@ -367,6 +689,9 @@ export class WASMBackend implements Backend {
   log2(a: NDArrayData): NDArrayData {
     this.ensureReady()
 
+    const f32 = this.f32Array('log2_array', [a])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
     const result = this.module.log2_array(buffer)
 
@@ -376,6 +701,9 @@ export class WASMBackend implements Backend {
   log10(a: NDArrayData): NDArrayData {
     this.ensureReady()
 
+    const f32 = this.f32Array('log10_array', [a])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
     const result = this.module.log10_array(buffer)
 
@@ -385,6 +713,9 @@ export class WASMBackend implements Backend {
   log1p(a: NDArrayData): NDArrayData {
     this.ensureReady()
 
+    const f32 = this.f32Array('log1p_array', [a])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
     const result = this.module.log1p_array(buffer)
 
@@ -394,6 +725,9 @@ export class WASMBackend implements Backend {
   round(a: NDArrayData): NDArrayData {
     this.ensureReady()
 
+    const f32 = this.f32Array('round_array', [a])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
     const result = this.module.round_array(buffer)
 
@@ -403,6 +737,9 @@ export class WASMBackend implements Backend {
   floor(a: NDArrayData): NDArrayData {
     this.ensureReady()
 
+    const f32 = this.f32Array('floor_array', [a])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
     const result = this.module.floor_array(buffer)
 
@@ -412,6 +749,9 @@ export class WASMBackend implements Backend {
   ceil(a: NDArrayData): NDArrayData {
     this.ensureReady()
 
+    const f32 = this.f32Array('ceil_array', [a])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
     const result = this.module.ceil_array(buffer)
 
@@ -421,6 +761,9 @@ export class WASMBackend implements Backend {
   trunc(a: NDArrayData): NDArrayData {
     this.ensureReady()
 
+    const f32 = this.f32Array('trunc_array', [a])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
     const result = this.module.trunc_array(buffer)
 
@@ -434,6 +777,9 @@ export class WASMBackend implements Backend {
       throw new Error('Arrays must have same length for maximum')
     }
 
+    const f32 = this.f32Array('maximum_arrays', [a, b])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const bufferA = this.toFloat64Array(a.buffer)
     const bufferB = this.toFloat64Array(b.buffer)
     const result = this.module.maximum_arrays(bufferA, bufferB)
@@ -448,6 +794,9 @@ export class WASMBackend implements Backend {
       throw new Error('Arrays must have same length for minimum')
     }
 
+    const f32 = this.f32Array('minimum_arrays', [a, b])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const bufferA = this.toFloat64Array(a.buffer)
     const bufferB = this.toFloat64Array(b.buffer)
     const result = this.module.minimum_arrays(bufferA, bufferB)
@@ -458,6 +807,9 @@ export class WASMBackend implements Backend {
   clip(a: NDArrayData, min: number, max: number): NDArrayData {
     this.ensureReady()
 
+    const f32 = this.f32Array('clip_array', [a], min, max)
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
     const result = this.module.clip_array(buffer, min, max)
 
@@ -467,6 +819,9 @@ export class WASMBackend implements Backend {
   sign(a: NDArrayData): NDArrayData {
     this.ensureReady()
 
+    const f32 = this.f32Array('sign_array', [a])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
     const result = this.module.sign_array(buffer)
 
@@ -479,6 +834,9 @@ export class WASMBackend implements Backend {
     const bufferA = this.toFloat64Array(a.buffer)
 
     if (typeof b === 'number') {
+      const f32 = this.f32Array('mod_scalar', [a], b)
+      if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
       const result = this.module.mod_scalar(bufferA, b)
       return this.toNDArrayData(result, a.shape, a.dtype)
     }
@@ -487,6 +845,9 @@ export class WASMBackend implements Backend {
       throw new Error('Arrays must have same length for mod')
     }
 
+    const f32 = this.f32Array('mod_arrays', [a, b])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const bufferB = this.toFloat64Array(b.buffer)
     const result = this.module.mod_arrays(bufferA, bufferB)
 
@@ -499,6 +860,9 @@ export class WASMBackend implements Backend {
     const bufferA = this.toFloat64Array(a.buffer)
 
     if (typeof b === 'number') {
+      const f32 = this.f32Array('fmod_scalar', [a], b)
+      if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
       const result = this.module.fmod_scalar(bufferA, b)
       return this.toNDArrayData(result, a.shape, a.dtype)
     }
@@ -507,6 +871,9 @@ export class WASMBackend implements Backend {
       throw new Error('Arrays must have same length for fmod')
     }
 
+    const f32 = this.f32Array('fmod_arrays', [a, b])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const bufferB = this.toFloat64Array(b.buffer)
     const result = this.module.fmod_arrays(bufferA, bufferB)
 
@@ -520,6 +887,9 @@ export class WASMBackend implements Backend {
       throw new Error('Arrays must have same length for arctan2')
     }
 
+    const f32 = this.f32Array('arctan2_arrays', [y, x])
+    if (f32) return this.toNDArrayData(f32, y.shape, 'float32')
+
     const bufferY = this.toFloat64Array(y.buffer)
     const bufferX = this.toFloat64Array(x.buffer)
     const result = this.module.arctan2_arrays(bufferY, bufferX)
@@ -530,6 +900,9 @@ export class WASMBackend implements Backend {
   deg2rad(a: NDArrayData): NDArrayData {
     this.ensureReady()
 
+    const f32 = this.f32Array('deg2rad_array', [a])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
     const result = this.module.deg2rad_array(buffer)
 
@@ -539,6 +912,9 @@ export class WASMBackend implements Backend {
   rad2deg(a: NDArrayData): NDArrayData {
     this.ensureReady()
 
+    const f32 = this.f32Array('rad2deg_array', [a])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
     const result = this.module.rad2deg_array(buffer)
 
@@ -552,6 +928,9 @@ export class WASMBackend implements Backend {
       throw new Error('Arrays must have same size for hypot')
     }
 
+    const f32 = this.f32Array('hypot_arrays', [a, b])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const bufferA = this.toFloat64Array(a.buffer)
     const bufferB = this.toFloat64Array(b.buffer)
     const result = this.module.hypot_arrays(bufferA, bufferB)
@@ -562,6 +941,9 @@ export class WASMBackend implements Backend {
   reciprocal(a: NDArrayData): NDArrayData {
     this.ensureReady()
 
+    const f32 = this.f32Array('reciprocal_array', [a])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
     const result = this.module.reciprocal_array(buffer)
 
@@ -571,6 +953,9 @@ export class WASMBackend implements Backend {
   sin(a: NDArrayData): NDArrayData {
     this.ensureReady()
 
+    const f32 = this.f32Array('sin_array', [a])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
     const result = this.module.sin_array(buffer)
 
@@ -580,6 +965,9 @@ export class WASMBackend implements Backend {
   cos(a: NDArrayData): NDArrayData {
     this.ensureReady()
 
+    const f32 = this.f32Array('cos_array', [a])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
     const result = this.module.cos_array(buffer)
 
@@ -589,6 +977,9 @@ export class WASMBackend implements Backend {
   tan(a: NDArrayData): NDArrayData {
     this.ensureReady()
 
+    const f32 = this.f32Array('tan_array', [a])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
     const result = this.module.tan_array(buffer)
 
@@ -598,6 +989,9 @@ export class WASMBackend implements Backend {
   sinh(a: NDArrayData): NDArrayData {
     this.ensureReady()
 
+    const f32 = this.f32Array('sinh_array', [a])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
     const result = this.module.sinh_array(buffer)
 
@@ -607,6 +1001,9 @@ export class WASMBackend implements Backend {
   cosh(a: NDArrayData): NDArrayData {
     this.ensureReady()
 
+    const f32 = this.f32Array('cosh_array', [a])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
     const result = this.module.cosh_array(buffer)
 
@@ -616,6 +1013,9 @@ export class WASMBackend implements Backend {
   tanh(a: NDArrayData): NDArrayData {
     this.ensureReady()
 
+    const f32 = this.f32Array('tanh_array', [a])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
     const result = this.module.tanh_array(buffer)
 
@@ -625,6 +1025,9 @@ export class WASMBackend implements Backend {
   arcsin(a: NDArrayData): NDArrayData {
     this.ensureReady()
 
+    const f32 = this.f32Array('arcsin_array', [a])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
     const result = this.module.arcsin_array(buffer)
 
@@ -634,6 +1037,9 @@ export class WASMBackend implements Backend {
   arccos(a: NDArrayData): NDArrayData {
     this.ensureReady()
 
+    const f32 = this.f32Array('arccos_array', [a])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
     const result = this.module.arccos_array(buffer)
 
@@ -643,6 +1049,9 @@ export class WASMBackend implements Backend {
   arctan(a: NDArrayData): NDArrayData {
     this.ensureReady()
 
+    const f32 = this.f32Array('arctan_array', [a])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
     const result = this.module.arctan_array(buffer)
 
@@ -652,6 +1061,9 @@ export class WASMBackend implements Backend {
   asinh(a: NDArrayData): NDArrayData {
     this.ensureReady()
 
+    const f32 = this.f32Array('asinh_array', [a])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
     const result = this.module.asinh_array(buffer)
 
@@ -661,6 +1073,9 @@ export class WASMBackend implements Backend {
   acosh(a: NDArrayData): NDArrayData {
     this.ensureReady()
 
+    const f32 = this.f32Array('acosh_array', [a])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
     const result = this.module.acosh_array(buffer)
 
@@ -670,6 +1085,9 @@ export class WASMBackend implements Backend {
   atanh(a: NDArrayData): NDArrayData {
     this.ensureReady()
 
+    const f32 = this.f32Array('atanh_array', [a])
+    if (f32) return this.toNDArrayData(f32, a.shape, 'float32')
+
     const buffer = this.toFloat64Array(a.buffer)
     const result = this.module.atanh_array(buffer)
 
@@ -688,12 +1106,8 @@ export class WASMBackend implements Backend {
     const n = a.shape[0]
     const buffer = this.toFloat64Array(a.buffer)
 
-    try {
-      const result = this.module.inv_matrix(buffer, n)
-      return this.toNDArrayData(result, [n, n], a.dtype)
-    } catch (error) {
-      throw new Error(error instanceof Error ? error.message : 'Matrix inversion failed')
-    }
+    const result = callNative(() => this.module.inv_matrix(buffer, n))
+    return this.toNDArrayData(result, [n, n], a.dtype)
   }
 
   det(a: NDArrayData): number {
@@ -706,11 +1120,7 @@ export class WASMBackend implements Backend {
     const n = a.shape[0]
     const buffer = this.toFloat64Array(a.buffer)
 
-    try {
-      return this.module.det_matrix(buffer, n)
-    } catch (error) {
-      throw new Error(error instanceof Error ? error.message : 'Determinant calculation failed')
-    }
+    return callNative(() => this.module.det_matrix(buffer, n))
   }
 
   transpose(a: NDArrayData): NDArrayData {
@@ -723,7 +1133,7 @@ export class WASMBackend implements Backend {
     const [rows, cols] = a.shape
     const buffer = this.toFloat64Array(a.buffer)
 
-    const result = this.module.transpose_matrix(buffer, rows, cols)
+    const result = callNative(() => this.module.transpose_matrix(buffer, rows, cols))
 
     return this.toNDArrayData(result, [cols, rows], a.dtype)
   }
@@ -736,7 +1146,7 @@ export class WASMBackend implements Backend {
     }
 
     const buffer = this.toFloat64Array(a.buffer)
-    return this.module.trace_matrix(buffer, a.shape[0], a.shape[1])
+    return callNative(() => this.module.trace_matrix(buffer, a.shape[0], a.shape[1]))
   }
 
   outer(a: NDArrayData, b: NDArrayData): NDArrayData {
@@ -749,7 +1159,7 @@ export class WASMBackend implements Backend {
     const bufferA = this.toFloat64Array(a.buffer)
     const bufferB = this.toFloat64Array(b.buffer)
 
-    const result = this.module.outer_product(bufferA, bufferB)
+    const result = callNative(() => this.module.outer_product(bufferA, bufferB))
 
     return this.toNDArrayData(result, [a.buffer.length, b.buffer.length], a.dtype)
   }
@@ -768,7 +1178,7 @@ export class WASMBackend implements Backend {
     const bufferA = this.toFloat64Array(a.buffer)
     const bufferB = this.toFloat64Array(b.buffer)
 
-    return this.module.inner_product(bufferA, bufferB)
+    return callNative(() => this.module.inner_product(bufferA, bufferB))
   }
 
   // ===== Helper Methods =====
@@ -785,6 +1195,49 @@ export class WASMBackend implements Backend {
     return this.wasmModule!
   }
 
+  /**
+   * The `_f32` twin of export `name` when every operand is float32, so
+   * float32 data is neither widened nor narrowed on the way. Null means some
+   * operand is not float32 and the float64 path applies.
+   */
+  private f32Kernel(
+    name: F32Kernel,
+    operands: NDArrayData[],
+  ): ((...args: unknown[]) => unknown) | null {
+    if (!operands.every((x) => x.buffer instanceof Float32Array)) {
+      return null
+    }
+    const kernel = (this.module as unknown as Record<string, unknown>)[`${name}_f32`]
+    if (typeof kernel !== 'function') {
+      throw new Error(`WASM build has no ${name}_f32 export; rebuild numpy-wasm`)
+    }
+    return kernel as (...args: unknown[]) => unknown
+  }
+
+  private f32Array(
+    name: F32Kernel,
+    operands: NDArrayData[],
+    ...args: unknown[]
+  ): Float32Array | null {
+    const kernel = this.f32Kernel(name, operands)
+    if (!kernel) {
+      return null
+    }
+    return callNative(() => kernel(...operands.map((x) => x.buffer), ...args) as Float32Array)
+  }
+
+  private f32Number(
+    name: F32Kernel,
+    operands: NDArrayData[],
+    ...args: unknown[]
+  ): number | null {
+    const kernel = this.f32Kernel(name, operands)
+    if (!kernel) {
+      return null
+    }
+    return callNative(() => kernel(...operands.map((x) => x.buffer), ...args) as number)
+  }
+
   private toFloat64Array(buffer: TypedArray): Float64Array {
     if (buffer instanceof Float64Array) {
       return buffer
@@ -793,11 +1246,18 @@ export class WASMBackend implements Backend {
     return new Float64Array(buffer)
   }
 
-  private toNDArrayData(result: Float64Array, shape: readonly number[], dtype: DType): NDArrayData {
-    // Convert result back to requested dtype
-    const buffer = dtype === 'float64' ? result : createTypedArray(result.length, dtype)
+  private toNDArrayData(
+    result: Float64Array | Float32Array,
+    shape: readonly number[],
+    dtype: DType,
+  ): NDArrayData {
+    // Convert result back to requested dtype; a float32 kernel result is kept as is
+    const kept =
+      (dtype === 'float64' && result instanceof Float64Array) ||
+      (dtype === 'float32' && result instanceof Float32Array)
+    const buffer = kept ? result : createTypedArray(result.length, dtype)
 
-    if (dtype !== 'float64') {
+    if (!kept) {
       for (let i = 0; i < result.length; i++) {
         buffer[i] = result[i]
       }
@@ -822,6 +1282,7 @@ export class WASMBackend implements Backend {
     a: NDArrayData,
     b: NDArrayData,
     op: (a: Float64Array, b: Float64Array) => Float64Array,
+    f32Name?: F32Kernel,
   ): NDArrayData {
     // Determine broadcast shape
     const resultShape = broadcastShapes(a.shape, b.shape)
@@ -830,10 +1291,15 @@ export class WASMBackend implements Backend {
     const aBroadcast = broadcastTo(a, resultShape)
     const bBroadcast = broadcastTo(b, resultShape)
 
+    const f32 = f32Name ? this.f32Array(f32Name, [aBroadcast, bBroadcast]) : null
+    if (f32) {
+      return this.toNDArrayData(f32, resultShape, 'float32')
+    }
+
     // Convert to Float64Array and perform WASM operation
     const bufA = this.toFloat64Array(aBroadcast.buffer)
     const bufB = this.toFloat64Array(bBroadcast.buffer)
-    const result = op(bufA, bufB)
+    const result = callNative(() => op(bufA, bufB))
 
     return this.toNDArrayData(result, resultShape, a.dtype)
   }
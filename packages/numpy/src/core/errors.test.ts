@@ -0,0 +1,58 @@
+import { describe, expect, test } from 'bun:test'
+import {
+  AxisError,
+  callNative,
+  DTypeError,
+  fromNativeError,
+  IndexError,
+  LinAlgError,
+  MemoryError,
+  ValueError,
+} from './errors'
+
+describe('native error mapping', () => {
+  test('decodes shape mismatches into ValueError with shapes', () => {
+    const error = fromNativeError(
+      new Error(
+        '{"name":"ValueError","message":"operands could not be broadcast together with shapes (2,3) (2,)","expectedShape":[2,3],"actualShape":[2]}',
+      ),
+    )
+    expect(error).toBeInstanceOf(ValueError)
+    expect((error as ValueError).message).toBe('operands could not be broadcast together with shapes (2,3) (2,)')
+    expect((error as ValueError).expectedShape).toEqual([2, 3])
+    expect((error as ValueError).actualShape).toEqual([2])
+  })
+
+  test('keeps the NumPy class hierarchy', () => {
+    const axis = fromNativeError(
+      new Error('{"name":"AxisError","message":"axis 2 is out of bounds for array of dimension 2","axis":2,"ndim":2}'),
+    )
+    expect(axis).toBeInstanceOf(AxisError)
+    expect(axis).toBeInstanceOf(ValueError)
+    expect((axis as AxisError).axis).toBe(2)
+
+    expect(fromNativeError(new Error('{"name":"LinAlgError","message":"Singular matrix"}'))).toBeInstanceOf(
+      LinAlgError,
+    )
+    expect(fromNativeError(new Error('{"name":"IndexError","message":"index 3 is out of bounds"}'))).toBeInstanceOf(
+      IndexError,
+    )
+    const dtype = fromNativeError(new Error('{"name":"TypeError","message":"bad dtype","dtype":"c16"}'))
+    expect(dtype).toBeInstanceOf(DTypeError)
+    expect(dtype).toBeInstanceOf(TypeError)
+    expect(fromNativeError(new Error('{"name":"MemoryError","message":"oom","bytes":8}'))).toBeInstanceOf(
+      MemoryError,
+    )
+  })
+
+  test('passes other errors through unchanged', () => {
+    const plain = new Error('Failed to read file')
+    expect(fromNativeError(plain)).toBe(plain)
+    expect(fromNativeError('not an error')).toBe('not an error')
+    expect(() =>
+      callNative(() => {
+        throw new Error('{"name":"LinAlgError","message":"Matrix is not positive definite"}')
+      }),
+    ).toThrow(LinAlgError)
+  })
+})
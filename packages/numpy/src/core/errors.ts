@@ -0,0 +1,123 @@
+// ===== NumPy Error Classes =====
+// Native (N-API) and WASM kernels throw a JS Error whose message is a JSON
+// payload naming the NumPy exception; fromNativeError turns it back into one
+// of these classes with the structured fields attached.
+
+export interface NumpyErrorDetails {
+  expectedShape?: number[]
+  actualShape?: number[]
+  axis?: number
+  ndim?: number
+  dtype?: string
+  bytes?: number
+}
+
+export class ValueError extends Error {
+  readonly expectedShape?: number[]
+  readonly actualShape?: number[]
+
+  constructor(message: string, details: NumpyErrorDetails = {}) {
+    super(message)
+    this.name = 'ValueError'
+    this.expectedShape = details.expectedShape
+    this.actualShape = details.actualShape
+  }
+}
+
+// Like numpy.exceptions.AxisError, also a ValueError
+export class AxisError extends ValueError {
+  readonly axis?: number
+  readonly ndim?: number
+
+  constructor(message: string, details: NumpyErrorDetails = {}) {
+    super(message, details)
+    this.name = 'AxisError'
+    this.axis = details.axis
+    this.ndim = details.ndim
+  }
+}
+
+// Like numpy.linalg.LinAlgError, also a ValueError
+export class LinAlgError extends ValueError {
+  constructor(message: string, details: NumpyErrorDetails = {}) {
+    super(message, details)
+    this.name = 'LinAlgError'
+  }
+}
+
+// Python's IndexError, for an index outside the axis it selects from
+export class IndexError extends Error {
+  constructor(message: string, _details: NumpyErrorDetails = {}) {
+    super(message)
+    this.name = 'IndexError'
+  }
+}
+
+// NumPy raises TypeError for dtype problems; this keeps `instanceof TypeError`
+export class DTypeError extends TypeError {
+  readonly dtype?: string
+
+  constructor(message: string, details: NumpyErrorDetails = {}) {
+    super(message)
+    this.name = 'TypeError'
+    this.dtype = details.dtype
+  }
+}
+
+export class MemoryError extends Error {
+  readonly bytes?: number
+
+  constructor(message: string, details: NumpyErrorDetails = {}) {
+    super(message)
+    this.name = 'MemoryError'
+    this.bytes = details.bytes
+  }
+}
+
+const ERROR_CLASSES = {
+  ValueError,
+  AxisError,
+  LinAlgError,
+  IndexError,
+  TypeError: DTypeError,
+  MemoryError,
+} as const
+
+type NativeErrorPayload = NumpyErrorDetails & {
+  name: keyof typeof ERROR_CLASSES
+  message: string
+}
+
+function parsePayload(message: string): NativeErrorPayload | null {
+  if (!message.startsWith('{"name":')) return null
+  try {
+    const payload = JSON.parse(message) as NativeErrorPayload
+    return payload.name in ERROR_CLASSES && typeof payload.message === 'string' ? payload : null
+  } catch {
+    return null
+  }
+}
+
+/**
+ * Map an error thrown by a native or WASM kernel to its NumPy error class.
+ * Anything that is not a kernel error payload is returned unchanged.
+ */
+export function fromNativeError(error: unknown): unknown {
+  if (!(error instanceof Error)) return error
+  const payload = parsePayload(error.message)
+  if (!payload) return error
+
+  const { name, message, ...details } = payload
+  const mapped = new ERROR_CLASSES[name](message, details)
+  mapped.cause = error
+  return mapped
+}
+
+/** Run a kernel call, rethrowing its failures as NumPy error classes. */
+export function callNative<T>(fn: () => T): T {
+  try {
+    return fn()
+  } catch (error) {
+    throw fromNativeError(error)
+  }
+}
@@ -1,3 +1,4 @@
+import { AxisError } from './errors'
 import type { DType, NDArrayData, TypedArray } from './types'
 import { DTYPE_TO_TYPEDARRAY } from './types'
 
@@ -162,3 +163,64 @@ export function broadcastTo(data: NDArrayData, targetShape: readonly number[]):
     dtype,
   }
 }
+
+// ===== Axis utilities =====
+
+// Map a possibly negative axis into 0..ndim, like NumPy's normalize_axis_index
+export function normalizeAxis(axis: number, ndim: number): number {
+  const resolved = axis < 0 ? axis + ndim : axis
+  if (!Number.isInteger(axis) || resolved < 0 || resolved >= ndim) {
+    throw new AxisError(`axis ${axis} is out of bounds for array of dimension ${ndim}`, {
+      axis,
+      ndim,
+    })
+  }
+  return resolved
+}
+
+// Split a C-contiguous shape around axis: element k of the lane at (o, i)
+// sits at (o * length + k) * inner + i
+export function axisLanes(
+  shape: readonly number[],
+  axis: number,
+): { outer: number; length: number; inner: number } {
+  return {
+    outer: computeSize(shape.slice(0, axis)),
+    length: shape[axis],
+    inner: computeSize(shape.slice(axis + 1)),
+  }
+}
+
+// The shape a reduction over axis (one or several) leaves: those axes
+// dropped, or kept with length 1 under keepdims. A null axis reduces every axis
+export function reducedShape(
+  shape: readonly number[],
+  axis: number | readonly number[] | null,
+  keepdims: boolean,
+): number[] {
+  const axes = typeof axis === 'number' ? [axis] : axis
+  const reduced = (d: number) => axes === null || axes.includes(d)
+  if (keepdims) {
+    return shape.map((dim, d) => (reduced(d) ? 1 : dim))
+  }
+  return shape.filter((_, d) => !reduced(d))
+}
+
+// Flat offsets into a C-contiguous array of shape of every index over dims,
+// the other axes held at 0, in C order. Offsets over the kept axes plus
+// offsets over the reduced axes address each lane of a reduction
+export function dimOffsets(shape: readonly number[], dims: readonly number[]): number[] {
+  const strides = computeStrides(shape)
+  let offsets = [0]
+  for (let d = 0; d < shape.length; d++) {
+    if (!dims.includes(d)) continue
+    const next: number[] = []
+    for (const base of offsets) {
+      for (let i = 0; i < shape[d]; i++) {
+        next.push(base + i * strides[d])
+      }
+    }
+    offsets = next
+  }
+  return offsets
+}
@@ -156,6 +156,8 @@ export {
   argsort,
   argmax,
   argmin,
+  nanargmax,
+  nanargmin,
   // Manipulation
   concat,
   concatenate,
@@ -206,8 +208,12 @@ export {
   // Cumulative
   cumsum,
   cumprod,
+  nancumsum,
+  nancumprod,
   diff,
   gradient,
+  // Ufunc methods
+  ufunc,
   // Set operations
   unique,
   isin,
@@ -360,11 +366,21 @@ export { NDArray } from './ndarray'
 
 // 4. Types
 export type { DType, TypedArray, NDArrayData, ArrayOptions, AxisOptions } from './core/types'
-export type { SliceRange } from './ops'
+export type { SliceRange, Ufunc, UfuncIndices, UfuncName, UfuncReduceOptions } from './ops'
 
 // 5. Utilities
 export { pipe, compose, partial } from './functional'
 export { computeSize, computeStrides, broadcastShapes, canBroadcast } from './core/utils'
+export {
+  ValueError,
+  AxisError,
+  LinAlgError,
+  IndexError,
+  DTypeError,
+  MemoryError,
+  fromNativeError,
+} from './core/errors'
+export type { NumpyErrorDetails } from './core/errors'
 
 // 6. Backend (WASM-first with TS fallback)
 export { getBackend, initWASM, initNativeBLAS, getBackendInfo } from './backend'
@@ -0,0 +1,62 @@
+import { afterAll, beforeAll, describe, expect, test } from 'bun:test'
+import { backendManager, initWASM } from '../backend/manager'
+import { AxisError } from '../core/errors'
+import { array } from '../creation'
+import { cumprod, cumsum, nancumprod, nancumsum } from './cumulative'
+
+describe.each(['typescript', 'wasm'])('cumulative operations (%s backend)', (backend) => {
+  beforeAll(async () => {
+    if (backend === 'wasm') {
+      expect((await initWASM()).success).toBe(true)
+    }
+  })
+
+  afterAll(() => {
+    backendManager.useTypeScript()
+  })
+
+  const m = array([
+    [1, 2, 3],
+    [4, 5, 6],
+  ])
+
+  test('without axis the array is flattened', () => {
+    const result = cumsum(m).getData()
+    expect(result.shape).toEqual([6])
+    expect(Array.from(result.buffer)).toEqual([1, 3, 6, 10, 15, 21])
+    expect(Array.from(cumprod(array([1, 2, 3, 4])).getData().buffer)).toEqual([1, 2, 6, 24])
+  })
+
+  test('along an axis', () => {
+    const down = cumsum(m, 0).getData()
+    expect(down.shape).toEqual([2, 3])
+    expect(Array.from(down.buffer)).toEqual([1, 2, 3, 5, 7, 9])
+    expect(Array.from(cumprod(m, -1).getData().buffer)).toEqual([1, 2, 6, 4, 20, 120])
+  })
+
+  test('nan variants skip NaN', () => {
+    const a = array([
+      [1, Number.NaN],
+      [Number.NaN, 3],
+    ])
+    expect(Array.from(nancumsum(a, 1).getData().buffer)).toEqual([1, 1, 0, 3])
+    expect(Array.from(nancumprod(a).getData().buffer)).toEqual([1, 1, 1, 3])
+    expect(cumsum(a, 1).getData().buffer[1]).toBeNaN()
+  })
+
+  test('the input dtype is kept', () => {
+    const f32 = cumsum(array([0.1, 0.2, 0.3], { dtype: 'float32' })).getData()
+    expect(f32.buffer).toBeInstanceOf(Float32Array)
+    const expected = Math.fround(Math.fround(0.1) + Math.fround(0.2))
+    expect(f32.buffer[1]).toBe(expected)
+    expect(f32.buffer[2]).toBe(Math.fround(expected + Math.fround(0.3)))
+
+    const i32 = cumprod(array([1, 2, 3], { dtype: 'int32' }), 0).getData()
+    expect(i32.dtype).toBe('int32')
+    expect(Array.from(i32.buffer)).toEqual([1, 2, 6])
+  })
+
+  test('a bad axis throws AxisError', () => {
+    expect(() => cumsum(m, 2)).toThrow(AxisError)
+  })
+})
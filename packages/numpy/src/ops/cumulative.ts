@@ -1,49 +1,36 @@
 // ===== Cumulative Operations =====
 
+import { getBackend } from '../backend/manager'
 import type { DType } from '../core/types'
 import { createTypedArray } from '../core/utils'
 import { NDArray } from '../ndarray'
 
 /**
- * Cumulative sum of array elements
+ * Cumulative sum of array elements along `axis`, or over the flattened array
  */
-export function cumsum<T extends DType>(a: NDArray<T>): NDArray<T> {
-  const data = a.getData()
-  const buffer = createTypedArray(data.buffer.length, data.dtype)
-
-  let sum = 0
-  for (let i = 0; i < data.buffer.length; i++) {
-    sum += Number(data.buffer[i])
-    buffer[i] = sum
-  }
-
-  return new NDArray({
-    buffer,
-    shape: data.shape,
-    strides: data.strides,
-    dtype: data.dtype,
-  })
+export function cumsum<T extends DType>(a: NDArray<T>, axis?: number): NDArray<T> {
+  return new NDArray<T>(getBackend().cumulative('cumsum', a.getData(), axis ?? null))
 }
 
 /**
- * Cumulative product of array elements
+ * Cumulative product of array elements along `axis`, or over the flattened array
  */
-export function cumprod<T extends DType>(a: NDArray<T>): NDArray<T> {
-  const data = a.getData()
-  const buffer = createTypedArray(data.buffer.length, data.dtype)
+export function cumprod<T extends DType>(a: NDArray<T>, axis?: number): NDArray<T> {
+  return new NDArray<T>(getBackend().cumulative('cumprod', a.getData(), axis ?? null))
+}
 
-  let prod = 1
-  for (let i = 0; i < data.buffer.length; i++) {
-    prod *= Number(data.buffer[i])
-    buffer[i] = prod
-  }
+/**
+ * Cumulative sum treating NaN as zero
+ */
+export function nancumsum<T extends DType>(a: NDArray<T>, axis?: number): NDArray<T> {
+  return new NDArray<T>(getBackend().cumulative('nancumsum', a.getData(), axis ?? null))
+}
 
-  return new NDArray({
-    buffer,
-    shape: data.shape,
-    strides: data.strides,
-    dtype: data.dtype,
-  })
+/**
+ * Cumulative product treating NaN as one
+ */
+export function nancumprod<T extends DType>(a: NDArray<T>, axis?: number): NDArray<T> {
+  return new NDArray<T>(getBackend().cumulative('nancumprod', a.getData(), axis ?? null))
 }
 
 /**
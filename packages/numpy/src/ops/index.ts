@@ -128,7 +128,7 @@ export {
 } from './logical'
 
 // Sorting
-export { argmax, argmin, argsort, sort } from './sorting'
+export { argmax, argmin, argsort, nanargmax, nanargmin, sort } from './sorting'
 
 // Array manipulation
 export {
@@ -185,7 +185,7 @@ export {
 export { intersect1d, isin, setdiff1d, setxor1d, union1d, unique } from './set'
 
 // Cumulative operations
-export { cumprod, cumsum, diff, gradient } from './cumulative'
+export { cumprod, cumsum, diff, gradient, nancumprod, nancumsum } from './cumulative'
 
 // Validation and comparison
 export {
@@ -236,3 +236,7 @@ export { isscalar, isreal, iscomplex, iscomplexobj, isrealobj } from './type-tes
 
 // Complex number operations
 export { real, imag, angle, conj, conjugate } from './complex'
+
+// Ufunc methods
+export { ufunc } from './ufunc'
+export type { Ufunc, UfuncIndices, UfuncName, UfuncReduceOptions } from './ufunc'
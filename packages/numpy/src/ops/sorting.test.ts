@@ -0,0 +1,79 @@
+import { afterAll, beforeAll, describe, expect, test } from 'bun:test'
+import { backendManager, initWASM } from '../backend/manager'
+import { AxisError, ValueError } from '../core/errors'
+import { array, zeros } from '../creation'
+import type { NDArray } from '../ndarray'
+import { argmax, argmin, nanargmax, nanargmin } from './sorting'
+
+describe.each(['typescript', 'wasm'])('arg-reductions (%s backend)', (backend) => {
+  beforeAll(async () => {
+    if (backend === 'wasm') {
+      expect((await initWASM()).success).toBe(true)
+    }
+  })
+
+  afterAll(() => {
+    backendManager.useTypeScript()
+  })
+
+  const m = array([
+    [3, 1, 3],
+    [0, 5, 2],
+  ])
+
+  test('without axis the flat index is returned', () => {
+    expect(argmax(m)).toBe(4)
+    expect(argmin(m)).toBe(3)
+    expect(argmax(m, {})).toBe(4)
+  })
+
+  test('along an axis, ties go to the first occurrence', () => {
+    const rows = argmax(m, { axis: 1 }) as NDArray<'int32'>
+    expect(rows.getData().shape).toEqual([2])
+    expect(rows.getData().dtype).toBe('int32')
+    expect(Array.from(rows.getData().buffer)).toEqual([0, 1])
+
+    const cols = argmin(m, { axis: 0 }) as NDArray<'int32'>
+    expect(Array.from(cols.getData().buffer)).toEqual([1, 0, 1])
+
+    const last = argmax(m, { axis: -1, keepdims: true }) as NDArray<'int32'>
+    expect(last.getData().shape).toEqual([2, 1])
+  })
+
+  test('keepdims without axis keeps every axis at length 1', () => {
+    const flat = argmin(m, { keepdims: true }) as NDArray<'int32'>
+    expect(flat.getData().shape).toEqual([1, 1])
+    expect(Array.from(flat.getData().buffer)).toEqual([3])
+  })
+
+  test('a NaN wins argmax but is skipped by nanargmax', () => {
+    const a = array([
+      [2, Number.NaN, 5],
+      [1, 4, 4],
+    ])
+    expect(Array.from((argmax(a, { axis: 1 }) as NDArray).getData().buffer)).toEqual([1, 1])
+    expect(Array.from((nanargmax(a, { axis: 1 }) as NDArray).getData().buffer)).toEqual([2, 1])
+    expect(Array.from((nanargmin(a, { axis: 0 }) as NDArray).getData().buffer)).toEqual([1, 1, 1])
+    expect(nanargmax(array([Number.NaN, 3, 7]))).toBe(2)
+    expect(argmax(array([1, Number.NaN, 3]))).toBe(1)
+    expect(argmin(array([1, Number.NaN, 0]))).toBe(1)
+  })
+
+  test('float32 data reduces without widening', () => {
+    const a = array([[0.1, 0.3], [0.2, -1]], { dtype: 'float32' })
+    expect(Array.from((argmax(a, { axis: 0 }) as NDArray).getData().buffer)).toEqual([1, 0])
+  })
+
+  test('errors match NumPy', () => {
+    expect(() => argmax(m, { axis: 2 })).toThrow(AxisError)
+    const holes = array([
+      [1, Number.NaN],
+      [Number.NaN, Number.NaN],
+    ])
+    expect(() => nanargmin(holes, { axis: 1 })).toThrow('All-NaN slice encountered')
+    expect(() => argmin(zeros([2, 0]), { axis: 1 })).toThrow(ValueError)
+    expect(() => argmax(zeros([0]))).toThrow('attempt to get argmax of an empty sequence')
+    // No lanes, so nothing to fail on
+    expect((argmin(zeros([0, 3]), { axis: 1 }) as NDArray).getData().shape).toEqual([0])
+  })
+})
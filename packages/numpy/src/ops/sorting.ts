@@ -1,7 +1,8 @@
 // ===== Sorting Operations =====
 
 import { getBackend } from '../backend/manager'
-import type { DType } from '../core/types'
+import type { ArgReduction } from '../backend/types'
+import type { AxisOptions, DType } from '../core/types'
 import { createTypedArray } from '../core/utils'
 import { NDArray } from '../ndarray'
 
@@ -61,17 +62,79 @@ export function sort<T extends DType>(a: NDArray<T>): NDArray<T> {
 }
 
 /**
- * Return index of maximum value (delegates to backend)
+ * Return index of maximum value (delegates to backend). With `axis`, the
+ * indices of the maxima along it; a NaN counts as the maximum
  */
-export function argmax<T extends DType>(a: NDArray<T>): number {
-  const backend = getBackend()
-  return backend.argmax(a.getData())
+export function argmax<T extends DType>(a: NDArray<T>): number
+export function argmax<T extends DType>(
+  a: NDArray<T>,
+  options: AxisOptions,
+): number | NDArray<'int32'>
+export function argmax<T extends DType>(
+  a: NDArray<T>,
+  options?: AxisOptions,
+): number | NDArray<'int32'> {
+  return argReduce('argmax', a, options)
 }
 
 /**
- * Return index of minimum value (delegates to backend)
+ * Return index of minimum value (delegates to backend), like `argmax`
  */
-export function argmin<T extends DType>(a: NDArray<T>): number {
+export function argmin<T extends DType>(a: NDArray<T>): number
+export function argmin<T extends DType>(
+  a: NDArray<T>,
+  options: AxisOptions,
+): number | NDArray<'int32'>
+export function argmin<T extends DType>(
+  a: NDArray<T>,
+  options?: AxisOptions,
+): number | NDArray<'int32'> {
+  return argReduce('argmin', a, options)
+}
+
+/**
+ * Return index of maximum value ignoring NaN; throws on an all-NaN slice
+ */
+export function nanargmax<T extends DType>(a: NDArray<T>): number
+export function nanargmax<T extends DType>(
+  a: NDArray<T>,
+  options: AxisOptions,
+): number | NDArray<'int32'>
+export function nanargmax<T extends DType>(
+  a: NDArray<T>,
+  options?: AxisOptions,
+): number | NDArray<'int32'> {
+  return argReduce('nanargmax', a, options)
+}
+
+/**
+ * Return index of minimum value ignoring NaN; throws on an all-NaN slice
+ */
+export function nanargmin<T extends DType>(a: NDArray<T>): number
+export function nanargmin<T extends DType>(
+  a: NDArray<T>,
+  options: AxisOptions,
+): number | NDArray<'int32'>
+export function nanargmin<T extends DType>(
+  a: NDArray<T>,
+  options?: AxisOptions,
+): number | NDArray<'int32'> {
+  return argReduce('nanargmin', a, options)
+}
+
+// A flat index without `axis` or `keepdims`, otherwise an int32 array
+function argReduce(
+  op: ArgReduction,
+  a: NDArray,
+  options?: AxisOptions,
+): number | NDArray<'int32'> {
   const backend = getBackend()
-  return backend.argmin(a.getData())
+  const axis = options?.axis ?? null
+  const keepdims = options?.keepdims ?? false
+  if (axis === null && !keepdims && (op === 'argmax' || op === 'argmin')) {
+    return backend[op](a.getData())
+  }
+
+  const result = backend.argAxis(op, a.getData(), axis, keepdims)
+  return axis === null && !keepdims ? result.buffer[0] : new NDArray<'int32'>(result)
 }
@@ -1,6 +1,6 @@
 import { describe, expect, test } from 'bun:test'
 import { array } from '../creation'
-import { average, nanpercentile, nanquantile, ptp } from './statistics'
+import { average, nanmax, nanmin, nanpercentile, nanquantile, ptp } from './statistics'
 
 describe('Enhanced Statistics Functions', () => {
   describe('average', () => {
@@ -116,6 +116,26 @@ describe('Enhanced Statistics Functions', () => {
     })
   })
 
+  describe('nanmax / nanmin', () => {
+    test('skip NaN values', () => {
+      const arr = array([1, Number.NaN, 3, -2])
+      expect(nanmax(arr)).toBe(3)
+      expect(nanmin(arr)).toBe(-2)
+    })
+
+    test('all-NaN input returns NaN', () => {
+      const arr = array([Number.NaN, Number.NaN])
+      expect(nanmax(arr)).toBeNaN()
+      expect(nanmin(arr)).toBeNaN()
+    })
+
+    test('empty input raises ValueError', () => {
+      const arr = array([])
+      expect(() => nanmax(arr)).toThrow('zero-size array to reduction operation fmax')
+      expect(() => nanmin(arr)).toThrow('zero-size array to reduction operation fmin')
+    })
+  })
+
   describe('nanpercentile', () => {
     test('nanpercentile - basic (50th)', () => {
       const arr = array([1, 2, Number.NaN, 3, 4], { dtype: 'float64' })
@@ -0,0 +1,150 @@
+import { afterAll, beforeAll, describe, expect, test } from 'bun:test'
+import { backendManager, initWASM } from '../backend/manager'
+import { AxisError, IndexError, ValueError } from '../core/errors'
+import { array, zeros } from '../creation'
+import type { NDArray } from '../ndarray'
+import { ufunc } from './ufunc'
+
+const values = (a: number | NDArray) => Array.from((a as NDArray).getData().buffer)
+
+describe.each(['typescript', 'wasm'])('ufunc methods (%s backend)', (backend) => {
+  beforeAll(async () => {
+    if (backend === 'wasm') {
+      expect((await initWASM()).success).toBe(true)
+    }
+  })
+
+  afterAll(() => {
+    backendManager.useTypeScript()
+  })
+
+  const m = array([
+    [1, 2, 3],
+    [4, 5, 6],
+  ])
+
+  test('reduce defaults to axis 0', () => {
+    const cols = ufunc('add').reduce(m) as NDArray
+    expect(cols.getData().shape).toEqual([3])
+    expect(values(cols)).toEqual([5, 7, 9])
+    expect(ufunc('multiply').reduce(array([1, 2, 3, 4]))).toBe(24)
+  })
+
+  test('reduce over several axes, keepdims and initial', () => {
+    expect(ufunc('maximum').reduce(m, { axis: null })).toBe(6)
+    expect(ufunc('add').reduce(m, { axis: [0, 1] })).toBe(21)
+
+    const rows = ufunc('add').reduce(m, { axis: -1, keepdims: true }) as NDArray
+    expect(rows.getData().shape).toEqual([2, 1])
+    expect(values(rows)).toEqual([6, 15])
+
+    expect(values(ufunc('minimum').reduce(m, { axis: 1, initial: 2 }))).toEqual([1, 2])
+  })
+
+  test('an empty lane is the identity, or an error without one', () => {
+    expect(values(ufunc('add').reduce(zeros([0, 2])))).toEqual([0, 0])
+    expect(values(ufunc('multiply').reduce(zeros([0, 2])))).toEqual([1, 1])
+    expect(() => ufunc('maximum').reduce(zeros([0, 2]))).toThrow(
+      'zero-size array to reduction operation maximum which has no identity',
+    )
+    expect(values(ufunc('maximum').reduce(zeros([0, 2]), { initial: -1 }))).toEqual([-1, -1])
+  })
+
+  test('reduce rejects several axes for a non-reorderable ufunc', () => {
+    expect(() => ufunc('subtract').reduce(m, { axis: [0, 1] })).toThrow(ValueError)
+    expect(() => ufunc('add').reduce(m, { axis: [1, -1] })).toThrow("duplicate value in 'axis'")
+    expect(() => ufunc('add').reduce(m, { axis: 2 })).toThrow(AxisError)
+  })
+
+  test('accumulate keeps the shape', () => {
+    const running = ufunc('maximum').accumulate(array([3, 1, 4, 1, 5]))
+    expect(values(running)).toEqual([3, 3, 4, 4, 5])
+
+    const rows = ufunc('subtract').accumulate(m, 1)
+    expect(rows.getData().shape).toEqual([2, 3])
+    expect(values(rows)).toEqual([1, -1, -4, 4, -1, -7])
+  })
+
+  test('outer has shape a.shape + b.shape', () => {
+    const table = ufunc('multiply').outer(array([1, 2]), array([[1, 2, 3]]))
+    expect(table.getData().shape).toEqual([2, 1, 3])
+    expect(values(table)).toEqual([1, 2, 3, 2, 4, 6])
+    expect(values(ufunc('power').outer(array([2, 3]), array([0, 2])))).toEqual([1, 4, 1, 9])
+  })
+
+  test('reduceat reduces slices between indices', () => {
+    const a = array([0, 1, 2, 3, 4, 5, 6, 7])
+    // a[0:4], then the lone a[4] since the next index is smaller, a[1:5] and a[5:]
+    expect(values(ufunc('add').reduceat(a, [0, 4, 1, 5]))).toEqual([6, 4, 10, 18])
+
+    const cols = ufunc('add').reduceat(m, [0, 2], 1)
+    expect(cols.getData().shape).toEqual([2, 2])
+    expect(values(cols)).toEqual([3, 3, 9, 6])
+    expect(() => ufunc('add').reduceat(a, [8])).toThrow(IndexError)
+  })
+
+  test('float32 data stays float32', () => {
+    const a = array([0.1, 0.2, 0.3], { dtype: 'float32' })
+    const running = ufunc('add').accumulate(a).getData()
+    expect(running.buffer).toBeInstanceOf(Float32Array)
+    expect(running.buffer[1]).toBe(Math.fround(Math.fround(0.1) + Math.fround(0.2)))
+    expect(ufunc('add').outer(a, a).getData().dtype).toBe('float32')
+  })
+
+  test('at applies a repeated index every time', () => {
+    const counts = zeros([4])
+    ufunc('add').at(counts, [0, 2, 2, -1], 1)
+    expect(values(counts)).toEqual([1, 0, 2, 1])
+
+    const low = array([5, 5, 5])
+    ufunc('minimum').at(low, array([1, 1, 2], { dtype: 'int32' }), array([3, 2, 9]))
+    expect(values(low)).toEqual([5, 2, 5])
+  })
+
+  test('at takes a tuple of index arrays, broadcasting length 1', () => {
+    const grid = array([
+      [1, 2],
+      [3, 4],
+    ])
+    const tuple = [
+      [0, 1, 1],
+      [1, 0, 0],
+    ]
+    ufunc('multiply').at(grid, tuple, array([5, 2, 10]))
+    expect(values(grid)).toEqual([1, 10, 60, 4])
+
+    ufunc('add').at(grid, [[1], [0, 1, 1]], 1)
+    expect(values(grid)).toEqual([1, 10, 61, 6])
+
+    // One index selects a whole row
+    ufunc('subtract').at(grid, [0], array([1, 10]))
+    expect(values(grid)).toEqual([0, 0, 61, 6])
+  })
+
+  test('at keeps float32 data in place', () => {
+    const a = array([0.5, 0.5], { dtype: 'float32' })
+    const { buffer } = a.getData()
+    ufunc('add').at(a, [1, 1], 0.1)
+    expect(a.getData().buffer).toBe(buffer)
+    expect(buffer[1]).toBe(Math.fround(Math.fround(0.5 + Math.fround(0.1)) + Math.fround(0.1)))
+  })
+
+  test('at checks every index before changing the target', () => {
+    const a = array([1, 2, 3])
+    expect(() => ufunc('add').at(a, [0, 3], 1)).toThrow(
+      'index 3 is out of bounds for axis 0 with size 3',
+    )
+    expect(values(a)).toEqual([1, 2, 3])
+    expect(() => ufunc('add').at(a, [[0], [0]], 1)).toThrow(IndexError)
+    expect(() => ufunc('add').at(zeros([2, 2]), [[0, 1], [0, 1, 1]], 1)).toThrow(
+      'shape mismatch: indexing arrays could not be broadcast together with shapes (2,) (3,) ',
+    )
+    expect(() => ufunc('add').at(a, [0, 1], array([1, 2, 3]))).toThrow(ValueError)
+  })
+
+  test('aliases and modulo follow NumPy', () => {
+    expect(values(ufunc('mod').outer(array([-7, 7]), array([3])))).toEqual([2, 1])
+    expect(values(ufunc('floor_divide').accumulate(array([100, 7, 2])))).toEqual([100, 14, 7])
+    expect(ufunc('true_divide').reduce(array([8, 2, 2]))).toBe(2)
+  })
+})
@@ -0,0 +1,77 @@
+// ===== Ufunc Methods =====
+// NumPy's reduce, accumulate, outer, reduceat and at for every binary ufunc
+
+import { getBackend } from '../backend/manager'
+import type { UfuncName } from '../backend/types'
+import type { DType } from '../core/types'
+import { NDArray } from '../ndarray'
+
+export type { UfuncName }
+
+export type UfuncReduceOptions = {
+  // Defaults to 0 as in NumPy; null reduces every axis
+  axis?: number | readonly number[] | null
+  keepdims?: boolean
+  // Starting value of each lane, and the result for an empty one
+  initial?: number
+}
+
+// One index array, or a tuple of them for the leading axes
+type IndexArray = NDArray | readonly number[]
+export type UfuncIndices = IndexArray | readonly IndexArray[]
+
+/**
+ * The methods of a binary ufunc, so `ufunc('add').reduce(a)` is `np.add.reduce(a)`
+ */
+export type Ufunc = {
+  readonly name: UfuncName
+  reduce<T extends DType>(a: NDArray<T>, options?: UfuncReduceOptions): number | NDArray<T>
+  accumulate<T extends DType>(a: NDArray<T>, axis?: number): NDArray<T>
+  outer<T extends DType>(a: NDArray<T>, b: NDArray<T>): NDArray<T>
+  reduceat<T extends DType>(a: NDArray<T>, indices: readonly number[], axis?: number): NDArray<T>
+  at(a: NDArray, indices: UfuncIndices, values: NDArray | number): void
+}
+
+/**
+ * Look up a binary ufunc by its NumPy name, such as `add`, `maximum` or `floor_divide`
+ */
+export function ufunc(name: UfuncName): Ufunc {
+  return {
+    name,
+
+    reduce<T extends DType>(a: NDArray<T>, options: UfuncReduceOptions = {}): number | NDArray<T> {
+      const { axis = 0, keepdims = false, initial } = options
+      const axes = axis === null ? null : typeof axis === 'number' ? [axis] : axis
+      const result = getBackend().ufuncReduce(name, a.getData(), axes, keepdims, initial)
+      // Like sum(), reducing every axis without keepdims gives a number
+      return result.shape.length === 0 ? result.buffer[0] : new NDArray<T>(result)
+    },
+
+    accumulate<T extends DType>(a: NDArray<T>, axis = 0): NDArray<T> {
+      return new NDArray<T>(getBackend().ufuncAccumulate(name, a.getData(), axis))
+    },
+
+    outer<T extends DType>(a: NDArray<T>, b: NDArray<T>): NDArray<T> {
+      return new NDArray<T>(getBackend().ufuncOuter(name, a.getData(), b.getData()))
+    },
+
+    reduceat<T extends DType>(a: NDArray<T>, indices: readonly number[], axis = 0): NDArray<T> {
+      return new NDArray<T>(getBackend().ufuncReduceat(name, a.getData(), indices, axis))
+    },
+
+    at(a: NDArray, indices: UfuncIndices, values: NDArray | number): void {
+      const data = typeof values === 'number' ? values : values.getData()
+      getBackend().ufuncAt(name, a.getData(), indexTuple(indices), data)
+    },
+  }
+}
+
+function indexTuple(indices: UfuncIndices): number[][] {
+  const single =
+    indices instanceof NDArray ||
+    (indices as readonly unknown[]).every((index) => typeof index === 'number')
+  const tuple = single ? [indices as IndexArray] : (indices as readonly IndexArray[])
+  return tuple.map((index) =>
+    index instanceof NDArray ? Array.from(index.getData().buffer, Number) : [...index],
+  )
+}
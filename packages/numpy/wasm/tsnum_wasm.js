@@ -1,1128 +0,0 @@
-const imports = {}
-imports['__wbindgen_placeholder__'] = module.exports
-
-let cachedUint8ArrayMemory0 = null
-
-function getUint8ArrayMemory0() {
-  if (cachedUint8ArrayMemory0 === null || cachedUint8ArrayMemory0.byteLength === 0) {
-    cachedUint8ArrayMemory0 = new Uint8Array(wasm.memory.buffer)
-  }
-  return cachedUint8ArrayMemory0
-}
-
-const cachedTextDecoder = new TextDecoder('utf-8', { ignoreBOM: true, fatal: true })
-
-cachedTextDecoder.decode()
-
-function decodeText(ptr, len) {
-  return cachedTextDecoder.decode(getUint8ArrayMemory0().subarray(ptr, ptr + len))
-}
-
-function getStringFromWasm0(ptr, len) {
-  ptr = ptr >>> 0
-  return decodeText(ptr, len)
-}
-
-let cachedFloat64ArrayMemory0 = null
-
-function getFloat64ArrayMemory0() {
-  if (cachedFloat64ArrayMemory0 === null || cachedFloat64ArrayMemory0.byteLength === 0) {
-    cachedFloat64ArrayMemory0 = new Float64Array(wasm.memory.buffer)
-  }
-  return cachedFloat64ArrayMemory0
-}
-
-let WASM_VECTOR_LEN = 0
-
-function passArrayF64ToWasm0(arg, malloc) {
-  const ptr = malloc(arg.length * 8, 8) >>> 0
-  getFloat64ArrayMemory0().set(arg, ptr / 8)
-  WASM_VECTOR_LEN = arg.length
-  return ptr
-}
-
-function getArrayF64FromWasm0(ptr, len) {
-  ptr = ptr >>> 0
-  return getFloat64ArrayMemory0().subarray(ptr / 8, ptr / 8 + len)
-}
-/**
- * @param {Float64Array} a
- * @param {Float64Array} b
- * @returns {Float64Array}
- */
-exports.minimum_arrays = (a, b) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ptr1 = passArrayF64ToWasm0(b, wasm.__wbindgen_malloc)
-  const len1 = WASM_VECTOR_LEN
-  const ret = wasm.minimum_arrays(ptr0, len0, ptr1, len1)
-  var v3 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v3
-}
-
-/**
- * Mean of all elements
- * @param {Float64Array} a
- * @returns {number}
- */
-exports.mean = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.mean(ptr0, len0)
-  return ret
-}
-
-/**
- * @param {Float64Array} a
- * @returns {Float64Array}
- */
-exports.sign_array = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.sign_array(ptr0, len0)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-function takeFromExternrefTable0(idx) {
-  const value = wasm.__wbindgen_externrefs.get(idx)
-  wasm.__externref_table_dealloc(idx)
-  return value
-}
-/**
- * Determinant for 2x2 and 3x3 matrices
- * @param {Float64Array} a
- * @param {number} n
- * @returns {number}
- */
-exports.det_matrix = (a, n) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.det_matrix(ptr0, len0, n)
-  if (ret[2]) {
-    throw takeFromExternrefTable0(ret[1])
-  }
-  return ret[0]
-}
-
-/**
- * Matrix transpose
- * @param {Float64Array} a
- * @param {number} rows
- * @param {number} cols
- * @returns {Float64Array}
- */
-exports.transpose_matrix = (a, rows, cols) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.transpose_matrix(ptr0, len0, rows, cols)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * Minimum element
- * @param {Float64Array} a
- * @returns {number}
- */
-exports.min = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.min(ptr0, len0)
-  return ret
-}
-
-/**
- * @param {Float64Array} a
- * @returns {Float64Array}
- */
-exports.arccos_array = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.arccos_array(ptr0, len0)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * @param {Float64Array} a
- * @returns {Float64Array}
- */
-exports.atanh_array = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.atanh_array(ptr0, len0)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * Add scalar to array
- * @param {Float64Array} a
- * @param {number} scalar
- * @returns {Float64Array}
- */
-exports.add_scalar = (a, scalar) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.add_scalar(ptr0, len0, scalar)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * Standard deviation
- * @param {Float64Array} a
- * @returns {number}
- */
-exports.std = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.std(ptr0, len0)
-  return ret
-}
-
-/**
- * Sum all elements
- * @param {Float64Array} a
- * @returns {number}
- */
-exports.sum = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.sum(ptr0, len0)
-  return ret
-}
-
-/**
- * @param {Float64Array} a
- * @returns {Float64Array}
- */
-exports.cosh_array = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.cosh_array(ptr0, len0)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * Matrix multiplication: C = A @ B
- * A is m×k, B is k×n, result is m×n
- * @param {Float64Array} a
- * @param {Float64Array} b
- * @param {number} m
- * @param {number} k
- * @param {number} n
- * @returns {Float64Array}
- */
-exports.matmul = (a, b, m, k, n) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ptr1 = passArrayF64ToWasm0(b, wasm.__wbindgen_malloc)
-  const len1 = WASM_VECTOR_LEN
-  const ret = wasm.matmul(ptr0, len0, ptr1, len1, m, k, n)
-  var v3 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v3
-}
-
-/**
- * Variance ignoring NaN values
- * @param {Float64Array} a
- * @returns {number}
- */
-exports.nanvar = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.nanvar(ptr0, len0)
-  return ret
-}
-
-/**
- * @param {Float64Array} a
- * @returns {Float64Array}
- */
-exports.ceil_array = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.ceil_array(ptr0, len0)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * Multiply array by scalar
- * @param {Float64Array} a
- * @param {number} scalar
- * @returns {Float64Array}
- */
-exports.mul_scalar = (a, scalar) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.mul_scalar(ptr0, len0, scalar)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * @param {Float64Array} a
- * @returns {Float64Array}
- */
-exports.rad2deg_array = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.rad2deg_array(ptr0, len0)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * Element-wise sine
- * @param {Float64Array} a
- * @returns {Float64Array}
- */
-exports.sin_array = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.sin_array(ptr0, len0)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * @param {Float64Array} a
- * @param {Float64Array} b
- * @returns {Float64Array}
- */
-exports.fmod_arrays = (a, b) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ptr1 = passArrayF64ToWasm0(b, wasm.__wbindgen_malloc)
-  const len1 = WASM_VECTOR_LEN
-  const ret = wasm.fmod_arrays(ptr0, len0, ptr1, len1)
-  var v3 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v3
-}
-
-/**
- * @param {Float64Array} y
- * @param {Float64Array} x
- * @returns {Float64Array}
- */
-exports.arctan2_arrays = (y, x) => {
-  const ptr0 = passArrayF64ToWasm0(y, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ptr1 = passArrayF64ToWasm0(x, wasm.__wbindgen_malloc)
-  const len1 = WASM_VECTOR_LEN
-  const ret = wasm.arctan2_arrays(ptr0, len0, ptr1, len1)
-  var v3 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v3
-}
-
-/**
- * @param {Float64Array} a
- * @returns {Float64Array}
- */
-exports.arcsin_array = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.arcsin_array(ptr0, len0)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * @param {Float64Array} a
- * @returns {Float64Array}
- */
-exports.log2_array = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.log2_array(ptr0, len0)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * @param {Float64Array} a
- * @returns {Float64Array}
- */
-exports.arctan_array = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.arctan_array(ptr0, len0)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * @param {Float64Array} a
- * @returns {Float64Array}
- */
-exports.round_array = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.round_array(ptr0, len0)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * Power: raise array elements to exponent
- * @param {Float64Array} a
- * @param {number} exponent
- * @returns {Float64Array}
- */
-exports.pow_scalar = (a, exponent) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.pow_scalar(ptr0, len0, exponent)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * Inverse Fast Fourier Transform
- * Input: interleaved [real, imag] pairs (length 2n)
- * Output: interleaved [real, imag] pairs (length 2n)
- * @param {Float64Array} input
- * @param {number} n
- * @returns {Float64Array}
- */
-exports.ifft = (input, n) => {
-  const ptr0 = passArrayF64ToWasm0(input, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.ifft(ptr0, len0, n)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * @param {Float64Array} a
- * @param {number} min
- * @param {number} max
- * @returns {Float64Array}
- */
-exports.clip_array = (a, min, max) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.clip_array(ptr0, len0, min, max)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * Element-wise exponential (e^x)
- * @param {Float64Array} a
- * @returns {Float64Array}
- */
-exports.exp_array = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.exp_array(ptr0, len0)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * @param {Float64Array} a
- * @returns {Float64Array}
- */
-exports.floor_array = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.floor_array(ptr0, len0)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * Index of minimum element
- * @param {Float64Array} a
- * @returns {number}
- */
-exports.argmin = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.argmin(ptr0, len0)
-  return ret >>> 0
-}
-
-/**
- * Divide array by scalar
- * @param {Float64Array} a
- * @param {number} scalar
- * @returns {Float64Array}
- */
-exports.div_scalar = (a, scalar) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.div_scalar(ptr0, len0, scalar)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * Element-wise cosine
- * @param {Float64Array} a
- * @returns {Float64Array}
- */
-exports.cos_array = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.cos_array(ptr0, len0)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * @param {Float64Array} a
- * @returns {Float64Array}
- */
-exports.tanh_array = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.tanh_array(ptr0, len0)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * Outer product of two vectors
- * @param {Float64Array} a
- * @param {Float64Array} b
- * @returns {Float64Array}
- */
-exports.outer_product = (a, b) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ptr1 = passArrayF64ToWasm0(b, wasm.__wbindgen_malloc)
-  const len1 = WASM_VECTOR_LEN
-  const ret = wasm.outer_product(ptr0, len0, ptr1, len1)
-  var v3 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v3
-}
-
-/**
- * Maximum ignoring NaN values
- * @param {Float64Array} a
- * @returns {number}
- */
-exports.nanmax = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.nanmax(ptr0, len0)
-  return ret
-}
-
-/**
- * @param {Float64Array} a
- * @param {number} b
- * @returns {Float64Array}
- */
-exports.mod_scalar = (a, b) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.mod_scalar(ptr0, len0, b)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * @param {Float64Array} a
- * @returns {Float64Array}
- */
-exports.cbrt_array = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.cbrt_array(ptr0, len0)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * @param {Float64Array} a
- * @returns {Float64Array}
- */
-exports.sinh_array = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.sinh_array(ptr0, len0)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * Sum ignoring NaN values
- * @param {Float64Array} a
- * @returns {number}
- */
-exports.nansum = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.nansum(ptr0, len0)
-  return ret
-}
-
-/**
- * Index of maximum element
- * @param {Float64Array} a
- * @returns {number}
- */
-exports.argmax = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.argmax(ptr0, len0)
-  return ret >>> 0
-}
-
-/**
- * Element-wise tangent
- * @param {Float64Array} a
- * @returns {Float64Array}
- */
-exports.tan_array = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.tan_array(ptr0, len0)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * @param {Float64Array} a
- * @returns {Float64Array}
- */
-exports.asinh_array = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.asinh_array(ptr0, len0)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * Inner product of two vectors
- * @param {Float64Array} a
- * @param {Float64Array} b
- * @returns {number}
- */
-exports.inner_product = (a, b) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ptr1 = passArrayF64ToWasm0(b, wasm.__wbindgen_malloc)
-  const len1 = WASM_VECTOR_LEN
-  const ret = wasm.inner_product(ptr0, len0, ptr1, len1)
-  return ret
-}
-
-/**
- * Subtract scalar from array
- * @param {Float64Array} a
- * @param {number} scalar
- * @returns {Float64Array}
- */
-exports.sub_scalar = (a, scalar) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.sub_scalar(ptr0, len0, scalar)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * @param {Float64Array} a
- * @returns {Float64Array}
- */
-exports.acosh_array = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.acosh_array(ptr0, len0)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * Subtract two arrays element-wise
- * @param {Float64Array} a
- * @param {Float64Array} b
- * @returns {Float64Array}
- */
-exports.sub_arrays = (a, b) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ptr1 = passArrayF64ToWasm0(b, wasm.__wbindgen_malloc)
-  const len1 = WASM_VECTOR_LEN
-  const ret = wasm.sub_arrays(ptr0, len0, ptr1, len1)
-  var v3 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v3
-}
-
-/**
- * @param {Float64Array} a
- * @returns {Float64Array}
- */
-exports.reciprocal_array = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.reciprocal_array(ptr0, len0)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * Standard deviation ignoring NaN values
- * @param {Float64Array} a
- * @returns {number}
- */
-exports.nanstd = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.nanstd(ptr0, len0)
-  return ret
-}
-
-/**
- * Minimum ignoring NaN values
- * @param {Float64Array} a
- * @returns {number}
- */
-exports.nanmin = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.nanmin(ptr0, len0)
-  return ret
-}
-
-/**
- * Product of all elements
- * @param {Float64Array} a
- * @returns {number}
- */
-exports.prod = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.prod(ptr0, len0)
-  return ret
-}
-
-/**
- * Maximum element
- * @param {Float64Array} a
- * @returns {number}
- */
-exports.max = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.max(ptr0, len0)
-  return ret
-}
-
-/**
- * Element-wise square root
- * @param {Float64Array} a
- * @returns {Float64Array}
- */
-exports.sqrt_array = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.sqrt_array(ptr0, len0)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * Element-wise natural logarithm
- * @param {Float64Array} a
- * @returns {Float64Array}
- */
-exports.log_array = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.log_array(ptr0, len0)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * @param {Float64Array} a
- * @returns {Float64Array}
- */
-exports.expm1_array = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.expm1_array(ptr0, len0)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * Element-wise base-10 logarithm
- * @param {Float64Array} a
- * @returns {Float64Array}
- */
-exports.log10_array = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.log10_array(ptr0, len0)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * Matrix inverse for 2x2 and 3x3 matrices
- * @param {Float64Array} a
- * @param {number} n
- * @returns {Float64Array}
- */
-exports.inv_matrix = (a, n) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.inv_matrix(ptr0, len0, n)
-  if (ret[3]) {
-    throw takeFromExternrefTable0(ret[2])
-  }
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * @param {Float64Array} a
- * @param {Float64Array} b
- * @returns {Float64Array}
- */
-exports.hypot_arrays = (a, b) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ptr1 = passArrayF64ToWasm0(b, wasm.__wbindgen_malloc)
-  const len1 = WASM_VECTOR_LEN
-  const ret = wasm.hypot_arrays(ptr0, len0, ptr1, len1)
-  var v3 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v3
-}
-
-/**
- * Dot product (inner product) of two 1D arrays
- * @param {Float64Array} a
- * @param {Float64Array} b
- * @returns {number}
- */
-exports.dot = (a, b) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ptr1 = passArrayF64ToWasm0(b, wasm.__wbindgen_malloc)
-  const len1 = WASM_VECTOR_LEN
-  const ret = wasm.dot(ptr0, len0, ptr1, len1)
-  return ret
-}
-
-/**
- * @param {Float64Array} a
- * @param {Float64Array} b
- * @returns {Float64Array}
- */
-exports.maximum_arrays = (a, b) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ptr1 = passArrayF64ToWasm0(b, wasm.__wbindgen_malloc)
-  const len1 = WASM_VECTOR_LEN
-  const ret = wasm.maximum_arrays(ptr0, len0, ptr1, len1)
-  var v3 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v3
-}
-
-/**
- * @param {Float64Array} a
- * @returns {Float64Array}
- */
-exports.square_array = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.square_array(ptr0, len0)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * Trace of a matrix (sum of diagonal elements)
- * @param {Float64Array} a
- * @param {number} rows
- * @param {number} cols
- * @returns {number}
- */
-exports.trace_matrix = (a, rows, cols) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.trace_matrix(ptr0, len0, rows, cols)
-  return ret
-}
-
-/**
- * Fast Fourier Transform (Cooley-Tukey algorithm)
- * Input: real-valued array of length n (must be power of 2)
- * Output: interleaved [real, imag] pairs (length 2n)
- * @param {Float64Array} input
- * @returns {Float64Array}
- */
-exports.fft = (input) => {
-  const ptr0 = passArrayF64ToWasm0(input, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.fft(ptr0, len0)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * Add two arrays element-wise (with broadcasting)
- * @param {Float64Array} a
- * @param {Float64Array} b
- * @returns {Float64Array}
- */
-exports.add_arrays = (a, b) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ptr1 = passArrayF64ToWasm0(b, wasm.__wbindgen_malloc)
-  const len1 = WASM_VECTOR_LEN
-  const ret = wasm.add_arrays(ptr0, len0, ptr1, len1)
-  var v3 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v3
-}
-
-/**
- * @param {Float64Array} a
- * @param {Float64Array} b
- * @returns {Float64Array}
- */
-exports.mod_arrays = (a, b) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ptr1 = passArrayF64ToWasm0(b, wasm.__wbindgen_malloc)
-  const len1 = WASM_VECTOR_LEN
-  const ret = wasm.mod_arrays(ptr0, len0, ptr1, len1)
-  var v3 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v3
-}
-
-/**
- * @param {Float64Array} a
- * @returns {Float64Array}
- */
-exports.exp2_array = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.exp2_array(ptr0, len0)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * @param {Float64Array} a
- * @returns {Float64Array}
- */
-exports.deg2rad_array = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.deg2rad_array(ptr0, len0)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * Multiply two arrays element-wise
- * @param {Float64Array} a
- * @param {Float64Array} b
- * @returns {Float64Array}
- */
-exports.mul_arrays = (a, b) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ptr1 = passArrayF64ToWasm0(b, wasm.__wbindgen_malloc)
-  const len1 = WASM_VECTOR_LEN
-  const ret = wasm.mul_arrays(ptr0, len0, ptr1, len1)
-  var v3 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v3
-}
-
-/**
- * Mean ignoring NaN values
- * @param {Float64Array} a
- * @returns {number}
- */
-exports.nanmean = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.nanmean(ptr0, len0)
-  return ret
-}
-
-/**
- * Variance
- * @param {Float64Array} a
- * @returns {number}
- */
-exports.variance = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.variance(ptr0, len0)
-  return ret
-}
-
-/**
- * @param {Float64Array} a
- * @returns {Float64Array}
- */
-exports.log1p_array = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.log1p_array(ptr0, len0)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * @param {Float64Array} a
- * @param {number} b
- * @returns {Float64Array}
- */
-exports.fmod_scalar = (a, b) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.fmod_scalar(ptr0, len0, b)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * Element-wise absolute value
- * @param {Float64Array} a
- * @returns {Float64Array}
- */
-exports.abs_array = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.abs_array(ptr0, len0)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * Compute vector/matrix norm
- * @param {Float64Array} a
- * @param {number} ord
- * @returns {number}
- */
-exports.norm = (a, ord) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.norm(ptr0, len0, ord)
-  if (ret[2]) {
-    throw takeFromExternrefTable0(ret[1])
-  }
-  return ret[0]
-}
-
-/**
- * @param {Float64Array} a
- * @returns {Float64Array}
- */
-exports.trunc_array = (a) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ret = wasm.trunc_array(ptr0, len0)
-  var v2 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v2
-}
-
-/**
- * Divide two arrays element-wise
- * @param {Float64Array} a
- * @param {Float64Array} b
- * @returns {Float64Array}
- */
-exports.div_arrays = (a, b) => {
-  const ptr0 = passArrayF64ToWasm0(a, wasm.__wbindgen_malloc)
-  const len0 = WASM_VECTOR_LEN
-  const ptr1 = passArrayF64ToWasm0(b, wasm.__wbindgen_malloc)
-  const len1 = WASM_VECTOR_LEN
-  const ret = wasm.div_arrays(ptr0, len0, ptr1, len1)
-  var v3 = getArrayF64FromWasm0(ret[0], ret[1]).slice()
-  wasm.__wbindgen_free(ret[0], ret[1] * 8, 8)
-  return v3
-}
-
-exports.__wbindgen_cast_2241b6af4c4b2941 = (arg0, arg1) => {
-  // Cast intrinsic for `Ref(String) -> Externref`.
-  const ret = getStringFromWasm0(arg0, arg1)
-  return ret
-}
-
-exports.__wbindgen_init_externref_table = () => {
-  const table = wasm.__wbindgen_externrefs
-  const offset = table.grow(4)
-  table.set(0, undefined)
-  table.set(offset + 0, undefined)
-  table.set(offset + 1, null)
-  table.set(offset + 2, true)
-  table.set(offset + 3, false)
-}
-
-const wasmPath = `${__dirname}/tsnum_wasm_bg.wasm`
-const wasmBytes = require('fs').readFileSync(wasmPath)
-const wasmModule = new WebAssembly.Module(wasmBytes)
-const wasm = (exports.__wasm = new WebAssembly.Instance(wasmModule, imports).exports)
-
-wasm.__wbindgen_start()
@@ -0,0 +1,54 @@
+#!/usr/bin/env bash
+# Build the numpy-wasm artifacts consumed by @sylphx/numpy.
+#
+#   scripts/build-wasm.sh            simd128 build -> packages/numpy/wasm
+#                                    scalar build  -> packages/numpy/wasm-nosimd
+#   scripts/build-wasm.sh --threads  also the shared-memory build -> packages/numpy/wasm-threads
+#
+# WASMBackend loads `wasm` when the runtime validates a simd128 module and
+# `wasm-nosimd` otherwise. The scalar build overrides the `+simd128` in
+# packages/numpy-wasm/.cargo/config.toml, so it needs its own target dir.
+#
+# The threaded build needs nightly Rust with rust-src: atomics are only
+# usable once std itself is rebuilt with them.
+#
+# `@sylphx/numpy`'s build runs this script, so `tsc`, the tests and the
+# published package always see glue generated from the current crate.
+set -euo pipefail
+
+ROOT="$(cd "$(dirname "${BASH_SOURCE[0]}")/.." && pwd)"
+CRATE="$ROOT/packages/numpy-wasm"
+OUT="$ROOT/packages/numpy"
+TARGET=wasm32-unknown-unknown
+ARTIFACT=sylphx_numpy_wasm.wasm
+
+cd "$CRATE"
+
+if command -v rustup >/dev/null; then
+  rustup target add "$TARGET"
+fi
+
+# The CLI only accepts modules built by the exact same wasm-bindgen crate.
+BINDGEN_VERSION="$(cargo pkgid wasm-bindgen | sed 's/.*[@#]//')"
+if [[ "$(wasm-bindgen --version 2>/dev/null)" != "wasm-bindgen $BINDGEN_VERSION" ]]; then
+  cargo install wasm-bindgen-cli --version "$BINDGEN_VERSION" --locked
+fi
+
+cargo build --release --target "$TARGET"
+wasm-bindgen --target nodejs --out-dir "$OUT/wasm" --out-name tsnum_wasm \
+  "target/$TARGET/release/$ARTIFACT"
+
+RUSTFLAGS="-C target-feature=-simd128" \
+  cargo build --release --target "$TARGET" --target-dir target/nosimd
+wasm-bindgen --target nodejs --out-dir "$OUT/wasm-nosimd" --out-name tsnum_wasm \
+  "target/nosimd/$TARGET/release/$ARTIFACT"
+
+if [[ "${1:-}" == "--threads" ]]; then
+  RUSTFLAGS="-C target-feature=+atomics,+bulk-memory,+mutable-globals,+simd128" \
+    cargo +nightly build --release --target "$TARGET" --features threads \
+    -Z build-std=panic_abort,std --target-dir target/threads
+  # Workers share one memory, so the glue must be able to import it: only the
+  # `web` target supports that, and Node loads it through `initSync`.
+  wasm-bindgen --target web --out-dir "$OUT/wasm-threads" --out-name tsnum_wasm \
+    "target/threads/$TARGET/release/$ARTIFACT"
+fi